@@ -129,6 +129,7 @@ pub fn derive_translate(_input: TokenStream) -> TokenStream {
                     Rule::loc_return => en::Rule::loc_return,
                     Rule::loc_break => en::Rule::loc_break,
                     Rule::loc_continue => en::Rule::loc_continue,
+                    Rule::loc_match => en::Rule::loc_match,
                     Rule::loc_function => en::Rule::loc_function,
                     Rule::loc_fn => en::Rule::loc_fn,
                     Rule::loc_na => en::Rule::loc_na,
@@ -188,6 +189,11 @@ pub fn derive_translate(_input: TokenStream) -> TokenStream {
                     Rule::and => en::Rule::and,
                     Rule::vand => en::Rule::vand,
                     Rule::assign => en::Rule::assign,
+                    Rule::super_assign => en::Rule::super_assign,
+                    Rule::right_assign => en::Rule::right_assign,
+                    Rule::right_super_assign => en::Rule::right_super_assign,
+                    Rule::tilde => en::Rule::tilde,
+                    Rule::tilde_prefix => en::Rule::tilde_prefix,
                     Rule::special => en::Rule::special,
                     Rule::pipe => en::Rule::pipe,
                     Rule::dollar => en::Rule::dollar,
@@ -207,6 +213,7 @@ pub fn derive_translate(_input: TokenStream) -> TokenStream {
                     Rule::atom => en::Rule::atom,
                     Rule::kw_function_or_fn => en::Rule::kw_function_or_fn,
                     Rule::kw_function => en::Rule::kw_function,
+                    Rule::lambda => en::Rule::lambda,
                     Rule::kw_if_else => en::Rule::kw_if_else,
                     Rule::kw_for => en::Rule::kw_for,
                     Rule::kw_while => en::Rule::kw_while,
@@ -214,6 +221,22 @@ pub fn derive_translate(_input: TokenStream) -> TokenStream {
                     Rule::kw_return => en::Rule::kw_return,
                     Rule::kw_break => en::Rule::kw_break,
                     Rule::kw_continue => en::Rule::kw_continue,
+                    Rule::kw_match => en::Rule::kw_match,
+                    Rule::match_arms => en::Rule::match_arms,
+                    Rule::match_sep => en::Rule::match_sep,
+                    Rule::match_arm => en::Rule::match_arm,
+                    Rule::match_guard => en::Rule::match_guard,
+                    Rule::pattern => en::Rule::pattern,
+                    Rule::pattern_wildcard => en::Rule::pattern_wildcard,
+                    Rule::pattern_rest => en::Rule::pattern_rest,
+                    Rule::pattern_list => en::Rule::pattern_list,
+                    Rule::pattern_vec => en::Rule::pattern_vec,
+                    Rule::pattern_elems => en::Rule::pattern_elems,
+                    Rule::pattern_elem => en::Rule::pattern_elem,
+                    Rule::pattern_named => en::Rule::pattern_named,
+                    Rule::pattern_literal => en::Rule::pattern_literal,
+                    Rule::pattern_negative => en::Rule::pattern_negative,
+                    Rule::pattern_bind => en::Rule::pattern_bind,
                     Rule::val_null => en::Rule::val_null,
                     Rule::val_na => en::Rule::val_na,
                     Rule::val_inf => en::Rule::val_inf,
@@ -239,6 +262,9 @@ pub fn derive_translate(_input: TokenStream) -> TokenStream {
                     Rule::pairs => en::Rule::pairs,
                     Rule::elem => en::Rule::elem,
                     Rule::named => en::Rule::named,
+                    Rule::index_pairs => en::Rule::index_pairs,
+                    Rule::index_elem => en::Rule::index_elem,
+                    Rule::elem_empty => en::Rule::elem_empty,
                     Rule::vec => en::Rule::vec,
                 }
             }
@@ -266,7 +292,9 @@ pub fn derive_localized_parser(input: TokenStream) -> TokenStream {
 
                 // Precedence is defined lowest to highest
                 pest::pratt_parser::PrattParser::new()
-                    .op(Op::infix(assign, Right))
+                    .op(Op::infix(assign, Right) | Op::infix(super_assign, Right))
+                    .op(Op::infix(right_assign, Left) | Op::infix(right_super_assign, Left))
+                    .op(Op::infix(tilde, Left) | Op::prefix(tilde_prefix))
                     .op(Op::infix(or, Left) | Op::infix(vor, Left))
                     .op(Op::infix(and, Left) | Op::infix(vand, Left))
                     .op(Op::infix(lt, Left)
@@ -1,6 +1,6 @@
 use r::cli::Cli;
 use r::lang::Signal;
-use r::repl::repl;
+use r::repl::{repl, script};
 use r::session::Session;
 
 #[cfg(target_family = "wasm")]
@@ -9,7 +9,18 @@ fn main() {}
 #[cfg(not(target_family = "wasm"))]
 fn main() -> Result<(), Signal> {
     use clap::Parser;
-    let session: Session = Session::from(Cli::parse()).with_history_file(
+    let cli = Cli::parse();
+
+    // evaluate scripts and expressions without launching the repl
+    if cli.is_non_interactive() {
+        let session = Session::from(cli.clone());
+        if script(session, &cli.expr, cli.script.as_deref()).is_err() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let session: Session = Session::from(cli).with_history_file(
         std::env::temp_dir()
             .join("history.txt")
             .into_os_string()
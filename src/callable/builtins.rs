@@ -7,6 +7,7 @@ use hashbrown::HashMap;
 use ::lazy_static::lazy_static;
 
 use crate::callable::core::Builtin;
+use crate::callable::keywords::*;
 use crate::callable::operators::*;
 use crate::callable::primitive::*;
 
@@ -14,7 +15,10 @@ lazy_static! {
     pub static ref BUILTIN: HashMap<&'static str, Box<dyn Builtin>> = {
         HashMap::from([
             // automatically populated on build. see build.rs // builtins start
+            ("switch", Box::new(PrimitiveSwitch) as Box<dyn Builtin>),
+            ("ifelse", Box::new(PrimitiveIfElse) as Box<dyn Builtin>),
             ("<-", Box::new(InfixAssign) as Box<dyn Builtin>),
+            ("<<-", Box::new(InfixSuperAssign) as Box<dyn Builtin>),
             ("+", Box::new(InfixAdd) as Box<dyn Builtin>),
             ("-", Box::new(InfixSub) as Box<dyn Builtin>),
             ("-", Box::new(PrefixSub) as Box<dyn Builtin>),
@@ -24,6 +28,7 @@ lazy_static! {
             ("/", Box::new(InfixDiv) as Box<dyn Builtin>),
             ("^", Box::new(InfixPow) as Box<dyn Builtin>),
             ("%", Box::new(InfixMod) as Box<dyn Builtin>),
+            ("%/%", Box::new(InfixIntDiv) as Box<dyn Builtin>),
             ("||", Box::new(InfixOr) as Box<dyn Builtin>),
             ("&&", Box::new(InfixAnd) as Box<dyn Builtin>),
             ("|", Box::new(InfixVectorOr) as Box<dyn Builtin>),
@@ -34,28 +39,192 @@ lazy_static! {
             ("<=", Box::new(InfixLessEqual) as Box<dyn Builtin>),
             ("==", Box::new(InfixEqual) as Box<dyn Builtin>),
             ("!=", Box::new(InfixNotEqual) as Box<dyn Builtin>),
+            ("~", Box::new(InfixTilde) as Box<dyn Builtin>),
+            ("~", Box::new(PrefixTilde) as Box<dyn Builtin>),
             ("|>", Box::new(InfixPipe) as Box<dyn Builtin>),
             (":", Box::new(InfixColon) as Box<dyn Builtin>),
             ("$", Box::new(InfixDollar) as Box<dyn Builtin>),
             ("..", Box::new(PostfixPack) as Box<dyn Builtin>),
             ("[[", Box::new(PostfixIndex) as Box<dyn Builtin>),
             ("[", Box::new(PostfixVecIndex) as Box<dyn Builtin>),
+            ("array", Box::new(PrimitiveArray) as Box<dyn Builtin>),
+            ("attr", Box::new(PrimitiveAttr) as Box<dyn Builtin>),
+            ("attr<-", Box::new(PrimitiveAttrAssign) as Box<dyn Builtin>),
+            ("attributes", Box::new(PrimitiveAttributes) as Box<dyn Builtin>),
             ("c", Box::new(PrimitiveC) as Box<dyn Builtin>),
             ("callstack", Box::new(PrimitiveCallstack) as Box<dyn Builtin>),
+            ("parent.frame", Box::new(PrimitiveParentFrame) as Box<dyn Builtin>),
+            ("sys.call", Box::new(PrimitiveSysCall) as Box<dyn Builtin>),
+            ("sys.function", Box::new(PrimitiveSysFunction) as Box<dyn Builtin>),
+            ("cbind", Box::new(PrimitiveCbind) as Box<dyn Builtin>),
+            ("class", Box::new(PrimitiveClass) as Box<dyn Builtin>),
+            ("class<-", Box::new(PrimitiveClassAssign) as Box<dyn Builtin>),
+            ("as.integer", Box::new(PrimitiveAsInteger) as Box<dyn Builtin>),
+            ("as.double", Box::new(PrimitiveAsDouble) as Box<dyn Builtin>),
+            ("as.numeric", Box::new(PrimitiveAsNumeric) as Box<dyn Builtin>),
+            ("as.logical", Box::new(PrimitiveAsLogical) as Box<dyn Builtin>),
+            ("as.character", Box::new(PrimitiveAsCharacter) as Box<dyn Builtin>),
+            ("as.list", Box::new(PrimitiveAsList) as Box<dyn Builtin>),
+            ("unlist", Box::new(PrimitiveUnlist) as Box<dyn Builtin>),
+            ("conditionMessage", Box::new(PrimitiveConditionMessage) as Box<dyn Builtin>),
+            ("conditionCall", Box::new(PrimitiveConditionCall) as Box<dyn Builtin>),
+            ("data.frame", Box::new(PrimitiveDataFrame) as Box<dyn Builtin>),
+            ("dim", Box::new(PrimitiveDim) as Box<dyn Builtin>),
+            ("dim<-", Box::new(PrimitiveDimAssign) as Box<dyn Builtin>),
+            ("dbeta", Box::new(PrimitiveDbeta) as Box<dyn Builtin>),
+            ("pbeta", Box::new(PrimitivePbeta) as Box<dyn Builtin>),
+            ("qbeta", Box::new(PrimitiveQbeta) as Box<dyn Builtin>),
+            ("dbinom", Box::new(PrimitiveDbinom) as Box<dyn Builtin>),
+            ("pbinom", Box::new(PrimitivePbinom) as Box<dyn Builtin>),
+            ("qbinom", Box::new(PrimitiveQbinom) as Box<dyn Builtin>),
+            ("dchisq", Box::new(PrimitiveDchisq) as Box<dyn Builtin>),
+            ("pchisq", Box::new(PrimitivePchisq) as Box<dyn Builtin>),
+            ("qchisq", Box::new(PrimitiveQchisq) as Box<dyn Builtin>),
+            ("dexp", Box::new(PrimitiveDexp) as Box<dyn Builtin>),
+            ("pexp", Box::new(PrimitivePexp) as Box<dyn Builtin>),
+            ("qexp", Box::new(PrimitiveQexp) as Box<dyn Builtin>),
+            ("df", Box::new(PrimitiveDf) as Box<dyn Builtin>),
+            ("pf", Box::new(PrimitivePf) as Box<dyn Builtin>),
+            ("qf", Box::new(PrimitiveQf) as Box<dyn Builtin>),
+            ("dgamma", Box::new(PrimitiveDgamma) as Box<dyn Builtin>),
+            ("pgamma", Box::new(PrimitivePgamma) as Box<dyn Builtin>),
+            ("qgamma", Box::new(PrimitiveQgamma) as Box<dyn Builtin>),
+            ("dnorm", Box::new(PrimitiveDnorm) as Box<dyn Builtin>),
+            ("pnorm", Box::new(PrimitivePnorm) as Box<dyn Builtin>),
+            ("qnorm", Box::new(PrimitiveQnorm) as Box<dyn Builtin>),
+            ("dpois", Box::new(PrimitiveDpois) as Box<dyn Builtin>),
+            ("ppois", Box::new(PrimitivePpois) as Box<dyn Builtin>),
+            ("qpois", Box::new(PrimitiveQpois) as Box<dyn Builtin>),
+            ("dt", Box::new(PrimitiveDt) as Box<dyn Builtin>),
+            ("pt", Box::new(PrimitivePt) as Box<dyn Builtin>),
+            ("qt", Box::new(PrimitiveQt) as Box<dyn Builtin>),
+            ("dunif", Box::new(PrimitiveDunif) as Box<dyn Builtin>),
+            ("punif", Box::new(PrimitivePunif) as Box<dyn Builtin>),
+            ("qunif", Box::new(PrimitiveQunif) as Box<dyn Builtin>),
             ("environment", Box::new(PrimitiveEnvironment) as Box<dyn Builtin>),
+            ("new.env", Box::new(PrimitiveNewEnv) as Box<dyn Builtin>),
+            ("local", Box::new(PrimitiveLocal) as Box<dyn Builtin>),
+            ("as.environment", Box::new(PrimitiveAsEnvironment) as Box<dyn Builtin>),
+            ("emptyenv", Box::new(PrimitiveEmptyEnv) as Box<dyn Builtin>),
+            ("globalenv", Box::new(PrimitiveGlobalEnv) as Box<dyn Builtin>),
+            ("baseenv", Box::new(PrimitiveBaseEnv) as Box<dyn Builtin>),
+            ("environmentName", Box::new(PrimitiveEnvironmentName) as Box<dyn Builtin>),
             ("eval", Box::new(PrimitiveEval) as Box<dyn Builtin>),
+            ("all.vars", Box::new(PrimitiveAllVars) as Box<dyn Builtin>),
+            ("terms", Box::new(PrimitiveTerms) as Box<dyn Builtin>),
+            ("lapply", Box::new(PrimitiveLapply) as Box<dyn Builtin>),
+            ("sapply", Box::new(PrimitiveSapply) as Box<dyn Builtin>),
+            ("vapply", Box::new(PrimitiveVapply) as Box<dyn Builtin>),
+            ("Map", Box::new(PrimitiveMap) as Box<dyn Builtin>),
+            ("Reduce", Box::new(PrimitiveReduce) as Box<dyn Builtin>),
+            ("Filter", Box::new(PrimitiveFilter) as Box<dyn Builtin>),
+            ("do.call", Box::new(PrimitiveDoCall) as Box<dyn Builtin>),
+            ("head", Box::new(PrimitiveHead) as Box<dyn Builtin>),
             ("length", Box::new(PrimitiveLength) as Box<dyn Builtin>),
             ("list", Box::new(PrimitiveList) as Box<dyn Builtin>),
+            ("abs", Box::new(PrimitiveAbs) as Box<dyn Builtin>),
+            ("sqrt", Box::new(PrimitiveSqrt) as Box<dyn Builtin>),
+            ("exp", Box::new(PrimitiveExp) as Box<dyn Builtin>),
+            ("log", Box::new(PrimitiveLog) as Box<dyn Builtin>),
+            ("log2", Box::new(PrimitiveLog2) as Box<dyn Builtin>),
+            ("log10", Box::new(PrimitiveLog10) as Box<dyn Builtin>),
+            ("floor", Box::new(PrimitiveFloor) as Box<dyn Builtin>),
+            ("ceiling", Box::new(PrimitiveCeiling) as Box<dyn Builtin>),
+            ("trunc", Box::new(PrimitiveTrunc) as Box<dyn Builtin>),
+            ("round", Box::new(PrimitiveRound) as Box<dyn Builtin>),
+            ("signif", Box::new(PrimitiveSignif) as Box<dyn Builtin>),
+            ("sin", Box::new(PrimitiveSin) as Box<dyn Builtin>),
+            ("cos", Box::new(PrimitiveCos) as Box<dyn Builtin>),
+            ("tan", Box::new(PrimitiveTan) as Box<dyn Builtin>),
+            ("asin", Box::new(PrimitiveAsin) as Box<dyn Builtin>),
+            ("acos", Box::new(PrimitiveAcos) as Box<dyn Builtin>),
+            ("atan", Box::new(PrimitiveAtan) as Box<dyn Builtin>),
+            ("%*%", Box::new(PrimitiveMatMul) as Box<dyn Builtin>),
+            ("matrix", Box::new(PrimitiveMatrix) as Box<dyn Builtin>),
+            ("message", Box::new(PrimitiveMessage) as Box<dyn Builtin>),
             ("names", Box::new(PrimitiveNames) as Box<dyn Builtin>),
+            ("names<-", Box::new(PrimitiveNamesAssign) as Box<dyn Builtin>),
+            ("ncol", Box::new(PrimitiveNcol) as Box<dyn Builtin>),
+            ("NextMethod", Box::new(PrimitiveNextMethod) as Box<dyn Builtin>),
+            ("nrow", Box::new(PrimitiveNrow) as Box<dyn Builtin>),
+            ("on.exit", Box::new(PrimitiveOnExit) as Box<dyn Builtin>),
             ("parent", Box::new(PrimitiveParent) as Box<dyn Builtin>),
             ("paste", Box::new(PrimitivePaste) as Box<dyn Builtin>),
+            ("grepl", Box::new(PrimitiveGrepl) as Box<dyn Builtin>),
+            ("sub", Box::new(PrimitiveSub) as Box<dyn Builtin>),
+            ("gsub", Box::new(PrimitiveGsub) as Box<dyn Builtin>),
+            ("regexpr", Box::new(PrimitiveRegexpr) as Box<dyn Builtin>),
+            ("gregexpr", Box::new(PrimitiveGregexpr) as Box<dyn Builtin>),
+            ("regmatches", Box::new(PrimitiveRegmatches) as Box<dyn Builtin>),
             ("print", Box::new(PrimitivePrint) as Box<dyn Builtin>),
             ("q", Box::new(PrimitiveQ) as Box<dyn Builtin>),
             ("quote", Box::new(PrimitiveQuote) as Box<dyn Builtin>),
+            ("rbind", Box::new(PrimitiveRbind) as Box<dyn Builtin>),
+            ("rbinom", Box::new(PrimitiveRbinom) as Box<dyn Builtin>),
+            ("read.csv", Box::new(PrimitiveReadCsv) as Box<dyn Builtin>),
+            ("rexp", Box::new(PrimitiveRexp) as Box<dyn Builtin>),
             ("rnorm", Box::new(PrimitiveRnorm) as Box<dyn Builtin>),
+            ("rpois", Box::new(PrimitiveRpois) as Box<dyn Builtin>),
             ("runif", Box::new(PrimitiveRunif) as Box<dyn Builtin>),
+            ("sample", Box::new(PrimitiveSample) as Box<dyn Builtin>),
+            ("set.seed", Box::new(PrimitiveSetSeed) as Box<dyn Builtin>),
+            ("sort", Box::new(PrimitiveSort) as Box<dyn Builtin>),
+            ("order", Box::new(PrimitiveOrder) as Box<dyn Builtin>),
+            ("rank", Box::new(PrimitiveRank) as Box<dyn Builtin>),
+            ("rev", Box::new(PrimitiveRev) as Box<dyn Builtin>),
+            ("unique", Box::new(PrimitiveUnique) as Box<dyn Builtin>),
+            ("duplicated", Box::new(PrimitiveDuplicated) as Box<dyn Builtin>),
+            ("match", Box::new(PrimitiveMatch) as Box<dyn Builtin>),
+            ("%in%", Box::new(PrimitiveIn) as Box<dyn Builtin>),
+            ("sprintf", Box::new(PrimitiveSprintf) as Box<dyn Builtin>),
+            ("stop", Box::new(PrimitiveStop) as Box<dyn Builtin>),
+            ("nchar", Box::new(PrimitiveNchar) as Box<dyn Builtin>),
+            ("substr", Box::new(PrimitiveSubstr) as Box<dyn Builtin>),
+            ("substring", Box::new(PrimitiveSubstring) as Box<dyn Builtin>),
+            ("toupper", Box::new(PrimitiveToupper) as Box<dyn Builtin>),
+            ("tolower", Box::new(PrimitiveTolower) as Box<dyn Builtin>),
+            ("trimws", Box::new(PrimitiveTrimws) as Box<dyn Builtin>),
+            ("strsplit", Box::new(PrimitiveStrsplit) as Box<dyn Builtin>),
+            ("startsWith", Box::new(PrimitiveStartsWith) as Box<dyn Builtin>),
+            ("endsWith", Box::new(PrimitiveEndsWith) as Box<dyn Builtin>),
+            ("format", Box::new(PrimitiveFormat) as Box<dyn Builtin>),
+            ("structure", Box::new(PrimitiveStructure) as Box<dyn Builtin>),
             ("substitute", Box::new(PrimitiveSubstitute) as Box<dyn Builtin>),
             ("sum", Box::new(PrimitiveSum) as Box<dyn Builtin>),
+            ("prod", Box::new(PrimitiveProd) as Box<dyn Builtin>),
+            ("max", Box::new(PrimitiveMax) as Box<dyn Builtin>),
+            ("min", Box::new(PrimitiveMin) as Box<dyn Builtin>),
+            ("range", Box::new(PrimitiveRange) as Box<dyn Builtin>),
+            ("mean", Box::new(PrimitiveMean) as Box<dyn Builtin>),
+            ("var", Box::new(PrimitiveVar) as Box<dyn Builtin>),
+            ("sd", Box::new(PrimitiveSd) as Box<dyn Builtin>),
+            ("median", Box::new(PrimitiveMedian) as Box<dyn Builtin>),
+            ("quantile", Box::new(PrimitiveQuantile) as Box<dyn Builtin>),
+            ("cumsum", Box::new(PrimitiveCumsum) as Box<dyn Builtin>),
+            ("cumprod", Box::new(PrimitiveCumprod) as Box<dyn Builtin>),
+            ("cummax", Box::new(PrimitiveCummax) as Box<dyn Builtin>),
+            ("cummin", Box::new(PrimitiveCummin) as Box<dyn Builtin>),
+            ("t", Box::new(PrimitiveT) as Box<dyn Builtin>),
+            ("tryCatch", Box::new(PrimitiveTryCatch) as Box<dyn Builtin>),
+            ("typeof", Box::new(PrimitiveTypeof) as Box<dyn Builtin>),
+            ("mode", Box::new(PrimitiveMode) as Box<dyn Builtin>),
+            ("is.na", Box::new(PrimitiveIsNa) as Box<dyn Builtin>),
+            ("is.null", Box::new(PrimitiveIsNull) as Box<dyn Builtin>),
+            ("is.function", Box::new(PrimitiveIsFunction) as Box<dyn Builtin>),
+            ("is.numeric", Box::new(PrimitiveIsNumeric) as Box<dyn Builtin>),
+            ("is.double", Box::new(PrimitiveIsDouble) as Box<dyn Builtin>),
+            ("is.integer", Box::new(PrimitiveIsInteger) as Box<dyn Builtin>),
+            ("is.logical", Box::new(PrimitiveIsLogical) as Box<dyn Builtin>),
+            ("is.character", Box::new(PrimitiveIsCharacter) as Box<dyn Builtin>),
+            ("is.list", Box::new(PrimitiveIsList) as Box<dyn Builtin>),
+            ("unclass", Box::new(PrimitiveUnclass) as Box<dyn Builtin>),
+            ("UseMethod", Box::new(PrimitiveUseMethod) as Box<dyn Builtin>),
+            ("assign", Box::new(PrimitiveAssign) as Box<dyn Builtin>),
+            ("get", Box::new(PrimitiveGet) as Box<dyn Builtin>),
+            ("exists", Box::new(PrimitiveExists) as Box<dyn Builtin>),
+            ("rm", Box::new(PrimitiveRm) as Box<dyn Builtin>),
+            ("warning", Box::new(PrimitiveWarning) as Box<dyn Builtin>),
+            ("write.csv", Box::new(PrimitiveWriteCsv) as Box<dyn Builtin>),
             // builtins end
         ])
     };
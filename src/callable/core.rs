@@ -62,9 +62,6 @@ pub trait Callable {
                 .with_inner_mut(|v| v.push(args.values.with_inner_mut(|x| x.remove(i))))
         }
 
-        // TODO(bug): need to evaluate trailing unassigned params that have
-        // a default value before popping off remaining trailing params
-
         // remove any Ellipsis param, and any trailing unassigned params
         let remainder = formals.pop_trailing();
 
@@ -95,8 +92,10 @@ pub trait Callable {
             }
         }
 
-        // add back in parameter defaults that weren't filled with args
-        for (param, default) in formals.into_iter() {
+        // add back in parameter defaults that weren't filled with args,
+        // including those trailing an ellipsis, which can only be named
+        let trailing = remainder.clone().into_iter().skip(1);
+        for (param, default) in formals.into_iter().chain(trailing) {
             matched_args.values.with_inner_mut(|v| {
                 v.push((
                     param,
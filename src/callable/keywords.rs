@@ -1,11 +1,15 @@
 use r_derive::*;
 
 use super::core::*;
+use super::operators::{PrimList, PrimVec};
+use super::primitive::{combine_named, missing, mode_of};
 use crate::context::Context;
+use crate::error::Error;
 use crate::internal_err;
 use crate::lang::Signal::*;
 use crate::lang::*;
-use crate::object::{ExprList, Obj};
+use crate::object::types::Logical;
+use crate::object::{Expr, ExprList, List, Obj, OptionNA, Vector};
 
 #[derive(Debug, Clone, PartialEq)]
 #[builtin]
@@ -110,8 +114,7 @@ impl Callable for KeywordFor {
             match eval_result {
                 Err(Condition(Break)) => break,
                 Err(Condition(Continue)) => continue,
-                Err(Return(..)) => return eval_result,
-                Err(Error(_)) => return eval_result,
+                Err(_) => return eval_result,
                 _ => (),
             }
 
@@ -267,9 +270,489 @@ impl Callable for KeywordBlock {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[builtin]
+pub struct KeywordMatch;
+
+impl Format for KeywordMatch {
+    fn rfmt_call_with(&self, _state: FormatState, args: &ExprList) -> String {
+        let arms: Vec<String> = args.values[1..]
+            .iter()
+            .map(|arm| match arm {
+                Expr::List(arm) => match &arm.values[..] {
+                    [pattern, Expr::Missing, body] => format!("{pattern} => {body}"),
+                    [pattern, guard, body] => format!("{pattern} if {guard} => {body}"),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            })
+            .collect();
+
+        format!("match ({}) {{ {} }}", args.values[0], arms.join(", "))
+    }
+
+    fn rfmt_with(&self, _state: FormatState) -> String {
+        "match".to_string()
+    }
+}
+
+impl Callable for KeywordMatch {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let mut args = args.values.into_iter();
+        let value = stack.eval_and_finalize(args.next().unwrap())?;
+
+        for arm in args {
+            let Expr::List(arm) = arm else {
+                return internal_err!();
+            };
+
+            let [pattern, guard, body] = &arm.values[..] else {
+                return internal_err!();
+            };
+
+            let mut bindings = vec![];
+            if !destructure(pattern, &value, &mut bindings, stack)? {
+                continue;
+            }
+
+            // bindings are visible to both the guard and the arm's body. They
+            // are made in the current environment, so that assignments in
+            // the arm persist, and are undone once the arm is evaluated
+            let env = stack.env();
+            let shadowed: Vec<(String, Option<Obj>)> = bindings
+                .iter()
+                .map(|(name, _)| (name.clone(), env.values.borrow().get(name).cloned()))
+                .collect();
+
+            for (name, value) in bindings {
+                env.insert(name, value);
+            }
+
+            let result = eval_arm(guard, body, stack);
+
+            for (name, value) in shadowed {
+                match value {
+                    Some(value) => env.insert(name, value),
+                    None => {
+                        env.values.borrow_mut().remove(&name);
+                    }
+                }
+            }
+
+            match result? {
+                Some(value) => return Ok(value),
+                None => continue,
+            }
+        }
+
+        let mode = mode_of(&value);
+        Error::Other(format!("no match arm matches value of mode '{mode}'")).into()
+    }
+}
+
+/// Evaluate a match arm's guard and, if it passes, the arm's body
+fn eval_arm(guard: &Expr, body: &Expr, stack: &mut CallStack) -> Result<Option<Obj>, Signal> {
+    if *guard != Expr::Missing {
+        let guard: bool = stack.eval_and_finalize(guard.clone())?.try_into()?;
+        if !guard {
+            return Ok(None);
+        }
+    }
+
+    stack.eval_and_finalize(body.clone()).map(Some)
+}
+
+/// Match a value against a destructuring pattern
+///
+/// Returns whether the value matches, collecting the values bound by the
+/// pattern's names.
+///
+fn destructure(
+    pattern: &Expr,
+    value: &Obj,
+    bindings: &mut Vec<(String, Obj)>,
+    stack: &mut CallStack,
+) -> Result<bool, Signal> {
+    match pattern {
+        Expr::Symbol(name) if name == "_" => Ok(true),
+        Expr::Symbol(name) | Expr::Ellipsis(Some(name)) => {
+            bindings.push((name.clone(), value.clone()));
+            Ok(true)
+        }
+        Expr::Ellipsis(None) => Ok(true),
+        Expr::Call(what, patterns) if **what == Expr::as_primitive(PrimList) => match value {
+            Obj::List(_) => destructure_elements(patterns, value, bindings, stack),
+            _ => Ok(false),
+        },
+        Expr::Call(what, patterns) if **what == Expr::as_primitive(PrimVec) => match value {
+            Obj::Vector(_) => destructure_elements(patterns, value, bindings, stack),
+            _ => Ok(false),
+        },
+        literal => Ok(literal_matches(literal, value)),
+    }
+}
+
+/// Match the elements of a list or vector against element patterns
+///
+/// Named patterns match the element of the same name. The remaining
+/// elements are matched by position, with a rest pattern standing in for
+/// any number of elements between those matched before and after it.
+///
+fn destructure_elements(
+    patterns: &ExprList,
+    value: &Obj,
+    bindings: &mut Vec<(String, Obj)>,
+    stack: &mut CallStack,
+) -> Result<bool, Signal> {
+    let mut elements = elements(value);
+    let mut positional = vec![];
+    let mut rest: Option<(usize, &Option<String>)> = None;
+
+    for (key, pattern) in patterns.keys.iter().zip(patterns.values.iter()) {
+        match (key, pattern) {
+            (Some(name), pattern) => {
+                let Some(i) = elements.iter().position(|(k, _)| k.as_ref() == Some(name)) else {
+                    return Ok(false);
+                };
+
+                let (_, element) = elements.remove(i);
+                if !destructure(pattern, &element, bindings, stack)? {
+                    return Ok(false);
+                }
+            }
+            (None, Expr::Ellipsis(name)) => {
+                if rest.is_some() {
+                    return Error::Other("patterns may only contain one rest pattern".into())
+                        .into();
+                }
+                rest = Some((positional.len(), name));
+            }
+            (None, pattern) => positional.push(pattern),
+        }
+    }
+
+    let (n, before) = match rest {
+        Some((i, _)) if elements.len() >= positional.len() => (elements.len(), i),
+        None if elements.len() == positional.len() => (elements.len(), positional.len()),
+        _ => return Ok(false),
+    };
+
+    let after = elements.split_off(n - (positional.len() - before));
+    let middle = elements.split_off(before);
+
+    let matched = elements.into_iter().chain(after);
+    for (pattern, (_, element)) in positional.into_iter().zip(matched) {
+        if !destructure(pattern, &element, bindings, stack)? {
+            return Ok(false);
+        }
+    }
+
+    if let Some((_, Some(name))) = rest {
+        let middle = match value {
+            Obj::List(_) => Obj::List(List::from(middle)),
+            Obj::Vector(v) if middle.is_empty() => {
+                v.try_get(Obj::Vector(Vector::from(Vec::<i32>::new())))?
+            }
+            _ => combine_named(middle, stack)?,
+        };
+
+        bindings.push((name.clone(), middle));
+    }
+
+    Ok(true)
+}
+
+/// The named elements of a list or vector
+fn elements(value: &Obj) -> Vec<(Option<String>, Obj)> {
+    match value {
+        Obj::List(l) => l.values.borrow().to_vec(),
+        Obj::Vector(v) => {
+            let names: Vec<String> = match value.get_attr("names") {
+                Some(Obj::Vector(names)) => names.into(),
+                _ => vec![],
+            };
+
+            (0..v.len())
+                .filter_map(|i| Some((names.get(i).cloned(), Obj::Vector(v.get(i)?))))
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Whether a value is a scalar equal to a literal pattern
+fn literal_matches(literal: &Expr, value: &Obj) -> bool {
+    let Obj::Vector(v) = value else {
+        return *literal == Expr::Null && *value == Obj::Null;
+    };
+
+    if v.len() != 1 {
+        return false;
+    }
+
+    if missing(v)[0] {
+        return *literal == Expr::NA;
+    }
+
+    let number: Option<f64> = match v {
+        Vector::Double(_) | Vector::Integer(_) => value.clone().try_into().ok(),
+        _ => None,
+    };
+
+    match (literal, v) {
+        (Expr::Number(x), _) => number == Some(*x),
+        (Expr::Integer(x), _) => number == Some(*x as f64),
+        (Expr::Inf, _) => number == Some(f64::INFINITY),
+        (Expr::String(x), Vector::Character(_)) => String::from(v.clone()) == *x,
+        (Expr::Bool(x), Vector::Logical(_)) => v.clone().try_into() == Ok(*x),
+        _ => false,
+    }
+}
+
+/// Select One of Several Alternatives
+///
+/// Evaluates only the alternative selected by `EXPR`. A `character` selector
+/// picks the alternative of the same name, falling through empty
+/// alternatives to the next provided one, or otherwise the single unnamed
+/// alternative as a default. A numeric selector picks an alternative by
+/// position. When nothing is selected, `NULL` is returned.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// switch(EXPR, ...)
+/// ```
+///
+/// ## Arguments
+///
+/// `EXPR`: A `character` or numeric value of length one.
+/// `...`: The alternatives.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// switch("b", a = "apple", b = "banana", "other")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// switch("x", a = , b = "a or b", "other")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// switch(2, "first", "second")
+/// ```
+///
+#[doc(alias = "switch")]
+#[builtin(sym = "switch")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSwitch;
+
+impl Callable for PrimitiveSwitch {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("EXPR".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+        ])
+    }
+
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let mut args: Vec<(Option<String>, Expr)> = args.into_iter().collect();
+
+        // the selector is named `EXPR`, or otherwise is the first argument
+        let selector = args
+            .iter()
+            .position(|(k, _)| k.as_deref() == Some("EXPR"))
+            .or_else(|| args.iter().position(|(k, _)| k.is_none()))
+            .ok_or(Error::ArgumentMissing("EXPR".to_string()))?;
+
+        let (_, selector) = args.remove(selector);
+        let selector = stack.eval_and_finalize(selector)?;
+
+        let arm = match selector {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => {
+                let name = String::from(v);
+                let defaults: Vec<usize> =
+                    (0..args.len()).filter(|&i| args[i].0.is_none()).collect();
+                if defaults.len() > 1 {
+                    return Error::Other("duplicate 'switch' defaults".into()).into();
+                }
+
+                // empty alternatives fall through to the next provided one
+                let selected = args.iter().position(|(k, _)| k.as_deref() == Some(&name));
+                match selected.or(defaults.first().copied()) {
+                    Some(i) => args[i..].iter().find(|(_, v)| *v != Expr::Missing).cloned(),
+                    None => None,
+                }
+            }
+            Obj::Vector(v @ (Vector::Double(_) | Vector::Integer(_))) if v.len() == 1 => {
+                let i: i32 = Obj::Vector(v).try_into()?;
+                let arm = match i >= 1 {
+                    true => args.get(i as usize - 1),
+                    false => None,
+                };
+
+                match arm {
+                    Some((_, Expr::Missing)) => {
+                        return Error::Other("empty alternative in numeric switch".into()).into()
+                    }
+                    arm => arm.cloned(),
+                }
+            }
+            _ => return Error::Other("EXPR must be a length 1 vector".into()).into(),
+        };
+
+        match arm {
+            Some((_, expr)) => stack.eval_and_finalize(expr),
+            None => Ok(Obj::Null),
+        }
+    }
+}
+
+/// Vectorized Conditional Selection
+///
+/// Selects elements from `yes` where `test` is true and from `no` where it
+/// is false, recycling both to the length of `test`. Missing tests produce
+/// missing elements. `yes` and `no` are only evaluated when some element of
+/// `test` selects them, and the result takes the most general type of those
+/// which are.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// ifelse(test, yes, no)
+/// ```
+///
+/// ## Arguments
+///
+/// `test`: A vector, coerced to `logical`.
+/// `yes`: Values for true elements of `test`.
+/// `no`: Values for false elements of `test`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- c(-2, 0, 3)
+/// ifelse(x > 0, "positive", "not positive")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// ifelse(c(true, NA, false), 1:3, 0)
+/// ```
+///
+#[doc(alias = "ifelse")]
+#[builtin(sym = "ifelse")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIfElse;
+
+impl Callable for PrimitiveIfElse {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("test".to_string()), Expr::Missing),
+            (Some("yes".to_string()), Expr::Missing),
+            (Some("no".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("test")?.force(stack)?;
+        let Obj::Vector(v) = &x else {
+            return Error::Other("argument 'test' must be a vector".into()).into();
+        };
+
+        let test: Vec<Logical> = match v.clone().as_logical() {
+            Vector::Logical(v) => v.iter().collect(),
+            _ => unreachable!(),
+        };
+
+        // only evaluate the alternatives which are selected
+        let (any_yes, any_no) = (
+            test.contains(&OptionNA::Some(true)),
+            test.contains(&OptionNA::Some(false)),
+        );
+        let yes = alternative(&mut args, "yes", any_yes, stack)?;
+        let no = alternative(&mut args, "no", any_no, stack)?;
+
+        // alternatives are coerced to the most general of their types
+        let coerce = match (&yes, &no) {
+            (Some(Vector::Character(_)), _) | (_, Some(Vector::Character(_))) => {
+                Vector::as_character
+            }
+            (Some(Vector::Double(_)), _) | (_, Some(Vector::Double(_))) => Vector::as_double,
+            (Some(Vector::Integer(_)), _) | (_, Some(Vector::Integer(_))) => Vector::as_integer,
+            _ => Vector::as_logical,
+        };
+
+        let empty = || Vector::from(Vec::<Logical>::new());
+        let yes = coerce(yes.unwrap_or_else(empty));
+        let no = coerce(no.unwrap_or_else(empty));
+
+        let result = match (yes, no) {
+            (Vector::Character(y), Vector::Character(n)) => {
+                Vector::from(pick(&test, y.iter(), n.iter()))
+            }
+            (Vector::Double(y), Vector::Double(n)) => Vector::from(pick(&test, y.iter(), n.iter())),
+            (Vector::Integer(y), Vector::Integer(n)) => {
+                Vector::from(pick(&test, y.iter(), n.iter()))
+            }
+            (Vector::Logical(y), Vector::Logical(n)) => {
+                Vector::from(pick(&test, y.iter(), n.iter()))
+            }
+            _ => unreachable!(),
+        };
+
+        let result = Obj::Vector(result);
+        if let Some(names) = x.get_attr("names") {
+            return result.set_attr("names", names);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Evaluate an alternative of `ifelse()`, when it is selected by the test
+fn alternative(
+    args: &mut Obj,
+    name: &str,
+    selected: bool,
+    stack: &mut CallStack,
+) -> Result<Option<Vector>, Signal> {
+    if !selected {
+        return Ok(None);
+    }
+
+    match args.try_get_named(name)?.force(stack)? {
+        Obj::Vector(v) if !v.is_empty() => Ok(Some(v)),
+        Obj::Vector(_) | Obj::Null => Error::Other("replacement has length zero".into()).into(),
+        _ => Error::Other(format!("argument '{name}' must be a vector")).into(),
+    }
+}
+
+/// Pick each element from `yes` where `test` is true and from `no` where it
+/// is false, recycling both to the length of `test`
+fn pick<T: Clone>(
+    test: &[Logical],
+    yes: impl Iterator<Item = OptionNA<T>>,
+    no: impl Iterator<Item = OptionNA<T>>,
+) -> Vec<OptionNA<T>> {
+    let (yes, no): (Vec<_>, Vec<_>) = (yes.collect(), no.collect());
+    test.iter()
+        .enumerate()
+        .map(|(i, t)| match t {
+            OptionNA::Some(true) => yes[i % yes.len()].clone(),
+            OptionNA::Some(false) => no[i % no.len()].clone(),
+            OptionNA::NA => OptionNA::NA,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
-    use crate::r;
+    use crate::{r, r_expect};
 
     #[test]
     fn repeat_with_break() {
@@ -310,4 +793,215 @@ mod test {
             r! { 6 }
         );
     }
+
+    #[test]
+    fn for_over_lazy_sequence() {
+        assert_eq!(
+            r! {{"
+               sum <- 0
+               for (i in 1:10000) {
+                   sum <- sum + i
+               }
+            "}},
+            r! { 50005000 }
+        );
+    }
+
+    #[test]
+    fn if_else_chains() {
+        assert_eq!(
+            r! {{"
+               sign <- function(n) if (n < 0) \"neg\" else if (n == 0) \"zero\" else \"pos\"
+               c(sign(-2), sign(0), sign(5))
+            "}},
+            r! { c("neg", "zero", "pos") }
+        );
+        assert_eq!(r! { x <- if (false) 1; x }, r! { NULL });
+    }
+
+    #[test]
+    fn switch_by_name() {
+        assert_eq!(r! { switch("b", a = 1, b = 2, 3) }, r! { 2 });
+        assert_eq!(r! { switch("z", a = 1, b = 2, 3) }, r! { 3 });
+        assert_eq!(r! { switch("z", a = 1, b = 2) }, r! { NULL });
+    }
+
+    #[test]
+    fn switch_fall_through() {
+        assert_eq!(r! { switch("a", a = , b = "ab", "other") }, r! { "ab" });
+        assert_eq!(r! { switch("c", a = 1, c = , d = , e = 5) }, r! { 5 });
+    }
+
+    #[test]
+    fn switch_by_position() {
+        assert_eq!(r! { switch(2, "a", "b", "c") }, r! { "b" });
+        assert_eq!(r! { switch(4, "a", "b", "c") }, r! { NULL });
+        assert_eq!(r! { switch(0, "a", "b", "c") }, r! { NULL });
+        assert_eq!(r! { switch(-1, "a", "b", "c") }, r! { NULL });
+    }
+
+    #[test]
+    fn switch_is_lazy() {
+        assert_eq!(
+            r! {{"
+               x <- 0
+               switch(\"a\", a = x <- 1, b = x <- 2)
+               x
+            "}},
+            r! { 1 }
+        );
+    }
+
+    #[test]
+    fn switch_errors() {
+        assert!(r! { switch("a", a = 1, 2, 3) }.is_err());
+        assert!(r! { switch(c(1, 2), 1, 2) }.is_err());
+        assert!(r! { switch(1, a = , 2) }.is_err());
+    }
+
+    #[test]
+    fn ifelse_recycles() {
+        assert_eq!(
+            r! { ifelse(c(true, false, true, false), 1:4, 0) },
+            r! { c(1, 0, 3, 0) }
+        );
+        assert_eq!(
+            r! { ifelse(c(-1, 0, 2) > 0, "pos", "non-pos") },
+            r! { c("non-pos", "non-pos", "pos") }
+        );
+    }
+
+    #[test]
+    fn ifelse_propagates_na() {
+        assert_eq!(r! { ifelse(c(true, NA, false), 1, 2) }, r! { c(1, NA, 2) });
+    }
+
+    #[test]
+    fn ifelse_coerces_alternatives() {
+        assert_eq!(r! { ifelse(c(true, false), 1L, "no") }, r! { c("1", "no") });
+        assert_eq!(
+            r! { ifelse(c(true, false, NA), true, 2L) },
+            r! { c(1L, 2L, NA) }
+        );
+        assert_eq!(r! { ifelse(c(true, true), 1L, 2.5) }, r! { c(1L, 1L) });
+    }
+
+    #[test]
+    fn ifelse_is_lazy() {
+        assert_eq!(
+            r! { ifelse(c(true, true), 1, stop("unused")) },
+            r! { c(1, 1) }
+        );
+        assert_eq!(
+            r! { x <- c(a = 1, b = -1); ifelse(x > 0, "yes", "no") },
+            r! { c(a = "yes", b = "no") }
+        )
+    }
+
+    #[test]
+    fn match_literals() {
+        assert_eq!(
+            r! {{"
+               f <- function(x) match (x) { 0 => \"zero\", -1 => \"minus one\", \"a\" => \"letter\", NA => \"missing\", _ => \"other\" }
+               c(f(0), f(-1), f(\"a\"), f(NA), f(2))
+            "}},
+            r! { c("zero", "minus one", "letter", "missing", "other") }
+        );
+        assert_eq!(r! { match (NULL) { NULL => 1, _ => 2 } }, r! { 1 });
+        assert_eq!(r! { match ("0") { 0 => 1, _ => 2 } }, r! { 2 });
+    }
+
+    #[test]
+    fn match_destructures_lists() {
+        assert_eq!(
+            r! {{"
+               match (list(1, \"a\", 3)) {
+                   (a, \"b\", c) => 0
+                   (a, \"a\", c) => a + c
+               }
+            "}},
+            r! { 4 }
+        );
+        assert_eq!(
+            r! { match (list(1, 2)) { (a) => a, (a, b) => b } },
+            r! { 2 }
+        );
+        assert_eq!(r! { match (c(1, 2)) { (a, b) => 1, _ => 2 } }, r! { 2 });
+    }
+
+    #[test]
+    fn match_destructures_vectors() {
+        assert_eq!(
+            r! { match (c(1, 2, 3, 4)) { [first, ..mid, last] => mid } },
+            r! { c(2, 3) }
+        );
+        assert_eq!(
+            r! { match (c(1, 2)) { [a, b, ..rest] => length(rest) } },
+            r! { 0L }
+        );
+        assert_eq!(r! { match (1:3) { [a, ..] => a } }, r! { 1L });
+    }
+
+    #[test]
+    fn match_named_fields() {
+        assert_eq!(
+            r! { match (list(a = 1, b = 2, c = 3)) { (c = x, ..rest) => list(x, rest) } },
+            r! { list(3, list(a = 1, b = 2)) }
+        );
+        assert_eq!(
+            r! { match (list(a = 1, b = 2)) { (a = x) => 1, (z = x, ..) => 2, _ => 3 } },
+            r! { 3 }
+        );
+        assert_eq!(
+            r! { match (list(a = list(1, 2))) { (a = (x, y)) => x + y } },
+            r! { 3 }
+        );
+    }
+
+    #[test]
+    fn match_guards() {
+        assert_eq!(
+            r! {{"
+               sign <- function(n) match (n) { x if x < 0 => -1, 0 => 0, _ => 1 }
+               c(sign(-5), sign(0), sign(5))
+            "}},
+            r! { c(-1, 0, 1) }
+        );
+    }
+
+    #[test]
+    fn match_bindings_are_local() {
+        r_expect! {{r#"
+            x <- "outer"
+            match (list(a = 0)) { (a = x) if x > 1 => "big", _ => "small" }
+            x == "outer"
+        "#}}
+        r_expect! {{"
+            x <- 1
+            match (5) { x => x } == 5 && x == 1
+        "}}
+    }
+
+    #[test]
+    fn match_arm_assignments_persist() {
+        r_expect! {{"
+            y <- 0
+            match (1) { x => { y <- 5 } }
+            y == 5
+        "}}
+        r_expect! {{"
+            match (1) { x => { y <- x + 1 } }
+            y == 2
+        "}}
+    }
+
+    #[test]
+    fn match_without_matching_arm() {
+        assert!(r! { match (3) { 1 => 1, 2 => 2 } }.is_err());
+    }
+
+    #[test]
+    fn match_function_still_callable() {
+        assert_eq!(r! { match(2, c(3, 2)) }, r! { 2L });
+    }
 }
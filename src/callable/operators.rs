@@ -1,9 +1,11 @@
 use r_derive::*;
 
 use super::core::*;
+use super::primitive::missing;
 use crate::context::Context;
 use crate::error::Error;
-use crate::lang::{CallStack, EvalResult};
+use crate::lang::{CallStack, EvalResult, Signal};
+use crate::object::reptype::RepType;
 use crate::object::types::*;
 use crate::object::*;
 
@@ -17,13 +19,60 @@ impl Callable for InfixAssign {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[builtin(sym = "<<-", kind = Infix)]
+pub struct InfixSuperAssign;
+impl Callable for InfixSuperAssign {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let (lhs, rhs) = args.unnamed_binary_args();
+        stack.assign_super(lhs, rhs)
+    }
+}
+
+/// Warn when integer arithmetic produces `NA` from values which aren't
+/// missing, as happens when a result overflows the range of an `integer`
+fn warn_integer_overflow(
+    lhs: &Obj,
+    rhs: &Obj,
+    result: &Obj,
+    stack: &mut CallStack,
+) -> Result<(), Signal> {
+    let (Obj::Vector(l), Obj::Vector(r), Obj::Vector(result @ Vector::Integer(_))) =
+        (lhs, rhs, result)
+    else {
+        return Ok(());
+    };
+
+    // sequences are only produced when their values are within range
+    if result.is_lazy() {
+        return Ok(());
+    }
+
+    let (l, r) = (missing(l), missing(r));
+    let overflow = missing(result)
+        .into_iter()
+        .enumerate()
+        .any(|(i, na)| na && !l[i % l.len()] && !r[i % r.len()]);
+
+    if overflow {
+        stack.warn("NAs produced by integer overflow".to_string())?;
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[builtin(sym = "+", kind = Infix)]
 pub struct InfixAdd;
 impl Callable for InfixAdd {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
-        lhs + rhs
+        if let Some(result) = stack.dispatch_operator("+", &lhs, &rhs) {
+            return result;
+        }
+        let result = (lhs.clone() + rhs.clone())?;
+        warn_integer_overflow(&lhs, &rhs, &result, stack)?;
+        Ok(result)
     }
 }
 
@@ -33,7 +82,12 @@ pub struct InfixSub;
 impl Callable for InfixSub {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
-        lhs - rhs
+        if let Some(result) = stack.dispatch_operator("-", &lhs, &rhs) {
+            return result;
+        }
+        let result = (lhs.clone() - rhs.clone())?;
+        warn_integer_overflow(&lhs, &rhs, &result, stack)?;
+        Ok(result)
     }
 }
 
@@ -43,6 +97,9 @@ pub struct PrefixSub;
 impl Callable for PrefixSub {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let what = stack.eval(args.unnamed_unary_arg())?;
+        if let Some(result) = stack.dispatch("-", &what, vec![(None, what.clone())]) {
+            return result;
+        }
         -what
     }
 }
@@ -72,7 +129,12 @@ pub struct InfixMul;
 impl Callable for InfixMul {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
-        lhs * rhs
+        if let Some(result) = stack.dispatch_operator("*", &lhs, &rhs) {
+            return result;
+        }
+        let result = (lhs.clone() * rhs.clone())?;
+        warn_integer_overflow(&lhs, &rhs, &result, stack)?;
+        Ok(result)
     }
 }
 
@@ -82,6 +144,9 @@ pub struct InfixDiv;
 impl Callable for InfixDiv {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
+        if let Some(result) = stack.dispatch_operator("/", &lhs, &rhs) {
+            return result;
+        }
         lhs / rhs
     }
 }
@@ -92,6 +157,9 @@ pub struct InfixPow;
 impl Callable for InfixPow {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
+        if let Some(result) = stack.dispatch_operator("^", &lhs, &rhs) {
+            return result;
+        }
         lhs.power(rhs)
     }
 }
@@ -102,10 +170,26 @@ pub struct InfixMod;
 impl Callable for InfixMod {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
         let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
+        if let Some(result) = stack.dispatch_operator("%%", &lhs, &rhs) {
+            return result;
+        }
         lhs % rhs
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[builtin(sym = "%/%")]
+pub struct InfixIntDiv;
+impl Callable for InfixIntDiv {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let (lhs, rhs) = stack.eval_binary(args.unnamed_binary_args())?;
+        if let Some(result) = stack.dispatch_operator("%/%", &lhs, &rhs) {
+            return result;
+        }
+        lhs.int_div(rhs)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[builtin(sym = "||", kind = Infix)]
 pub struct InfixOr;
@@ -224,6 +308,27 @@ impl Callable for InfixNotEqual {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[builtin(sym = "~", kind = Infix)]
+pub struct InfixTilde;
+impl Callable for InfixTilde {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        // formulas quote their arguments, capturing the calling environment
+        let formula = Expr::new_primitive_call(self.clone(), args);
+        Ok(Obj::Formula(formula, stack.env(), Attributes::default()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[builtin(sym = "~", kind = Prefix)]
+pub struct PrefixTilde;
+impl Callable for PrefixTilde {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let formula = Expr::new_primitive_call(self.clone(), args);
+        Ok(Obj::Formula(formula, stack.env(), Attributes::default()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[builtin(sym = "|>", kind = Infix)]
 pub struct InfixPipe;
@@ -293,30 +398,35 @@ impl Callable for InfixColon {
                 return Ok(Obj::Vector(Vector::from(Vec::<Double>::new())));
             }
 
-            let mut v = start;
-            return Ok(Obj::Vector(Vector::from(
-                vec![start]
-                    .into_iter()
-                    .chain(std::iter::repeat_with(|| {
-                        v += by;
-                        v
-                    }))
-                    .take_while(|x| if start <= end { x <= &end } else { x >= &end })
-                    .collect::<Vec<f64>>(),
-            )));
+            // allow for floating point error in reaching the end of the sequence
+            let len = (range / by + 1e-10).floor() as usize + 1;
+            return Ok(Obj::Vector(Vector::from(RepType::<Double>::seq(
+                start, by, len,
+            ))));
 
         // binary case
         } else {
-            let start: i32 = stack.eval(arg1)?.as_integer()?.try_into()?;
-            let end: i32 = stack.eval(arg2)?.as_integer()?.try_into()?;
+            let start: f64 = stack.eval(arg1)?.try_into()?;
+            let end: f64 = stack.eval(arg2)?.try_into()?;
             if start > end {
                 return Error::InvalidRange.into();
             }
-            return Ok(Obj::Vector(Vector::from(if start <= end {
-                (start..=end).map(|i| i as f64).collect::<Vec<f64>>()
-            } else {
-                (end..=start).map(|i| i as f64).rev().collect::<Vec<f64>>()
-            })));
+
+            // allow for floating point error in reaching the end of the sequence
+            let len = (end - start + 1e-10).floor() as usize + 1;
+            let last = start + (len - 1) as f64;
+
+            // sequences of whole numbers within the range of an integer are integers
+            let bound = i32::MAX as f64;
+            if start.fract() == 0.0 && start >= -bound && last <= bound {
+                return Ok(Obj::Vector(Vector::from(RepType::<Integer>::seq(
+                    start, 1.0, len,
+                ))));
+            }
+
+            return Ok(Obj::Vector(Vector::from(RepType::<Double>::seq(
+                start, 1.0, len,
+            ))));
         }
     }
 }
@@ -411,6 +521,20 @@ impl Callable for PostfixIndex {
         let index = stack.eval(x.1)?;
         what.try_get_inner_mut(index)
     }
+
+    fn call_assign(&self, value: Expr, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let value = stack.eval(value)?;
+        promote_target(&args, &value, stack)?;
+
+        let x = args.unnamed_binary_args();
+        let what = stack.eval_mut(x.0)?;
+        let index = stack.eval(x.1)?;
+        match what {
+            // elements of lists are replaced, rather than assigned into
+            Obj::List(l) => l.set_inner(index, value),
+            what => what.try_get_inner_mut(index)?.assign(value),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -418,15 +542,95 @@ impl Callable for PostfixIndex {
 pub struct PostfixVecIndex;
 impl Callable for PostfixVecIndex {
     fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
-        let (what, index) = stack.eval_binary(args.unnamed_binary_args())?;
-        what.try_get(index)
+        let mut args = args.into_iter();
+        let Some((_, what)) = args.next() else {
+            unreachable!();
+        };
+
+        let what = stack.eval_and_finalize(what)?.force(stack)?;
+        let (indices, drop) = eval_indices(args, stack)?;
+        index_vector(what, indices, drop)
     }
 
     fn call_mut(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
-        let x = args.unnamed_binary_args();
-        let what = stack.eval_mut(x.0)?;
-        let index = stack.eval(x.1)?;
-        what.try_get(index)
+        let mut args = args.into_iter();
+        let Some((_, what)) = args.next() else {
+            unreachable!();
+        };
+
+        let what = stack.eval_mut(what)?;
+        let (indices, drop) = eval_indices(args, stack)?;
+        index_vector(what, indices, drop)
+    }
+
+    fn call_assign(&self, value: Expr, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let value = stack.eval(value)?;
+        promote_target(&args, &value, stack)?;
+        self.call_mut(args, stack)?.assign(value)
+    }
+}
+
+/// Promote the vector being indexed into to the type of an assigned value
+///
+/// Vectors are modified in place, so a vector which can't hold an assigned
+/// value, like an `integer` vector assigned a `double`, is first replaced by
+/// its coercion to the type of the value.
+fn promote_target(args: &ExprList, value: &Obj, stack: &mut CallStack) -> Result<(), Signal> {
+    use Vector::*;
+
+    let (Some(target), Obj::Vector(value)) = (args.values.first(), value) else {
+        return Ok(());
+    };
+
+    // targets which can't be evaluated raise their errors when assigned to
+    let Ok(Obj::Vector(v)) = stack.eval(target.clone()) else {
+        return Ok(());
+    };
+
+    let promoted = match (v, value) {
+        (v @ Logical(_), Integer(_)) => v.as_integer(),
+        (v @ (Logical(_) | Integer(_)), Double(_)) => v.as_double(),
+        (v @ (Logical(_) | Integer(_) | Double(_)), Character(_)) => v.as_character(),
+        _ => return Ok(()),
+    };
+
+    stack.assign_replacement(target.clone(), Obj::Vector(promoted))?;
+    Ok(())
+}
+
+/// Evaluate the indices of a `[` call, with empty indices as `None`
+///
+/// A named `drop` argument is not an index, and instead determines whether
+/// dimensions of extent one are dropped from the result (by default, they
+/// are).
+///
+fn eval_indices(
+    args: impl Iterator<Item = (Option<String>, Expr)>,
+    stack: &mut CallStack,
+) -> Result<(Vec<Option<Obj>>, bool), Signal> {
+    let mut indices = vec![];
+    let mut drop = true;
+
+    for (name, index) in args {
+        match (name.as_deref(), index) {
+            (Some("drop"), value) => drop = stack.eval(value)?.force(stack)?.try_into()?,
+            (_, Expr::Missing) => indices.push(None),
+            (_, index) => indices.push(Some(stack.eval_and_finalize(index)?.force(stack)?)),
+        }
+    }
+
+    Ok((indices, drop))
+}
+
+/// Index a vector by a single index, or by one index per dimension
+fn index_vector(what: Obj, mut indices: Vec<Option<Obj>>, drop: bool) -> EvalResult {
+    match indices.len() {
+        0 => Ok(what),
+        1 => match indices.pop().flatten() {
+            Some(index) => what.try_get(index),
+            None => Ok(what),
+        },
+        _ => what.try_get_dims(indices, drop),
     }
 }
 
@@ -468,12 +672,287 @@ impl Callable for PrimList {
 mod tests {
     use crate::error::Error;
     use crate::lang::{EvalResult, Signal};
-    use crate::r;
+    use crate::{r, r_expect};
     #[test]
     fn colon_operator() {
         assert_eq!(EvalResult::Err(Signal::Error(Error::InvalidRange)), r!(1:0));
-        assert_eq!(r!([1, 2]), r!(1:2));
-        assert_eq!(r!([1]), r!(1:1));
+        assert_eq!(r! { c(1L, 2L) }, r!(1:2));
+        assert_eq!(r! { c(1L) }, r!(1:1));
+        assert_eq!(r! { c(1.5, 2.5) }, r!(1.5:3));
         assert_eq!(r!(1:-2:-3), r!([1, -1, -3]));
     }
+
+    #[test]
+    fn integer_arithmetic() {
+        assert_eq!(r! { 1:3 * 2L }, r! { c(2L, 4L, 6L) });
+        assert_eq!(r! { -(1:3) }, r! { c(-1L, -2L, -3L) });
+        assert_eq!(r! { TRUE + TRUE }, r! { 2L });
+        assert_eq!(r! { 1:3 + 0.5 }, r! { c(1.5, 2.5, 3.5) });
+    }
+
+    #[test]
+    fn integer_overflow_is_na() {
+        assert_eq!(r! { 2147483647L + 1L }, r! { c(NA, 1L)[1] });
+        assert_eq!(r! { -2147483647L - 1L }, r! { c(NA, 1L)[1] });
+        assert_eq!(r! { 100000L * 100000L }, r! { c(NA, 1L)[1] });
+    }
+
+    #[test]
+    fn integer_overflow_warns() {
+        assert_eq!(
+            r! { tryCatch(2147483647L + 1L, warning = fn(w) conditionMessage(w)) },
+            r! { "NAs produced by integer overflow" }
+        );
+        assert_eq!(
+            r! { tryCatch(c(1L, NA) * 2L, warning = fn(w) "warned") },
+            r! { c(2L, NA) }
+        );
+    }
+
+    #[test]
+    fn division_is_double() {
+        assert_eq!(r! { 1L / 2L }, r! { 0.5 });
+        assert_eq!(r! { 1:2 / 1L }, r! { c(1, 2) });
+    }
+
+    #[test]
+    fn integer_division() {
+        assert_eq!(r! { 7L %/% 2L }, r! { 3L });
+        assert_eq!(r! { -7L %/% 2L }, r! { -4L });
+        assert_eq!(r! { 7L %/% 0L }, r! { c(NA, 1L)[1] });
+        assert_eq!(r! { 7.5 %/% 2 }, r! { 3 });
+    }
+
+    #[test]
+    fn modulo_takes_sign_of_divisor() {
+        assert_eq!(r! { 5L %% -3L }, r! { -1L });
+        assert_eq!(r! { -5 %% 3 }, r! { 1 });
+        assert_eq!(r! { 5L %% 0L }, r! { c(NA, 1L)[1] });
+    }
+
+    #[test]
+    fn assignment_promotes_target() {
+        assert_eq!(
+            r! {{"
+                x <- 1:3
+                x[2] <- 2.5
+                x
+            "}},
+            r! { c(1, 2.5, 3) }
+        );
+        assert_eq!(
+            r! {{"
+                l <- list(1:2)
+                l[[1]][1] <- 0.5
+                l[[1]]
+            "}},
+            r! { c(0.5, 2) }
+        );
+    }
+
+    #[test]
+    fn colon_operator_is_lazy() {
+        r_expect! { length(1:1000000000) == 1000000000 };
+        r_expect! { (1:1000000000)[5] == 5 };
+        assert_eq!(r! { (1:1000000000)[5:7] }, r! { c(5L, 6L, 7L) });
+    }
+
+    #[test]
+    fn colon_operator_materialized_on_assignment() {
+        assert_eq!(
+            r! {{"
+                x <- 1:3
+                y <- x
+                y[2] <- 10
+                c(x, y)
+            "}},
+            r! { c(1, 2, 3, 1, 10, 3) }
+        );
+    }
+
+    #[test]
+    fn nested_subset_views() {
+        assert_eq!(
+            r! {{"
+                x <- c(-1, 2, -3, 4, 5)
+                x[x > 0][1:2]
+            "}},
+            r! { c(2, 4) }
+        );
+        r_expect! {{"
+            x <- c(-1, 2, -3, 4, 5)
+            length(x[x > 0]) == 3 && length(x[x > 0][-1]) == 2
+        "}};
+    }
+
+    #[test]
+    fn negative_index() {
+        assert_eq!(r! { (1:5)[-1] }, r! { c(2L, 3L, 4L, 5L) });
+        assert_eq!(r! { (1:5)[c(-1, -5, -10)] }, r! { c(2L, 3L, 4L) });
+        assert_eq!(
+            r! {{"
+                x <- c(1, 2, 3)
+                x[-2] <- 0
+                x
+            "}},
+            r! { c(0, 2, 0) }
+        );
+        assert_eq!(
+            r! { (1:5)[c(-1, 2)] },
+            Error::Other("can't mix positive and negative subscripts".to_string()).into()
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_index() {
+        assert_eq!(r! { c(1, 2, 3)[c(3, 5)] }, r! { c(3, NA) });
+        assert_eq!(r! { c(1, 2, 3)[c(true, NA)] }, r! { c(1, NA, 3) });
+    }
+
+    #[test]
+    fn subset_views_are_copied_on_assignment() {
+        assert_eq!(
+            r! {{"
+                x <- c(-1, 2, -3, 4, 5)
+                y <- x[x > 0]
+                y[1] <- 10
+                c(x, y)
+            "}},
+            r! { c(-1, 2, -3, 4, 5, 10, 4, 5) }
+        );
+    }
+
+    #[test]
+    fn matrix_index() {
+        r_expect! { matrix(c(1, 2, 3, 4, 5, 6), nrow = 2)[2, 3] == 6 };
+        assert_eq!(
+            r! { matrix(c(1, 2, 3, 4, 5, 6), nrow = 2)[2, ] },
+            r! { c(2, 4, 6) }
+        );
+        assert_eq!(
+            r! { matrix(c(1, 2, 3, 4, 5, 6), nrow = 2)[, 2] },
+            r! { c(3, 4) }
+        );
+    }
+
+    #[test]
+    fn matrix_index_keeps_dims() {
+        assert_eq!(
+            r! { dim(matrix(1:6, nrow = 2)[, c(1, 3)]) },
+            r! { c(2L, 2L) }
+        )
+    }
+
+    #[test]
+    fn matrix_index_without_drop() {
+        assert_eq!(
+            r! { dim(matrix(1:6, nrow = 2)[1, , drop = FALSE]) },
+            r! { c(1L, 3L) }
+        );
+        assert_eq!(
+            r! { dim(matrix(1:6, nrow = 2)[1, 2, drop = FALSE]) },
+            r! { c(1L, 1L) }
+        );
+        assert_eq!(
+            r! { matrix(1:6, nrow = 2)[1, , drop = TRUE] },
+            r! { c(1L, 3L, 5L) }
+        );
+    }
+
+    #[test]
+    fn matrix_index_negative() {
+        assert_eq!(
+            r! { matrix(c(1, 2, 3, 4, 5, 6), nrow = 2)[-1, ] },
+            r! { c(2, 4, 6) }
+        )
+    }
+
+    #[test]
+    fn matrix_index_out_of_bounds() {
+        assert_eq!(
+            r! { matrix(1:6, nrow = 2)[3, 1] },
+            Error::Other("subscript out of bounds".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn matrix_index_wrong_dimensions() {
+        assert_eq!(
+            r! { c(1, 2)[1, 1] },
+            Error::Other("incorrect number of dimensions".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn list_assign_new_name() {
+        r_expect! {{r#"
+            x <- list(a = 1)
+            x[["b"]] <- 2
+            length(x) == 2 && x$b == 2
+        "#}}
+    }
+
+    #[test]
+    fn matrix_assign() {
+        r_expect! {{r#"
+            m <- matrix(c(1, 2, 3, 4), nrow = 2)
+            m[1, 2] <- 10
+            m[, 1] <- c(0, 0)
+            m[1, 1] == 0 && m[2, 1] == 0 && m[1, 2] == 10 && m[2, 2] == 4
+        "#}}
+    }
+
+    #[test]
+    fn empty_index() {
+        assert_eq!(r! { c(1, 2, 3)[] }, r! { c(1, 2, 3) })
+    }
+
+    #[test]
+    fn special_operator() {
+        r_expect! {{r#"
+            `%+%` <- function(a, b) paste(a, b)
+            ("a" %+% "b") == "a b"
+        "#}}
+    }
+
+    #[test]
+    fn operator_dispatch_on_lhs() {
+        r_expect! {{r#"
+            "+.money" <- function(e1, e2) "added"
+            structure(1, class = "money") + 2 == "added"
+        "#}}
+    }
+
+    #[test]
+    fn operator_dispatch_on_rhs() {
+        r_expect! {{r#"
+            "*.money" <- function(e1, e2) e2
+            x <- 2 * structure(3, class = "money")
+            unclass(x) == 3
+        "#}}
+    }
+
+    #[test]
+    fn operator_dispatch_modulo() {
+        r_expect! {{r#"
+            "%%.money" <- function(e1, e2) "remainder"
+            structure(5, class = "money") %% 2 == "remainder"
+        "#}}
+    }
+
+    #[test]
+    fn operator_dispatch_unary() {
+        r_expect! {{r#"
+            "-.money" <- function(e1) "negated"
+            x <- -structure(1, class = "money")
+            x == "negated"
+        "#}}
+    }
+
+    #[test]
+    fn operator_falls_back_without_method() {
+        r_expect! {{r#"
+            unclass(structure(1, class = "money") + 2) == 3
+        "#}}
+    }
 }
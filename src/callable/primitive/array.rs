@@ -0,0 +1,98 @@
+use r_derive::*;
+
+use super::matrix::reshape;
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Create an Array
+///
+/// Creates an array of any number of dimensions from a vector of values,
+/// recycling the values to fill every cell in column-major order.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// array(data = NA, dim = length(data))
+/// ```
+///
+/// ## Arguments
+///
+/// `data`: A vector of values.
+/// `dim`: A `numeric` vector of dimension extents.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// array(1:12, dim = c(2, 3, 2))
+/// ```
+///
+#[doc(alias = "array")]
+#[builtin(sym = "array")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveArray;
+
+impl Callable for PrimitiveArray {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("data".to_string()), Expr::NA),
+            (Some("dim".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let Obj::Vector(data) = args.try_get_named("data")?.force(stack)? else {
+            return Error::Other("'data' must be of a vector type".into()).into();
+        };
+
+        let dim: Vec<usize> = match args.try_get_named("dim") {
+            Err(_) => vec![data.len()],
+            Ok(dim) => {
+                let dim: Vec<f64> = dim.force(stack)?.try_into()?;
+                if dim.iter().any(|d| d.is_nan() || *d < 0.0) {
+                    let msg = "negative length vectors are not allowed";
+                    return Error::Other(msg.into()).into();
+                }
+                dim.into_iter().map(|d| d as usize).collect()
+            }
+        };
+
+        let n = dim.iter().product();
+        Ok(Obj::Vector(reshape(data, 0..n, &dim)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{r, r_expect};
+
+    #[test]
+    fn three_dimensions() {
+        r_expect! {{r#"
+            a <- array(1:24, dim = c(2, 3, 4))
+            a[2, 3, 4] == 24 && a[1, 2, 3] == 15
+        "#}}
+    }
+
+    #[test]
+    fn implicit_class() {
+        assert_eq!(r! { class(array(1:8, c(2, 2, 2))) }, r! { "array" })
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", r! { array(1:8, c(2, 2, 2)) }.unwrap()),
+            concat!(
+                ", , 1\n\n",
+                "     [,1] [,2]\n[1,]    1    3\n[2,]    2    4\n\n",
+                ", , 2\n\n",
+                "     [,1] [,2]\n[1,]    5    7\n[2,]    6    8",
+            )
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Get an Object Attribute
+///
+/// Retrieve a single attribute of an object, such as its `names`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// attr(x, which)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose attribute to retrieve.
+/// `which`: The name of the attribute, as a `character` value.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- structure(1:3, units = "cm")
+/// attr(x, "units")
+/// ```
+///
+#[doc(alias = "attr")]
+#[builtin(sym = "attr")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAttr;
+
+impl Callable for PrimitiveAttr {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("which".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let which = attr_name(args.try_get_named("which")?.force(stack)?)?;
+        Ok(x.get_attr(&which).unwrap_or_default())
+    }
+}
+
+/// Interpret an object as the name of an attribute
+pub fn attr_name(which: Obj) -> Result<String, Signal> {
+    match which {
+        Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => Ok(v.into()),
+        _ => Error::Other("'which' must be of mode character".into()).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn attr_from_structure() {
+        r_expect! {{r#"
+            x <- structure(1:3, units = "cm")
+            attr(x, "units") == "cm"
+        "#}}
+    }
+
+    #[test]
+    fn missing_attr_is_null() {
+        assert_eq!(r! { attr(1:3, "units") }, r! { null })
+    }
+
+    #[test]
+    fn names_of_list() {
+        assert_eq!(r! { attr(list(a = 1, b = 2), "names") }, r! { c("a", "b") })
+    }
+
+    #[test]
+    fn which_must_be_character() {
+        assert_eq!(
+            r! { attr(1:3, 1) },
+            Error::Other("'which' must be of mode character".to_string()).into()
+        )
+    }
+}
@@ -0,0 +1,107 @@
+use r_derive::*;
+
+use super::attr::attr_name;
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Set an Object Attribute
+///
+/// Set a single attribute of an object. Setting an attribute to `NULL`
+/// removes it.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// attr(x, which) <- value
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose attribute to set.
+/// `which`: The name of the attribute, as a `character` value.
+/// `value`: The new value of the attribute.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- 1:3
+/// attr(x, "units") <- "cm"
+/// x
+/// ```
+///
+#[doc(alias = "attr<-")]
+#[builtin(sym = "attr<-")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAttrAssign;
+
+impl Callable for PrimitiveAttrAssign {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("which".to_string()), Expr::Missing),
+            (Some("value".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let which = attr_name(args.try_get_named("which")?.force(stack)?)?;
+        let value = args.try_get_named("value")?.force(stack)?;
+        x.set_attr(&which, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn set_attr() {
+        r_expect! {{r#"
+            x <- 1:3
+            attr(x, "units") <- "cm"
+            attr(x, "units") == "cm"
+        "#}}
+    }
+
+    #[test]
+    fn remove_attr() {
+        assert_eq!(
+            r! {{r#"
+                x <- structure(1:3, units = "cm")
+                attr(x, "units") <- null
+                attributes(x)
+            "#}},
+            r! { null }
+        )
+    }
+
+    #[test]
+    fn copy_on_write() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1:3
+                y <- x
+                attr(y, "units") <- "cm"
+                attr(x, "units")
+            "#}},
+            r! { null }
+        )
+    }
+
+    #[test]
+    fn null_has_no_attributes() {
+        assert_eq!(
+            r! {{r#"
+                x <- null
+                attr(x, "units") <- "cm"
+            "#}},
+            Error::Other("attempt to set an attribute on NULL".to_string()).into()
+        )
+    }
+}
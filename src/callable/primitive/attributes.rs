@@ -0,0 +1,70 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Get All Object Attributes
+///
+/// Retrieve all attributes of an object as a named `list`, or `NULL` if
+/// the object has no attributes.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// attributes(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose attributes to retrieve.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// attributes(structure(1:3, units = "cm"))
+/// ```
+///
+#[doc(alias = "attributes")]
+#[builtin(sym = "attributes")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAttributes;
+
+impl Callable for PrimitiveAttributes {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        Ok(x.attributes().map_or(Obj::Null, Obj::List))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn no_attributes() {
+        assert_eq!(r! { attributes(1:3) }, r! { null })
+    }
+
+    #[test]
+    fn all_attributes() {
+        assert_eq!(
+            r! { attributes(structure(1:2, names = c("a", "b"), units = "cm")) },
+            r! { list(names = c("a", "b"), units = "cm") }
+        )
+    }
+
+    #[test]
+    fn list_names() {
+        assert_eq!(
+            r! { attributes(list(a = 1, 2)) },
+            r! { list(names = c("a", NA)) }
+        )
+    }
+}
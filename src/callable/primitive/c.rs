@@ -50,6 +50,15 @@ impl Callable for PrimitiveC {
             unreachable!()
         };
 
+        // dispatch on the class of the first argument
+        let first = vals.values.borrow().first().map(|(_, v)| v.clone());
+        if let Some(first) = first {
+            let args = vals.values.iter().collect();
+            if let Some(result) = stack.dispatch("c", &first, args) {
+                return result;
+            }
+        }
+
         // lets first see what we're aiming to build.
         let ty: u8 = vals
             .values
@@ -95,9 +104,27 @@ impl Callable for PrimitiveC {
                 (v @ Vector::Logical(_), _) => v,
             });
 
+        // names are derived from argument names and element names
+        let any_names = vals
+            .values
+            .borrow()
+            .iter()
+            .any(|(k, v)| k.is_some() || v.get_attr("names").is_some());
+
+        let names = if any_names {
+            Some(
+                vals.values
+                    .iter()
+                    .flat_map(|(k, v)| element_names(&k, &v))
+                    .collect::<Vec<String>>(),
+            )
+        } else {
+            None
+        };
+
         // consume values and merge into a new collection
-        match ret {
-            Vector::Character(_) => Ok(Obj::Vector(Vector::from(
+        let result = match ret {
+            Vector::Character(_) => Obj::Vector(Vector::from(
                 Vec::<OptionNA<String>>::new()
                     .into_iter()
                     .chain(
@@ -109,8 +136,8 @@ impl Callable for PrimitiveC {
                             }),
                     )
                     .collect::<Vec<Character>>(),
-            ))),
-            Vector::Double(_) => Ok(Obj::Vector(Vector::from(
+            )),
+            Vector::Double(_) => Obj::Vector(Vector::from(
                 Vec::<OptionNA<f64>>::new()
                     .into_iter()
                     .chain(
@@ -122,8 +149,8 @@ impl Callable for PrimitiveC {
                             }),
                     )
                     .collect::<Vec<Double>>(),
-            ))),
-            Vector::Integer(_) => Ok(Obj::Vector(Vector::from(
+            )),
+            Vector::Integer(_) => Obj::Vector(Vector::from(
                 Vec::<OptionNA<i32>>::new()
                     .into_iter()
                     .chain(
@@ -135,8 +162,8 @@ impl Callable for PrimitiveC {
                             }),
                     )
                     .collect::<Vec<Integer>>(),
-            ))),
-            Vector::Logical(_) => Ok(Obj::Vector(Vector::from(
+            )),
+            Vector::Logical(_) => Obj::Vector(Vector::from(
                 Vec::<OptionNA<bool>>::new()
                     .into_iter()
                     .chain(
@@ -148,7 +175,76 @@ impl Callable for PrimitiveC {
                             }),
                     )
                     .collect::<Vec<Logical>>(),
-            ))),
+            )),
+        };
+
+        match names {
+            Some(names) => result.set_attr("names", names.into()),
+            None => Ok(result),
         }
     }
 }
+
+/// Concatenate values, as `c()` would
+pub fn combine(values: Vec<Obj>, stack: &mut CallStack) -> EvalResult {
+    combine_named(values.into_iter().map(|v| (None, v)).collect(), stack)
+}
+
+/// Concatenate named values, as `c()` would
+pub fn combine_named(values: Vec<(Option<String>, Obj)>, stack: &mut CallStack) -> EvalResult {
+    let c = Obj::Function(
+        ExprList::new(),
+        Expr::Primitive(Box::new(PrimitiveC)),
+        stack.env(),
+    );
+    stack.call_with(c, values)
+}
+
+/// Determine the names of concatenated elements
+///
+/// Following R, an argument name is used as-is for single values, is
+/// suffixed with an index for unnamed vector elements, and prefixes the
+/// names of named vector elements.
+///
+fn element_names(name: &Option<String>, value: &Obj) -> Vec<String> {
+    let n = value.len().unwrap_or(0);
+    let inner: Option<Vec<String>> = match value.get_attr("names") {
+        Some(Obj::Vector(v)) => Some(v.into()),
+        _ => None,
+    };
+
+    match (name, inner) {
+        (Some(name), Some(inner)) => inner.iter().map(|i| format!("{name}.{i}")).collect(),
+        (Some(name), None) if n == 1 => vec![name.clone()],
+        (Some(name), None) => (1..=n).map(|i| format!("{name}{i}")).collect(),
+        (None, Some(inner)) => inner,
+        (None, None) => vec![String::new(); n],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn dispatch_on_first_argument() {
+        assert_eq!(
+            r! {{r#"
+                c.a <- function(...) length(list(...))
+                c(structure(1, class = "a"), 2, 3)
+            "#}},
+            r! { 3L }
+        )
+    }
+
+    #[test]
+    fn no_dispatch_on_later_arguments() {
+        assert_eq!(
+            r! {{r#"
+                c.a <- function(...) "method"
+                c(1, structure(2, class = "a"))
+            "#}},
+            r! { c(1, 2) }
+        )
+    }
+}
@@ -1,9 +1,47 @@
+use std::rc::Rc;
+
 use r_derive::builtin;
 
 use crate::callable::core::*;
-use crate::lang::{CallStack, EvalResult};
+use crate::error::Error;
+use crate::lang::{CallStack, EvalResult, Signal};
 use crate::object::*;
 
+/// The index of the frame from which the function of a frame was called
+///
+/// The caller's environment is that of the preceding frame, and its frame
+/// is the first to have introduced that environment.
+fn caller_frame(stack: &CallStack, frame: usize) -> usize {
+    if frame == 0 {
+        return 0;
+    }
+
+    let env = &stack.frames[frame - 1].env;
+    stack
+        .frames
+        .iter()
+        .position(|f| Rc::ptr_eq(&f.env, env))
+        .unwrap_or(0)
+}
+
+/// The frame of a function call, counting from the current call for
+/// non-positive values of `which` and from the global frame otherwise
+fn which_frame(which: i32, stack: &CallStack) -> Result<usize, Signal> {
+    let mut frame = stack.origin_frame();
+    if which > 0 {
+        frame = which as usize;
+        if frame >= stack.frames.len() {
+            return Error::Other("not that many frames on the stack".into()).into();
+        }
+    } else {
+        for _ in 0..which.unsigned_abs() {
+            frame = caller_frame(stack, frame);
+        }
+    }
+
+    Ok(frame)
+}
+
 /// Get the Current Call Stack
 ///
 /// Returns a list of frames in the call stack.
@@ -45,3 +83,215 @@ impl Callable for PrimitiveCallstack {
         )))
     }
 }
+
+/// Get the Environment of a Calling Frame
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// parent.frame(n = 1)
+/// ```
+///
+/// ## Arguments
+///
+/// `n`: The number of calls to go back. `1` is the environment from which
+///   the current function was called.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- function() { x <- "f"; g() }
+/// g <- function() get("x", envir = parent.frame())
+/// f()
+/// ```
+///
+#[doc(alias = "parent.frame")]
+#[builtin(sym = "parent.frame")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveParentFrame;
+impl Callable for PrimitiveParentFrame {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("n".to_string()), Expr::Number(1.0))])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let n: i32 = args.try_get_named("n")?.force(stack)?.try_into()?;
+        if n < 1 {
+            return Error::Other("invalid 'n' value".into()).into();
+        }
+
+        let mut frame = stack.origin_frame();
+        for _ in 0..n {
+            frame = caller_frame(stack, frame);
+        }
+
+        Ok(Obj::Environment(stack.frames[frame].env.clone()))
+    }
+}
+
+/// Get the Call of a Frame
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sys.call(which = 0)
+/// ```
+///
+/// ## Arguments
+///
+/// `which`: The frame, either the current call when `0`, a number of calls
+///   to go back when negative, or a frame number counting from the global
+///   frame when positive.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- function(x) sys.call()
+/// f(1 + 2)
+/// ```
+///
+#[doc(alias = "sys.call")]
+#[builtin(sym = "sys.call")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSysCall;
+impl Callable for PrimitiveSysCall {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("which".to_string()), Expr::Number(0.0))])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let which: i32 = args.try_get_named("which")?.force(stack)?.try_into()?;
+        match which_frame(which, stack)? {
+            0 => Ok(Obj::Null),
+            frame => Ok(Obj::Expr(stack.frames[frame].call.clone())),
+        }
+    }
+}
+
+/// Get the Function of a Frame
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sys.function(which = 0)
+/// ```
+///
+/// ## Arguments
+///
+/// `which`: The frame, as for `sys.call()`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- function() sys.function()
+/// f()
+/// ```
+///
+#[doc(alias = "sys.function")]
+#[builtin(sym = "sys.function")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSysFunction;
+impl Callable for PrimitiveSysFunction {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("which".to_string()), Expr::Number(0.0))])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let which: i32 = args.try_get_named("which")?.force(stack)?.try_into()?;
+        match which_frame(which, stack)? {
+            0 => Error::Other("not that many frames on the stack".into()).into(),
+            frame => Ok(stack.frames[frame].to.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{r, r_expect};
+
+    #[test]
+    fn parent_frame_of_caller() {
+        assert_eq!(
+            r! {{ r#"
+                f <- function() { x <- "f"; g() }
+                g <- function() { x <- "g"; get("x", envir = parent.frame()) }
+                f()
+            "# }},
+            r! { "f" }
+        );
+        r_expect! {{"
+            f <- function() parent.frame()
+            f() == globalenv()
+        "}}
+    }
+
+    #[test]
+    fn parent_frame_generations() {
+        assert_eq!(
+            r! {{ r#"
+                x <- "global"
+                f <- function() { x <- "f"; g() }
+                g <- function() h()
+                h <- function() get("x", envir = parent.frame(2))
+                f()
+            "# }},
+            r! { "f" }
+        )
+    }
+
+    #[test]
+    fn parent_frame_as_default() {
+        assert_eq!(
+            r! {{ r#"
+                setter <- function(value, envir = parent.frame()) assign("y", value, envir = envir)
+                f <- function() { setter(3); y }
+                f()
+            "# }},
+            r! { 3 }
+        )
+    }
+
+    #[test]
+    fn sys_call_of_function() {
+        assert_eq!(
+            r! {{ r#"
+                f <- function(x) sys.call()
+                f(1 + 2)
+            "# }},
+            r! { quote(f(1 + 2)) }
+        );
+        assert_eq!(r! { sys.call() }, r! { NULL })
+    }
+
+    #[test]
+    fn sys_call_of_caller() {
+        assert_eq!(
+            r! {{ r#"
+                f <- function() g()
+                g <- function() sys.call(-1)
+                f()
+            "# }},
+            r! { quote(f()) }
+        )
+    }
+
+    #[test]
+    fn sys_function_recursion() {
+        assert_eq!(
+            r! {{ r#"
+                fact <- function(n) if (n <= 1) 1 else n * sys.function()(n - 1)
+                fact(5)
+            "# }},
+            r! { 120 }
+        )
+    }
+}
@@ -0,0 +1,203 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+use super::c::combine;
+use super::matrix::reshape;
+
+/// Combine Objects by Columns
+///
+/// Combines vectors and matrices as the columns of a matrix, recycling
+/// vectors to the number of rows. When any argument is a data frame, the
+/// columns of each argument are combined into a data frame instead.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// cbind(...)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Vectors, matrices or data frames to combine.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// cbind(1:3, 4:6)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// cbind(data.frame(x = 1:2), y = c("a", "b"))
+/// ```
+///
+#[doc(alias = "cbind")]
+#[builtin(sym = "cbind")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCbind;
+
+impl Callable for PrimitiveCbind {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let Obj::List(vals) = stack.eval_list_eager(args)? else {
+            unreachable!()
+        };
+
+        let vals: Vec<(Option<String>, Obj)> = vals.values.iter().collect();
+        let any_frames = vals
+            .iter()
+            .any(|(_, v)| matches!(v, Obj::List(l) if l.is_data_frame()));
+
+        if !any_frames {
+            return bind(vals.into_iter().map(|(_, v)| v).collect(), false, stack);
+        }
+
+        let mut columns: Vec<(String, Vector)> = vec![];
+        for (name, value) in vals {
+            match value {
+                Obj::List(l) if l.is_data_frame() => columns.extend(l.columns()),
+                Obj::Vector(v) => {
+                    let name = name.unwrap_or_else(|| format!("V{}", columns.len() + 1));
+                    columns.push((name, v));
+                }
+                Obj::Null => (),
+                _ => return Error::Other("cannot bind objects of this type".into()).into(),
+            }
+        }
+
+        Ok(Obj::List(List::data_frame(columns)?))
+    }
+}
+
+/// Bind vectors and matrices into a matrix
+///
+/// Each matrix contributes its columns (or rows, when binding by row),
+/// while each vector contributes a single column (or row), recycled to
+/// the extent shared by all arguments.
+///
+pub fn bind(values: Vec<Obj>, byrow: bool, stack: &mut CallStack) -> EvalResult {
+    let values: Vec<Obj> = values
+        .into_iter()
+        .filter(|v| !matches!(v, Obj::Null))
+        .collect();
+
+    // each argument's (0-indexed) cells, by their position along the
+    // shared extent and their position along the bound extent
+    let mut pieces: Vec<(usize, usize, Option<usize>)> = vec![];
+    for value in values.iter() {
+        let Obj::Vector(v) = value else {
+            return Error::Other("cannot bind objects of this type".into()).into();
+        };
+
+        pieces.push(match v.attributes().dim() {
+            Some(dim) if dim.len() == 2 && byrow => (dim[1], dim[0], Some(dim[0])),
+            Some(dim) if dim.len() == 2 => (dim[0], dim[1], Some(dim[0])),
+            _ => (v.len(), 1, None),
+        });
+    }
+
+    if pieces.is_empty() {
+        return Ok(Obj::Null);
+    }
+
+    // the first matrix determines the shared extent, falling back to the
+    // longest vector
+    let n = match pieces.iter().find(|(.., nrow)| nrow.is_some()) {
+        Some((shared, ..)) => *shared,
+        None => pieces.iter().map(|(shared, ..)| *shared).max().unwrap_or(0),
+    };
+
+    let mut lines: Vec<Vec<usize>> = vec![];
+    let mut offset = 0;
+    for (i, (shared, count, nrow)) in pieces.into_iter().enumerate() {
+        match nrow {
+            Some(_) if shared != n => {
+                let margin = if byrow { "columns" } else { "rows" };
+                let msg = format!(
+                    "number of {margin} of matrices must match (see arg {})",
+                    i + 1
+                );
+                return Error::Other(msg).into();
+            }
+            Some(nrow) => {
+                for t in 0..count {
+                    lines.push(match byrow {
+                        true => (0..n).map(|s| offset + t + s * nrow).collect(),
+                        false => (0..n).map(|s| offset + s + t * nrow).collect(),
+                    });
+                }
+            }
+            None if shared == 0 => (),
+            None => lines.push((0..n).map(|s| offset + s % shared).collect()),
+        }
+
+        offset += shared * count;
+    }
+
+    let Obj::Vector(data) = combine(values, stack)? else {
+        return Error::Other("cannot bind objects of this type".into()).into();
+    };
+
+    let m = lines.len();
+    match byrow {
+        true => {
+            let indices = (0..n).flat_map(|s| lines.iter().map(move |line| line[s]));
+            Ok(Obj::Vector(reshape(data, indices, &[m, n])))
+        }
+        false => {
+            let indices = lines.iter().flatten().copied();
+            Ok(Obj::Vector(reshape(data, indices, &[n, m])))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn vectors_as_columns() {
+        r_expect! {{r#"
+            m <- cbind(1:3, 4:6)
+            nrow(m) == 3 && ncol(m) == 2 && m[2, 2] == 5
+        "#}}
+    }
+
+    #[test]
+    fn vectors_are_recycled() {
+        r_expect! {{r#"
+            m <- cbind(1:4, 0)
+            nrow(m) == 4 && ncol(m) == 2 && m[4, 2] == 0
+        "#}}
+    }
+
+    #[test]
+    fn matrices_and_vectors() {
+        r_expect! {{r#"
+            m <- cbind(matrix(1:4, nrow = 2), 5:6)
+            nrow(m) == 2 && ncol(m) == 3 && m[1, 3] == 5 && m[2, 2] == 4
+        "#}}
+    }
+
+    #[test]
+    fn mismatched_rows() {
+        assert_eq!(
+            r! { cbind(matrix(1:4, nrow = 2), matrix(1:6, nrow = 3)) },
+            Error::Other("number of rows of matrices must match (see arg 2)".into()).into()
+        )
+    }
+
+    #[test]
+    fn data_frame_columns() {
+        assert_eq!(
+            r! { names(cbind(data.frame(x = 1:2), y = c("a", "b"))) },
+            r! { c("x", "y") }
+        )
+    }
+}
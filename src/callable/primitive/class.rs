@@ -0,0 +1,71 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Get the Class of an Object
+///
+/// Returns the classes of an object as set by its `class` attribute or,
+/// for objects without one, the class implied by its type.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// class(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose class to retrieve.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// class(1)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// class(structure(list(), class = "shape"))
+/// ```
+///
+#[doc(alias = "class")]
+#[builtin(sym = "class")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveClass;
+
+impl Callable for PrimitiveClass {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        Ok(x.class().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn implicit_class() {
+        assert_eq!(r! { class(1) }, r! { "numeric" });
+        assert_eq!(r! { class("a") }, r! { "character" });
+        assert_eq!(r! { class(true) }, r! { "logical" });
+        assert_eq!(r! { class(list()) }, r! { "list" });
+        assert_eq!(r! { class(null) }, r! { "NULL" });
+        assert_eq!(r! { class(function() 1) }, r! { "function" });
+    }
+
+    #[test]
+    fn explicit_class() {
+        assert_eq!(
+            r! { class(structure(1, class = c("a", "b"))) },
+            r! { c("a", "b") }
+        )
+    }
+}
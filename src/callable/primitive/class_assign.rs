@@ -0,0 +1,85 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Set the Class of an Object
+///
+/// Sets the `class` attribute of an object, determining the S3 methods it
+/// dispatches to. Setting the class to `NULL` removes it.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// class(x) <- value
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose class to set.
+/// `value`: A `character` vector of classes, or `NULL`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- list(side = 3)
+/// class(x) <- "square"
+/// class(x)
+/// ```
+///
+#[doc(alias = "class<-")]
+#[builtin(sym = "class<-")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveClassAssign;
+
+impl Callable for PrimitiveClassAssign {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("value".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let value = match args.try_get_named("value")?.force(stack)? {
+            Obj::Null => Obj::Null,
+            value => value.as_character()?,
+        };
+
+        x.set_attr("class", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn set_class() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1
+                class(x) <- "a"
+                class(x)
+            "#}},
+            r! { "a" }
+        )
+    }
+
+    #[test]
+    fn remove_class() {
+        assert_eq!(
+            r! {{r#"
+                x <- structure(1, class = "a")
+                class(x) <- null
+                class(x)
+            "#}},
+            r! { "numeric" }
+        )
+    }
+}
@@ -0,0 +1,657 @@
+use r_derive::*;
+
+use super::c::combine_named;
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// The values of an object as an atomic vector
+///
+/// `NULL` is an empty vector and lists of single values are combined into a
+/// vector of their most general type.
+///
+fn atomic(x: Obj, to: &'static str, stack: &mut CallStack) -> Result<Vector, Signal> {
+    match x {
+        Obj::Null => Ok(Vector::from(Vec::<Logical>::new())),
+        Obj::Vector(v) => Ok(v),
+        Obj::List(l) => {
+            let values = l.materialize().values.borrow().to_vec();
+            if values.is_empty() {
+                return Ok(Vector::from(Vec::<Logical>::new()));
+            }
+
+            if !values
+                .iter()
+                .all(|(_, v)| matches!(v, Obj::Vector(v) if v.len() == 1))
+            {
+                return Error::CannotBeCoercedTo(to).into();
+            }
+
+            match combine_named(values.into_iter().map(|(_, v)| (None, v)).collect(), stack)? {
+                Obj::Vector(v) => Ok(v),
+                _ => Error::CannotBeCoercedTo(to).into(),
+            }
+        }
+        _ => Error::CannotBeCoercedTo(to).into(),
+    }
+}
+
+/// A copy of a vector's values without its attributes
+fn bare(v: Vector) -> Vector {
+    match v {
+        Vector::Double(v) => Vector::from(v.iter().collect::<Vec<Double>>()),
+        Vector::Integer(v) => Vector::from(v.iter().collect::<Vec<Integer>>()),
+        Vector::Logical(v) => Vector::from(v.iter().collect::<Vec<Logical>>()),
+        Vector::Character(v) => Vector::from(v.iter().collect::<Vec<Character>>()),
+    }
+}
+
+/// Parse strings as numbers, ignoring surrounding whitespace
+///
+/// Warns when any string can't be parsed and becomes `NA`.
+///
+fn parse_doubles(x: &[Character], stack: &mut CallStack) -> Result<Vec<Double>, Signal> {
+    let trimmed: Vec<Character> = x
+        .iter()
+        .map(|xi| xi.clone().map(|s| s.trim().to_string()))
+        .collect();
+
+    let (any_new_nas, result) = Vector::vec_parse::<f64>(&trimmed);
+    if any_new_nas {
+        stack.warn("NAs introduced by coercion".to_string())?;
+    }
+
+    Ok(result)
+}
+
+/// Truncate doubles towards zero
+///
+/// `NaN`s become `NA`, as do values outside the range of integers with a
+/// warning.
+///
+fn truncate_doubles(x: Vec<Double>, stack: &mut CallStack) -> Result<Vec<Integer>, Signal> {
+    let mut out_of_range = false;
+    let result = x
+        .into_iter()
+        .map(|xi| match xi {
+            OptionNA::Some(x) if x.is_nan() => OptionNA::NA,
+            OptionNA::Some(x) if x.trunc().abs() > i32::MAX as f64 => {
+                out_of_range = true;
+                OptionNA::NA
+            }
+            OptionNA::Some(x) => OptionNA::Some(x.trunc() as i32),
+            OptionNA::NA => OptionNA::NA,
+        })
+        .collect();
+
+    if out_of_range {
+        stack.warn("NAs introduced by coercion to integer range".to_string())?;
+    }
+
+    Ok(result)
+}
+
+fn as_integer(x: Obj, stack: &mut CallStack) -> EvalResult {
+    let result = match atomic(x, "integer", stack)? {
+        Vector::Double(v) => Vector::from(truncate_doubles(v.iter().collect(), stack)?),
+        Vector::Character(v) => {
+            let doubles = parse_doubles(&v.iter().collect::<Vec<_>>(), stack)?;
+            Vector::from(truncate_doubles(doubles, stack)?)
+        }
+        v => bare(v.as_integer()),
+    };
+
+    Ok(Obj::Vector(result))
+}
+
+fn as_double(x: Obj, stack: &mut CallStack) -> EvalResult {
+    let result = match atomic(x, "double", stack)? {
+        Vector::Character(v) => Vector::from(parse_doubles(&v.iter().collect::<Vec<_>>(), stack)?),
+        v => bare(v.as_double()),
+    };
+
+    Ok(Obj::Vector(result))
+}
+
+fn x_formals() -> ExprList {
+    ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+}
+
+/// Coerce to Integer
+///
+/// Coerces an object to an `integer` vector without attributes. Doubles
+/// are truncated towards zero and strings are parsed as numbers, becoming
+/// `NA` with a warning where they can't be.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.integer(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to coerce.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// as.integer(c(1.9, -1.9))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// as.integer(c("12", "twelve"))
+/// ```
+///
+#[doc(alias = "as.integer")]
+#[builtin(sym = "as.integer")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsInteger;
+
+impl Callable for PrimitiveAsInteger {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        as_integer(x, stack)
+    }
+}
+
+/// Coerce to Double
+///
+/// Coerces an object to a `double` vector without attributes. Strings are
+/// parsed as numbers, becoming `NA` with a warning where they can't be.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.double(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to coerce.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// as.double(c(true, false))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// as.double(" 1.5 ")
+/// ```
+///
+#[doc(alias = "as.double")]
+#[builtin(sym = "as.double")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsDouble;
+
+impl Callable for PrimitiveAsDouble {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        as_double(x, stack)
+    }
+}
+
+/// Coerce to Numeric
+///
+/// An alias of `as.double()`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.numeric(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to coerce.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// as.numeric("3.14")
+/// ```
+///
+#[doc(alias = "as.numeric")]
+#[builtin(sym = "as.numeric")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsNumeric;
+
+impl Callable for PrimitiveAsNumeric {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        as_double(x, stack)
+    }
+}
+
+/// Coerce to Logical
+///
+/// Coerces an object to a `logical` vector without attributes. Numbers are
+/// `true` when non-zero, and the strings `"TRUE"`, `"true"`, `"True"` and
+/// `"T"`, or their `false` counterparts, are recognised. Any other string
+/// becomes `NA`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.logical(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to coerce.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// as.logical(c(0, 1, 2))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// as.logical(c("T", "false", "maybe"))
+/// ```
+///
+#[doc(alias = "as.logical")]
+#[builtin(sym = "as.logical")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsLogical;
+
+impl Callable for PrimitiveAsLogical {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let result: Vec<Logical> = match atomic(x, "logical", stack)? {
+            Vector::Double(v) => v
+                .iter()
+                .map(|xi| match xi {
+                    OptionNA::Some(x) if x.is_nan() => OptionNA::NA,
+                    xi => xi.map(|x| x != 0.0),
+                })
+                .collect(),
+            Vector::Character(v) => v
+                .iter()
+                .map(|xi| match xi {
+                    OptionNA::Some(s) => match s.as_str() {
+                        "TRUE" | "true" | "True" | "T" => OptionNA::Some(true),
+                        "FALSE" | "false" | "False" | "F" => OptionNA::Some(false),
+                        _ => OptionNA::NA,
+                    },
+                    OptionNA::NA => OptionNA::NA,
+                })
+                .collect(),
+            v => match v.as_logical() {
+                Vector::Logical(v) => v.iter().collect(),
+                _ => unreachable!(),
+            },
+        };
+
+        Ok(Obj::Vector(Vector::from(result)))
+    }
+}
+
+/// Coerce to Character
+///
+/// Coerces an object to a `character` vector without attributes.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.character(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to coerce.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// as.character(c(1.5, 2, NA))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// as.character(list(1L, "a", true))
+/// ```
+///
+#[doc(alias = "as.character")]
+#[builtin(sym = "as.character")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsCharacter;
+
+impl Callable for PrimitiveAsCharacter {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        Ok(Obj::Vector(bare(
+            atomic(x, "character", stack)?.as_character(),
+        )))
+    }
+}
+
+/// Coerce to List
+///
+/// Coerces an object to a `list`. Each value of a vector becomes an element
+/// of the list, keeping the vector's names, and the bindings of an
+/// environment become elements named by their symbols.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.list(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to coerce.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// as.list(c(a = 1, b = 2))
+/// ```
+///
+#[doc(alias = "as.list")]
+#[builtin(sym = "as.list")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsList;
+
+impl Callable for PrimitiveAsList {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        match x {
+            Obj::Vector(v) => {
+                let names: Option<Vec<String>> = match v.attributes().get("names") {
+                    Some(Obj::Vector(names)) => Some(names.into()),
+                    _ => None,
+                };
+
+                let values = (0..v.len())
+                    .map(|i| {
+                        let name = names.as_ref().map(|names| names[i].clone());
+                        let value = v.get(i).map_or(Obj::Null, |vi| Obj::Vector(bare(vi)));
+                        (name, value)
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(Obj::List(List::from(values)))
+            }
+            Obj::Environment(env) => {
+                let mut values: Vec<(Option<String>, Obj)> = env
+                    .values
+                    .borrow()
+                    .iter()
+                    .map(|(k, v)| match v {
+                        Obj::Promise(Some(value), ..) => (Some(k.clone()), (**value).clone()),
+                        v => (Some(k.clone()), v.clone()),
+                    })
+                    .collect();
+
+                values.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Ok(Obj::List(List::from(values)))
+            }
+            Obj::Function(..) | Obj::Promise(..) => Error::CannotBeCoercedTo("list").into(),
+            x => x.as_list(),
+        }
+    }
+}
+
+/// Flatten nested lists into named elements
+///
+/// Names of nested elements are prefixed by the names of the lists which
+/// contain them. Produces `None` if any element isn't a vector.
+///
+fn flatten(x: &List, prefix: Option<&str>, into: &mut Vec<(Option<String>, Obj)>) -> Option<()> {
+    let values = x.materialize().values.borrow().to_vec();
+    let n = values.len();
+
+    for (i, (name, value)) in values.into_iter().enumerate() {
+        let name = name.filter(|name| !name.is_empty());
+        let name = match (prefix, name) {
+            (Some(prefix), Some(name)) => Some(format!("{prefix}.{name}")),
+            (Some(prefix), None) if n == 1 => Some(prefix.to_string()),
+            (Some(prefix), None) => Some(format!("{prefix}{}", i + 1)),
+            (None, name) => name,
+        };
+
+        match value {
+            Obj::Null => (),
+            Obj::Vector(_) => into.push((name, value)),
+            Obj::List(l) => flatten(&l, name.as_deref(), into)?,
+            _ => return None,
+        }
+    }
+
+    Some(())
+}
+
+/// Flatten a List
+///
+/// Flattens a list, and any lists it contains, into an atomic vector of the
+/// most general type of its values. Lists containing values which aren't
+/// vectors are returned unchanged.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// unlist(x, use.names = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A list to flatten.
+/// `use.names`: Whether to name values after the elements containing them.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// unlist(list(a = 1, b = list(c = 2, d = 3)))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// unlist(list(1L, "a"), use.names = false)
+/// ```
+///
+#[doc(alias = "unlist")]
+#[builtin(sym = "unlist")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveUnlist;
+
+impl Callable for PrimitiveUnlist {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("use.names".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let use_names: bool = args.try_get_named("use.names")?.force(stack)?.try_into()?;
+
+        let Obj::List(l) = &x else {
+            return Ok(x);
+        };
+
+        let mut values = vec![];
+        if flatten(l, None, &mut values).is_none() {
+            return Ok(x);
+        }
+
+        if values.is_empty() {
+            return Ok(Obj::Null);
+        }
+
+        if !use_names {
+            values = values
+                .into_iter()
+                .map(|(_, v)| match v {
+                    Obj::Vector(v) => (None, Obj::Vector(bare(v))),
+                    v => (None, v),
+                })
+                .collect();
+        }
+
+        combine_named(values, stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+    use crate::lang::CallStack;
+    use crate::r;
+
+    #[test]
+    fn as_integer_truncates() {
+        assert_eq!(
+            r! { as.integer(c(1.9, -1.9, NA, 0 / 0)) },
+            r! { c(1L, -1L, NA, NA) }
+        );
+        assert_eq!(r! { as.integer(c(true, false)) }, r! { c(1L, 0L) })
+    }
+
+    #[test]
+    fn as_integer_parses() {
+        assert_eq!(r! { as.integer(c(" 12 ", "1e3")) }, r! { c(12L, 1000L) })
+    }
+
+    #[test]
+    fn coercion_warns_of_new_nas() {
+        let mut stack = CallStack::default();
+        let expr = stack
+            .parse("as.integer(c(\"12\", \"twelve\"))")
+            .expect("parse failed");
+        let value = stack.eval_and_finalize(expr).unwrap();
+
+        assert_eq!(value, r! { c(12L, NA) }.unwrap());
+        assert_eq!(
+            stack.flush_warnings(),
+            Some("Warning message:\nNAs introduced by coercion\n".to_string())
+        );
+    }
+
+    #[test]
+    fn coercion_of_na_does_not_warn() {
+        let mut stack = CallStack::default();
+        let expr = stack
+            .parse("as.double(c(\"1\", NA))")
+            .expect("parse failed");
+        stack.eval_and_finalize(expr).unwrap();
+
+        assert_eq!(stack.flush_warnings(), None);
+    }
+
+    #[test]
+    fn as_integer_out_of_range() {
+        let mut stack = CallStack::default();
+        let expr = stack.parse("as.integer(3000000000)").expect("parse failed");
+        let value = stack.eval_and_finalize(expr).unwrap();
+
+        assert_eq!(value, r! { as.integer(NA) }.unwrap());
+        assert_eq!(
+            stack.flush_warnings(),
+            Some("Warning message:\nNAs introduced by coercion to integer range\n".to_string())
+        );
+    }
+
+    #[test]
+    fn as_double_drops_attributes() {
+        assert_eq!(r! { as.double(c(a = 1L, b = 2L)) }, r! { c(1, 2) });
+        assert_eq!(r! { as.numeric("2.5") }, r! { 2.5 })
+    }
+
+    #[test]
+    fn as_logical_values() {
+        assert_eq!(r! { as.logical(c(0, 2, 0 / 0)) }, r! { c(false, true, NA) });
+        assert_eq!(
+            r! { as.logical(c("T", "false", "maybe")) },
+            r! { c(true, false, NA) }
+        )
+    }
+
+    #[test]
+    fn as_character_values() {
+        assert_eq!(r! { as.character(c(1.5, 2, NA)) }, r! { c("1.5", "2", NA) });
+        assert_eq!(
+            r! { as.character(list(1L, "a", true)) },
+            r! { c("1", "a", "true") }
+        )
+    }
+
+    #[test]
+    fn as_vector_of_list_requires_single_values() {
+        assert!(r! { as.integer(list(1, c(2, 3))) }.is_err());
+        assert!(r! { as.integer(function() 1) }.is_err())
+    }
+
+    #[test]
+    fn as_list_of_vector() {
+        assert_eq!(r! { as.list(c(a = 1, b = 2)) }, r! { list(a = 1, b = 2) });
+        assert_eq!(r! { as.list(null) }, r! { list() })
+    }
+
+    #[test]
+    fn unlist_flattens() {
+        assert_eq!(
+            r! { unlist(list(a = 1, b = list(c = 2, d = 3L))) },
+            r! { c(a = 1, b.c = 2, b.d = 3) }
+        );
+        assert_eq!(
+            r! { unlist(list(a = 1:2, list("x"))) },
+            r! { c(a1 = "1", a2 = "2", "x") }
+        )
+    }
+
+    #[test]
+    fn unlist_without_names() {
+        assert_eq!(
+            r! { unlist(list(a = 1, b = 2), use.names = false) },
+            r! { c(1, 2) }
+        )
+    }
+
+    #[test]
+    fn unlist_keeps_non_vectors() {
+        assert_eq!(r! { length(unlist(list(1, function() 1))) }, r! { 2L });
+        assert_eq!(r! { unlist(list()) }, r! { null })
+    }
+}
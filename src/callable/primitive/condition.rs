@@ -0,0 +1,157 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Build a condition object
+///
+/// Conditions are lists of a `message` and the `call` which raised them,
+/// classed by their kind of condition (`"error"`, `"warning"` or
+/// `"message"`), as in `c("simpleError", "error", "condition")`.
+///
+pub fn condition(kind: &str, message: String, call: Obj) -> EvalResult {
+    let simple = format!("simple{}{}", kind[..1].to_uppercase(), &kind[1..]);
+    let class = vec![simple, kind.to_string(), "condition".to_string()];
+
+    Obj::List(List::from(vec![
+        (
+            Some("message".to_string()),
+            Obj::Vector(Vector::from(vec![message])),
+        ),
+        (Some("call".to_string()), call),
+    ]))
+    .set_attr("class", Obj::Vector(Vector::from(class)))
+}
+
+/// Get an element of a condition object
+fn condition_element(args: List, name: &str, stack: &mut CallStack) -> EvalResult {
+    let mut args = Obj::List(args);
+    match args.try_get_named("c")?.force(stack)? {
+        mut c @ Obj::List(_) => c.try_get_named(name),
+        _ => Error::Other("argument is not a condition".into()).into(),
+    }
+}
+
+/// Get the Message of a Condition
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// conditionMessage(c)
+/// ```
+///
+/// ## Arguments
+///
+/// `c`: A condition object, as is passed to the handlers of `tryCatch()`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// tryCatch(stop("oh no"), error = fn(e) conditionMessage(e))
+/// ```
+///
+#[doc(alias = "conditionMessage")]
+#[builtin(sym = "conditionMessage")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveConditionMessage;
+impl Callable for PrimitiveConditionMessage {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("c".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        condition_element(args, "message", stack)
+    }
+}
+
+/// Get the Call of a Condition
+///
+/// Conditions raised by `stop()`, `warning()` and `message()` are raised
+/// from the call to the function which called them. Conditions raised by
+/// builtins are raised from the call to the builtin.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// conditionCall(c)
+/// ```
+///
+/// ## Arguments
+///
+/// `c`: A condition object, as is passed to the handlers of `tryCatch()`.
+///
+/// ## Value
+///
+/// The call which raised the condition, or `NULL` when it was raised
+/// outside of a function call.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- fn(x) stop("oh no")
+/// tryCatch(f(1), error = fn(e) conditionCall(e))
+/// ```
+///
+#[doc(alias = "conditionCall")]
+#[builtin(sym = "conditionCall")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveConditionCall;
+impl Callable for PrimitiveConditionCall {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("c".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        condition_element(args, "call", stack)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::r;
+
+    #[test]
+    fn condition_message() {
+        assert_eq!(
+            r! { tryCatch(stop("oh no"), error = fn(e) conditionMessage(e)) },
+            r! { "oh no" }
+        )
+    }
+
+    #[test]
+    fn condition_call() {
+        assert_eq!(
+            r! {{r#"
+                f <- fn(x) stop("oh no")
+                tryCatch(f(1), error = fn(e) conditionCall(e))
+            "#}},
+            r! { quote(f(1)) }
+        );
+        assert_eq!(
+            r! { tryCatch(stop("oh no"), error = fn(e) conditionCall(e)) },
+            r! { NULL }
+        );
+    }
+
+    #[test]
+    fn builtin_condition_call() {
+        assert_eq!(
+            r! { tryCatch(log(-1), warning = fn(w) conditionCall(w)) },
+            r! { quote(log(-1)) }
+        )
+    }
+
+    #[test]
+    fn condition_class() {
+        assert_eq!(
+            r! { tryCatch(warning("!"), warning = fn(w) class(w)) },
+            r! { c("simpleWarning", "warning", "condition") }
+        )
+    }
+}
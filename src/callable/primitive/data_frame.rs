@@ -0,0 +1,211 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Create a Data Frame
+///
+/// Creates a data frame from a collection of columns. Columns are recycled
+/// to a common number of rows.
+///
+/// Columns are named by their argument names or, for unnamed symbols, by
+/// the symbol itself. Data frame and list arguments contribute each of
+/// their elements as columns.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// data.frame(...)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Vectors, lists or data frames of columns.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// data.frame(x = 1:3, y = c("a", "b", "c"))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// data.frame(x = 1:4, group = c("a", "b"))
+/// ```
+///
+#[doc(alias = "data.frame")]
+#[builtin(sym = "data.frame")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDataFrame;
+
+impl Callable for PrimitiveDataFrame {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let mut columns: Vec<(String, Vector)> = vec![];
+
+        for (name, expr) in args {
+            // unnamed symbols name their column
+            let name = match (name, &expr) {
+                (None, Expr::Symbol(sym)) => Some(sym.clone()),
+                (name, _) => name,
+            };
+
+            let Obj::List(values) = stack.eval_list_eager(ExprList::from(vec![(name, expr)]))?
+            else {
+                unreachable!()
+            };
+
+            for (name, value) in values.values.iter() {
+                match value {
+                    Obj::Vector(v) => {
+                        let name = name.unwrap_or_else(|| format!("V{}", columns.len() + 1));
+                        columns.push((name, v));
+                    }
+                    Obj::List(l) => {
+                        for (i, (k, v)) in l.values.iter().enumerate() {
+                            let Obj::Vector(v) = v else {
+                                return Error::Other(
+                                    "cannot coerce list element to a data frame column".into(),
+                                )
+                                .into();
+                            };
+
+                            let k = match (&name, k) {
+                                (_, Some(k)) if l.is_data_frame() => k,
+                                (Some(name), Some(k)) => format!("{name}.{k}"),
+                                (None, Some(k)) => k,
+                                (Some(name), None) => format!("{name}.{}", i + 1),
+                                (None, None) => format!("V{}", columns.len() + 1),
+                            };
+
+                            columns.push((k, v));
+                        }
+                    }
+                    Obj::Null => (),
+                    _ => {
+                        return Error::Other("cannot coerce value to a data frame column".into())
+                            .into();
+                    }
+                }
+            }
+        }
+
+        Ok(Obj::List(List::data_frame(columns)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn columns_named_by_arguments() {
+        assert_eq!(
+            r! { names(data.frame(x = 1:3, y = c("a", "b", "c"))) },
+            r! { c("x", "y") }
+        )
+    }
+
+    #[test]
+    fn columns_named_by_symbols() {
+        assert_eq!(
+            r! {{"
+                a <- 1:3
+                names(data.frame(a, b = a * 2))
+            "}},
+            r! { c("a", "b") }
+        )
+    }
+
+    #[test]
+    fn columns_are_recycled() {
+        assert_eq!(
+            r! { data.frame(x = 1:4, y = c("a", "b"))$y },
+            r! { c("a", "b", "a", "b") }
+        )
+    }
+
+    #[test]
+    fn differing_rows_error() {
+        assert_eq!(
+            r! { data.frame(x = 1:3, y = 1:2) },
+            Error::Other("arguments imply differing number of rows: 3, 2".into()).into()
+        );
+        assert_eq!(
+            r! { data.frame(a = 1:2, b = 1:3) },
+            Error::Other("arguments imply differing number of rows: 2, 3".into()).into()
+        )
+    }
+
+    #[test]
+    fn data_frames_contribute_columns() {
+        r_expect! {{r#"
+            df <- data.frame(data.frame(x = 1:2), y = 3:4)
+            names(df)[[1]] == "x" && names(df)[[2]] == "y" && nrow(df) == 2
+        "#}}
+    }
+
+    #[test]
+    fn indexing() {
+        r_expect! {{r#"
+            df <- data.frame(x = 1:3, y = c("a", "b", "c"))
+            df[[1]][[3]] == 3 && df[2, "y"] == "b" && df[[2]][[3]] == "c"
+        "#}}
+    }
+
+    #[test]
+    fn column_selection_drops_to_vector() {
+        assert_eq!(
+            r! { data.frame(x = 1:3, y = c("a", "b", "c"))[, "x"] },
+            r! { 1:3 }
+        )
+    }
+
+    #[test]
+    fn column_selection_without_drop() {
+        assert_eq!(
+            r! { class(data.frame(x = 1:3, y = c("a", "b", "c"))[, "x", drop = FALSE]) },
+            r! { "data.frame" }
+        )
+    }
+
+    #[test]
+    fn row_selection_keeps_data_frame() {
+        r_expect! {{r#"
+            df <- data.frame(x = 1:3, y = c("a", "b", "c"))
+            row <- df[2, ]
+            class(row) == "data.frame" && nrow(row) == 1 && row$y == "b"
+        "#}}
+    }
+
+    #[test]
+    fn dimensions() {
+        r_expect! {{r#"
+            df <- data.frame(x = 1:3, y = 4:6, z = 7:9)
+            nrow(df) == 3 && ncol(df) == 3 && length(dim(df)) == 2
+        "#}}
+    }
+
+    #[test]
+    fn undefined_columns() {
+        assert_eq!(
+            r! { data.frame(x = 1:3)[, "y"] },
+            Error::Other("undefined columns selected".into()).into()
+        )
+    }
+
+    #[test]
+    fn printing() {
+        assert_eq!(
+            format!(
+                "{}",
+                r! { data.frame(x = c(1, 10), name = c("a", NA)) }.unwrap()
+            ),
+            "   x name\n1  1    a\n2 10 <NA>"
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Get the Dimensions of an Object
+///
+/// Returns the extent of each dimension of a matrix, array or data frame,
+/// or `NULL` for objects without dimensions.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dim(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose dimensions to retrieve.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dim(matrix(1:6, nrow = 2))
+/// ```
+///
+#[doc(alias = "dim")]
+#[builtin(sym = "dim")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDim;
+
+impl Callable for PrimitiveDim {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        match x.dim() {
+            Some(dim) => Ok(dim.into_iter().map(|d| d as i32).collect::<Vec<_>>().into()),
+            None => Ok(Obj::Null),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn matrix_dim() {
+        assert_eq!(r! { dim(matrix(1:6, nrow = 2)) }, r! { c(2L, 3L) })
+    }
+
+    #[test]
+    fn vector_dim() {
+        assert_eq!(r! { dim(1:6) }, r! { null })
+    }
+}
@@ -0,0 +1,93 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Set the Dimensions of an Object
+///
+/// Sets the `dim` attribute of a vector, turning it into a matrix or array
+/// whose values fill each dimension in column-major order. The product of
+/// the dimensions must equal the length of the vector. Setting dimensions
+/// to `NULL` removes them.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dim(x) <- value
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector whose dimensions to set.
+/// `value`: A `numeric` vector of dimension extents, or `NULL`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- 1:6
+/// dim(x) <- c(2, 3)
+/// x
+/// ```
+///
+#[doc(alias = "dim<-")]
+#[builtin(sym = "dim<-")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDimAssign;
+
+impl Callable for PrimitiveDimAssign {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("value".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let value = args.try_get_named("value")?.force(stack)?;
+        x.set_attr("dim", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn set_dim() {
+        r_expect! {{r#"
+            x <- 1:6
+            dim(x) <- c(2, 3)
+            nrow(x) == 2 && ncol(x) == 3
+        "#}}
+    }
+
+    #[test]
+    fn remove_dim() {
+        assert_eq!(
+            r! {{r#"
+                x <- matrix(1:6, nrow = 2)
+                dim(x) <- null
+                x
+            "#}},
+            r! { 1:6 }
+        )
+    }
+
+    #[test]
+    fn mismatched_length() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1:6
+                dim(x) <- c(4, 2)
+            "#}},
+            Error::Other("dims [product 8] do not match the length of object [6]".to_string())
+                .into()
+        )
+    }
+}
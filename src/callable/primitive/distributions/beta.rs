@@ -0,0 +1,163 @@
+use r_derive::builtin;
+use statrs::distribution::{Beta, Continuous, ContinuousCDF};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("shape1", Expr::Missing), ("shape2", Expr::Missing)]
+}
+
+fn beta(p: &[f64]) -> Option<Beta> {
+    Beta::new(p[0], p[1]).ok()
+}
+
+/// Beta Density
+///
+/// The density of the beta distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dbeta(x, shape1, shape2, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `shape1`,`shape2`: The shape parameters of the distribution.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dbeta(0.5, shape1 = 2, shape2 = 2)
+/// ```
+///
+#[doc(alias = "dbeta")]
+#[builtin(sym = "dbeta")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDbeta;
+impl Callable for PrimitiveDbeta {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match beta(p) {
+            Some(d) => d.ln_pdf(x),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Beta Distribution Function
+///
+/// The cumulative probability of the beta distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pbeta(q, shape1, shape2, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `shape1`,`shape2`: The shape parameters of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pbeta(0.5, shape1 = 2, shape2 = 3)
+/// ```
+///
+#[doc(alias = "pbeta")]
+#[builtin(sym = "pbeta")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePbeta;
+impl Callable for PrimitivePbeta {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match beta(p) {
+            Some(d) if lower => d.cdf(q),
+            Some(d) => d.sf(q),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Beta Quantile Function
+///
+/// The quantiles of the beta distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qbeta(p, shape1, shape2, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `shape1`,`shape2`: The shape parameters of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qbeta(c(0.1, 0.5, 0.9), shape1 = 2, shape2 = 2)
+/// ```
+///
+#[doc(alias = "qbeta")]
+#[builtin(sym = "qbeta")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQbeta;
+impl Callable for PrimitiveQbeta {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| match beta(params) {
+            Some(d) => d.inverse_cdf(p),
+            None => f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn density() {
+        assert_near("dbeta(0.5, 2, 2)", 1.5);
+        assert_near("dbeta(2, 2, 2)", 0.0);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pbeta(0.5, 2, 3)", 0.6875);
+        assert_near("pbeta(0.5, 2, 3, lower.tail = false)", 0.3125);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qbeta(0.5, 2, 2)", 0.5);
+    }
+}
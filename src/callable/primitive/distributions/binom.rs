@@ -0,0 +1,175 @@
+use r_derive::builtin;
+use statrs::distribution::{Binomial, Discrete, DiscreteCDF};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("size", Expr::Missing), ("prob", Expr::Missing)]
+}
+
+fn binomial(p: &[f64]) -> Option<Binomial> {
+    match p[0] {
+        size if size >= 0.0 && size.fract() == 0.0 => Binomial::new(p[1], size as u64).ok(),
+        _ => None,
+    }
+}
+
+/// Binomial Probabilities
+///
+/// The probability mass function of the binomial distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dbinom(x, size, prob, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the probabilities.
+/// * `size`,`prob`: The number of trials and the probability of success of
+///   each trial.
+/// * `log`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dbinom(0:10, size = 10, prob = 0.5)
+/// ```
+///
+#[doc(alias = "dbinom")]
+#[builtin(sym = "dbinom")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDbinom;
+impl Callable for PrimitiveDbinom {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match binomial(p) {
+            Some(d) => discrete_density(x, |k| d.ln_pmf(k)),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Binomial Distribution Function
+///
+/// The cumulative probability of the binomial distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pbinom(q, size, prob, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `size`,`prob`: The number of trials and the probability of success of
+///   each trial.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pbinom(3, size = 10, prob = 0.5)
+/// ```
+///
+#[doc(alias = "pbinom")]
+#[builtin(sym = "pbinom")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePbinom;
+impl Callable for PrimitivePbinom {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match binomial(p) {
+            Some(d) => discrete_probability(q, lower, p[0], |k, lower| match lower {
+                true => d.cdf(k),
+                false => d.sf(k),
+            }),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Binomial Quantile Function
+///
+/// The quantiles of the binomial distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qbinom(p, size, prob, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `size`,`prob`: The number of trials and the probability of success of
+///   each trial.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qbinom(c(0.25, 0.5, 0.75), size = 10, prob = 0.5)
+/// ```
+///
+#[doc(alias = "qbinom")]
+#[builtin(sym = "qbinom")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQbinom;
+impl Callable for PrimitiveQbinom {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| match binomial(params) {
+            Some(_) if p == 1.0 => params[0],
+            Some(d) => d.inverse_cdf(p) as f64,
+            None => f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn probabilities() {
+        assert_near("dbinom(3, 10, 0.5)", 0.1171875);
+        assert_near("dbinom(2.5, 10, 0.5)", 0.0);
+        assert_near("dbinom(11, 10, 0.5)", 0.0);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pbinom(3, 10, 0.5)", 0.171875);
+        assert_near("pbinom(3.5, 10, 0.5)", 0.171875);
+        assert_near("pbinom(3, 10, 0.5, lower.tail = false)", 0.828125);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qbinom(0.5, 10, 0.5)", 5.0);
+        assert_near("qbinom(1, 10, 0.5)", 10.0);
+    }
+}
@@ -0,0 +1,164 @@
+use r_derive::builtin;
+use statrs::distribution::{ChiSquared, Continuous, ContinuousCDF};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("df", Expr::Missing)]
+}
+
+fn chi_squared(p: &[f64]) -> Option<ChiSquared> {
+    ChiSquared::new(p[0]).ok()
+}
+
+/// Chi-Squared Density
+///
+/// The density of the chi-squared distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dchisq(x, df, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `df`: The degrees of freedom of the distribution.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dchisq(1, df = 2)
+/// ```
+///
+#[doc(alias = "dchisq")]
+#[builtin(sym = "dchisq")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDchisq;
+impl Callable for PrimitiveDchisq {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match chi_squared(p) {
+            Some(d) => d.ln_pdf(x),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Chi-Squared Distribution Function
+///
+/// The cumulative probability of the chi-squared distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pchisq(q, df, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `df`: The degrees of freedom of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pchisq(3.84, df = 1)
+/// ```
+///
+#[doc(alias = "pchisq")]
+#[builtin(sym = "pchisq")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePchisq;
+impl Callable for PrimitivePchisq {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match chi_squared(p) {
+            Some(d) if lower => d.cdf(q),
+            Some(d) => d.sf(q),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Chi-Squared Quantile Function
+///
+/// The quantiles of the chi-squared distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qchisq(p, df, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `df`: The degrees of freedom of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qchisq(0.95, df = c(1, 2))
+/// ```
+///
+#[doc(alias = "qchisq")]
+#[builtin(sym = "qchisq")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQchisq;
+impl Callable for PrimitiveQchisq {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| {
+            match chi_squared(params) {
+                Some(d) => d.inverse_cdf(p),
+                None => f64::NAN,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn density() {
+        assert_near("dchisq(1, 2)", 0.3032653);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pchisq(3.841459, 1)", 0.95);
+        assert_near("pchisq(3.841459, 1, lower.tail = false)", 0.05);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qchisq(0.95, 2)", 5.9914645);
+    }
+}
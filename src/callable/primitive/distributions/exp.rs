@@ -0,0 +1,163 @@
+use r_derive::builtin;
+use statrs::distribution::{Continuous, ContinuousCDF, Exp};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("rate", Expr::Number(1.0))]
+}
+
+fn exp(p: &[f64]) -> Option<Exp> {
+    Exp::new(p[0]).ok()
+}
+
+/// Exponential Density
+///
+/// The density of the exponential distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dexp(x, rate = 1, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `rate`: The rate of the distribution.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dexp(1, rate = 2)
+/// ```
+///
+#[doc(alias = "dexp")]
+#[builtin(sym = "dexp")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDexp;
+impl Callable for PrimitiveDexp {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match exp(p) {
+            Some(d) => d.ln_pdf(x),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Exponential Distribution Function
+///
+/// The cumulative probability of the exponential distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pexp(q, rate = 1, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `rate`: The rate of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pexp(c(0.5, 1, 2))
+/// ```
+///
+#[doc(alias = "pexp")]
+#[builtin(sym = "pexp")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePexp;
+impl Callable for PrimitivePexp {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match exp(p) {
+            Some(d) if lower => d.cdf(q),
+            Some(d) => d.sf(q),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Exponential Quantile Function
+///
+/// The quantiles of the exponential distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qexp(p, rate = 1, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `rate`: The rate of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qexp(0.5, rate = 2)
+/// ```
+///
+#[doc(alias = "qexp")]
+#[builtin(sym = "qexp")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQexp;
+impl Callable for PrimitiveQexp {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| match exp(params) {
+            Some(d) => d.inverse_cdf(p),
+            None => f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn density() {
+        assert_near("dexp(1, 2)", 0.2706706);
+        assert_near("dexp(-1)", 0.0);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pexp(1)", 0.6321206);
+        assert_near("pexp(1, lower.tail = false)", 0.3678794);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qexp(0.5, rate = 2)", 0.3465736);
+    }
+}
@@ -0,0 +1,166 @@
+use r_derive::builtin;
+use statrs::distribution::{Continuous, ContinuousCDF, FisherSnedecor};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("df1", Expr::Missing), ("df2", Expr::Missing)]
+}
+
+fn fisher_snedecor(p: &[f64]) -> Option<FisherSnedecor> {
+    FisherSnedecor::new(p[0], p[1]).ok()
+}
+
+/// F Density
+///
+/// The density of the F distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// df(x, df1, df2, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `df1`,`df2`: The degrees of freedom of the distribution.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// df(1, df1 = 2, df2 = 2)
+/// ```
+///
+#[doc(alias = "df")]
+#[builtin(sym = "df")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDf;
+impl Callable for PrimitiveDf {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match fisher_snedecor(p) {
+            Some(d) => d.ln_pdf(x),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// F Distribution Function
+///
+/// The cumulative probability of the F distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pf(q, df1, df2, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `df1`,`df2`: The degrees of freedom of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pf(1, df1 = 2, df2 = 2)
+/// ```
+///
+#[doc(alias = "pf")]
+#[builtin(sym = "pf")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePf;
+impl Callable for PrimitivePf {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| {
+            match fisher_snedecor(p) {
+                Some(d) if lower => d.cdf(q),
+                Some(d) => d.sf(q),
+                None => f64::NAN,
+            }
+        })
+    }
+}
+
+/// F Quantile Function
+///
+/// The quantiles of the F distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qf(p, df1, df2, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `df1`,`df2`: The degrees of freedom of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qf(0.95, df1 = 1, df2 = 10)
+/// ```
+///
+#[doc(alias = "qf")]
+#[builtin(sym = "qf")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQf;
+impl Callable for PrimitiveQf {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| {
+            match fisher_snedecor(params) {
+                Some(d) => d.inverse_cdf(p),
+                None => f64::NAN,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn density() {
+        assert_near("df(1, 2, 2)", 0.25);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pf(1, 2, 2)", 0.5);
+        assert_near("pf(3, 2, 2, lower.tail = false)", 0.25);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qf(0.95, 1, 10)", 4.9646027);
+    }
+}
@@ -0,0 +1,240 @@
+use r_derive::builtin;
+use statrs::distribution::{Continuous, ContinuousCDF, Gamma};
+
+use super::*;
+use crate::callable::core::*;
+use crate::error::Error;
+
+fn params() -> Params {
+    vec![("shape", Expr::Missing), ("rate", Expr::Number(1.0))]
+}
+
+/// Parameters as accepted by calls, where `scale` may be given in place of
+/// `rate`, as `1 / rate`
+fn formal_params() -> Params {
+    vec![
+        ("shape", Expr::Missing),
+        ("rate", Expr::Null),
+        ("scale", Expr::Null),
+    ]
+}
+
+/// Resolve the `rate` argument, either as given or from its reciprocal,
+/// `scale`
+fn with_rate(args: List, stack: &mut CallStack) -> Result<List, Signal> {
+    let mut args = Obj::List(args);
+    let one = Obj::Vector(Vector::from(vec![1.0]));
+
+    let rate = match (
+        args.try_get_named("rate")?.force(stack)?,
+        args.try_get_named("scale")?.force(stack)?,
+    ) {
+        (Obj::Null, Obj::Null) => one,
+        (rate, Obj::Null) => rate,
+        (Obj::Null, scale) => (one / scale)?,
+        (rate, scale) => {
+            let Obj::Vector(Vector::Double(product)) = (rate.clone() * scale)?.as_double()? else {
+                unreachable!()
+            };
+
+            let consistent = product.inner().borrow().iter().all(|p| match p {
+                OptionNA::Some(p) => (p - 1.0).abs() < 1e-15,
+                OptionNA::NA => false,
+            });
+
+            if !consistent {
+                return Error::Other("specify 'rate' or 'scale' but not both".into()).into();
+            }
+
+            stack.warn("specify 'rate' or 'scale' but not both".to_string())?;
+            rate
+        }
+    };
+
+    args.set_named("rate", rate)?;
+    match args {
+        Obj::List(args) => Ok(args),
+        _ => unreachable!(),
+    }
+}
+
+fn gamma(p: &[f64]) -> Option<Gamma> {
+    Gamma::new(p[0], p[1]).ok()
+}
+
+/// Gamma Density
+///
+/// The density of the gamma distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dgamma(x, shape, rate = 1, scale = 1/rate, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `shape`,`rate`: The shape and rate of the distribution.
+/// * `scale`: The scale of the distribution, an alternative to `rate`.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dgamma(2, shape = 2)
+/// ```
+///
+#[doc(alias = "dgamma")]
+#[builtin(sym = "dgamma")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDgamma;
+impl Callable for PrimitiveDgamma {
+    fn formals(&self) -> ExprList {
+        density_formals(formal_params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(
+            with_rate(args, stack)?,
+            params(),
+            stack,
+            |x, p| match gamma(p) {
+                Some(d) => d.ln_pdf(x),
+                None => f64::NAN,
+            },
+        )
+    }
+}
+
+/// Gamma Distribution Function
+///
+/// The cumulative probability of the gamma distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pgamma(q, shape, rate = 1, scale = 1/rate, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `shape`,`rate`: The shape and rate of the distribution.
+/// * `scale`: The scale of the distribution, an alternative to `rate`.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pgamma(2, shape = 2, rate = c(1, 2))
+/// ```
+///
+#[doc(alias = "pgamma")]
+#[builtin(sym = "pgamma")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePgamma;
+impl Callable for PrimitivePgamma {
+    fn formals(&self) -> ExprList {
+        probability_formals(formal_params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(
+            with_rate(args, stack)?,
+            params(),
+            stack,
+            |q, p, lower| match gamma(p) {
+                Some(d) if lower => d.cdf(q),
+                Some(d) => d.sf(q),
+                None => f64::NAN,
+            },
+        )
+    }
+}
+
+/// Gamma Quantile Function
+///
+/// The quantiles of the gamma distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qgamma(p, shape, rate = 1, scale = 1/rate, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `shape`,`rate`: The shape and rate of the distribution.
+/// * `scale`: The scale of the distribution, an alternative to `rate`.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qgamma(0.5, shape = 2)
+/// ```
+///
+#[doc(alias = "qgamma")]
+#[builtin(sym = "qgamma")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQgamma;
+impl Callable for PrimitiveQgamma {
+    fn formals(&self) -> ExprList {
+        quantile_formals(formal_params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(
+            with_rate(args, stack)?,
+            params(),
+            stack,
+            |p, params| match gamma(params) {
+                Some(d) => d.inverse_cdf(p),
+                None => f64::NAN,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+    use crate::r;
+
+    #[test]
+    fn density() {
+        assert_near("dgamma(2, 2)", 0.2706706);
+        assert_near("dgamma(1, 2, rate = 2)", 0.5413411);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pgamma(2, 2)", 0.5939942);
+        assert_near("pgamma(2, 2, lower.tail = false)", 0.4060058);
+    }
+
+    #[test]
+    fn scale() {
+        assert_near("pgamma(2, 2, scale = 2)", 0.2642411);
+        assert_near("dgamma(1, 2, scale = 0.5)", 0.5413411);
+        assert_near("qgamma(0.5, 2, rate = 0.5, scale = 2)", 3.356694);
+        assert!(r! { pgamma(2, 2, rate = 1, scale = 2) }.is_err());
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qgamma(0.5, 2)", 1.678347);
+    }
+}
@@ -0,0 +1,281 @@
+//! Density, distribution and quantile functions
+//!
+//! Each distribution provides a `d*` density (or mass) function, a `p*`
+//! distribution function and a `q*` quantile function. Arguments are
+//! recycled against one another and evaluated elementwise, producing
+//! `NA` wherever any argument is `NA`.
+//!
+
+mod beta;
+pub use beta::*;
+mod binom;
+pub use binom::*;
+mod chisq;
+pub use chisq::*;
+mod exp;
+pub use exp::*;
+mod f;
+pub use f::*;
+mod gamma;
+pub use gamma::*;
+mod norm;
+pub use norm::*;
+mod pois;
+pub use pois::*;
+mod t;
+pub use t::*;
+mod unif;
+pub use unif::*;
+
+use std::rc::Rc;
+
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// Parameters of a distribution, by name and with their default values
+type Params = Vec<(&'static str, Expr)>;
+
+fn formals(first: &str, params: Params, flags: Vec<(&str, bool)>) -> ExprList {
+    let first = std::iter::once((Some(first.to_string()), Expr::Missing));
+    let params = params.into_iter().map(|(k, v)| (Some(k.to_string()), v));
+    let flags = flags
+        .into_iter()
+        .map(|(k, v)| (Some(k.to_string()), Expr::Bool(v)));
+
+    ExprList::from_iter(first.chain(params).chain(flags))
+}
+
+/// Formals of a density function, `(x, <params>, log = false)`
+fn density_formals(params: Params) -> ExprList {
+    formals("x", params, vec![("log", false)])
+}
+
+/// Formals of a distribution function, `(q, <params>, lower.tail = true, log.p = false)`
+fn probability_formals(params: Params) -> ExprList {
+    formals("q", params, vec![("lower.tail", true), ("log.p", false)])
+}
+
+/// Formals of a quantile function, `(p, <params>, lower.tail = true, log.p = false)`
+fn quantile_formals(params: Params) -> ExprList {
+    formals("p", params, vec![("lower.tail", true), ("log.p", false)])
+}
+
+/// Evaluate a function elementwise over recycled, named arguments
+///
+/// Produces `NA` where any argument is `NA` and warns when `NaN`s are
+/// produced from arguments that are not themselves `NaN`.
+///
+fn evaluate<F>(args: &mut Obj, names: &[&str], stack: &mut CallStack, f: F) -> EvalResult
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let mut values: Vec<Rc<Vec<Double>>> = vec![];
+    for name in names {
+        let Obj::Vector(Vector::Double(v)) = args.try_get_named(name)?.force(stack)?.as_double()?
+        else {
+            unreachable!()
+        };
+
+        values.push(v.inner().borrow().clone());
+    }
+
+    let n = match values.iter().any(|v| v.is_empty()) {
+        true => 0,
+        false => values.iter().map(|v| v.len()).max().unwrap_or(0),
+    };
+
+    let mut any_nan = false;
+    let result: Vec<Double> = (0..n)
+        .map(|i| {
+            let mut x = vec![];
+            for v in values.iter() {
+                match v[i % v.len()] {
+                    OptionNA::Some(vi) => x.push(vi),
+                    OptionNA::NA => return OptionNA::NA,
+                }
+            }
+
+            let y = f(&x);
+            any_nan |= y.is_nan() && !x.iter().any(|xi| xi.is_nan());
+            OptionNA::Some(y)
+        })
+        .collect();
+
+    if any_nan {
+        stack.warn("NaNs produced".to_string())?;
+    }
+
+    Ok(Obj::Vector(Vector::from(result)))
+}
+
+/// Evaluate a density function, given as a function of `x` and parameters
+/// producing the log density
+fn density<F>(args: List, params: Params, stack: &mut CallStack, f: F) -> EvalResult
+where
+    F: Fn(f64, &[f64]) -> f64,
+{
+    let mut args = Obj::List(args);
+    let log: bool = args.try_get_named("log")?.force(stack)?.try_into()?;
+
+    let names: Vec<&str> = std::iter::once("x")
+        .chain(params.iter().map(|(k, _)| *k))
+        .collect();
+    evaluate(&mut args, &names, stack, |x| match f(x[0], &x[1..]) {
+        d if log => d,
+        d => d.exp(),
+    })
+}
+
+/// Evaluate a distribution function, given as a function of `q`, parameters
+/// and whether to produce the probability of the lower tail
+fn probability<F>(args: List, params: Params, stack: &mut CallStack, f: F) -> EvalResult
+where
+    F: Fn(f64, &[f64], bool) -> f64,
+{
+    let mut args = Obj::List(args);
+    let lower_tail: bool = args.try_get_named("lower.tail")?.force(stack)?.try_into()?;
+    let log_p: bool = args.try_get_named("log.p")?.force(stack)?.try_into()?;
+
+    let names: Vec<&str> = std::iter::once("q")
+        .chain(params.iter().map(|(k, _)| *k))
+        .collect();
+    evaluate(&mut args, &names, stack, |x| {
+        match f(x[0], &x[1..], lower_tail) {
+            p if log_p => p.ln(),
+            p => p,
+        }
+    })
+}
+
+/// Evaluate a distribution function, given as a function of `q`, parameters
+/// and whether to produce the log probability of the lower tail
+///
+/// Distributions whose tails underflow provide their log probabilities
+/// directly, so that `log.p` keeps its precision far into either tail.
+///
+fn log_probability<F>(args: List, params: Params, stack: &mut CallStack, f: F) -> EvalResult
+where
+    F: Fn(f64, &[f64], bool) -> f64,
+{
+    let mut args = Obj::List(args);
+    let lower_tail: bool = args.try_get_named("lower.tail")?.force(stack)?.try_into()?;
+    let log_p: bool = args.try_get_named("log.p")?.force(stack)?.try_into()?;
+
+    let names: Vec<&str> = std::iter::once("q")
+        .chain(params.iter().map(|(k, _)| *k))
+        .collect();
+    evaluate(&mut args, &names, stack, |x| {
+        match f(x[0], &x[1..], lower_tail) {
+            p if log_p => p,
+            p => p.exp(),
+        }
+    })
+}
+
+/// Evaluate a quantile function, given as a function of a lower tail
+/// probability `p` in `[0, 1]` and parameters
+fn quantile<F>(args: List, params: Params, stack: &mut CallStack, f: F) -> EvalResult
+where
+    F: Fn(f64, &[f64]) -> f64,
+{
+    let mut args = Obj::List(args);
+    let lower_tail: bool = args.try_get_named("lower.tail")?.force(stack)?.try_into()?;
+    let log_p: bool = args.try_get_named("log.p")?.force(stack)?.try_into()?;
+
+    let names: Vec<&str> = std::iter::once("p")
+        .chain(params.iter().map(|(k, _)| *k))
+        .collect();
+    evaluate(&mut args, &names, stack, |x| {
+        let p = if log_p { x[0].exp() } else { x[0] };
+        let p = if lower_tail { p } else { 1.0 - p };
+        match p {
+            p if !(0.0..=1.0).contains(&p) => f64::NAN,
+            p => f(p, &x[1..]),
+        }
+    })
+}
+
+/// The probability of the lower or upper tail of a discrete distribution
+/// on `0..=max` at `q`, given its distribution function on whole numbers
+fn discrete_probability<F>(q: f64, lower_tail: bool, max: f64, cdf: F) -> f64
+where
+    F: Fn(u64, bool) -> f64,
+{
+    let q = q.floor();
+    let p = match q {
+        q if q.is_nan() => return f64::NAN,
+        q if q < 0.0 => 0.0,
+        q if q >= max => 1.0,
+        q => return cdf(q as u64, lower_tail),
+    };
+
+    if lower_tail {
+        p
+    } else {
+        1.0 - p
+    }
+}
+
+/// The log probability of a discrete distribution at `x`, given its log
+/// probability mass function on whole numbers
+fn discrete_density<F>(x: f64, ln_pmf: F) -> f64
+where
+    F: Fn(u64) -> f64,
+{
+    match x {
+        x if x.is_nan() => f64::NAN,
+        x if x < 0.0 || x.fract() != 0.0 || !x.is_finite() => f64::NEG_INFINITY,
+        x => ln_pmf(x as u64),
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use crate::context::Context;
+    use crate::lang::CallStack;
+    use crate::r;
+
+    /// Assert that an expression evaluates to a value near another
+    pub fn assert_near(expr: &str, expected: f64) {
+        let value: f64 = CallStack::default()
+            .parse_and_eval(expr)
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        assert!(
+            (value - expected).abs() < 1e-6,
+            "{expr} produced {value}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn recycled_arguments() {
+        assert_eq!(
+            r! { pnorm(c(0, 1, 2, 3), mean = c(0, 1)) },
+            r! { c(0.5, 0.5, pnorm(2), pnorm(2)) }
+        )
+    }
+
+    #[test]
+    fn missing_values() {
+        assert_eq!(
+            r! { dnorm(c(0, NA), mean = c(0, 1)) },
+            r! { c(dnorm(0), NA) }
+        );
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let mut stack = CallStack::default();
+        let expr = stack.parse("qnorm(2)").expect("parse failed");
+        let value: f64 = stack.eval_and_finalize(expr).unwrap().try_into().unwrap();
+
+        assert!(value.is_nan());
+        assert_eq!(
+            stack.flush_warnings(),
+            Some("Warning message:\nNaNs produced\n".to_string())
+        );
+    }
+}
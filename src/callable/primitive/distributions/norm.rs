@@ -0,0 +1,215 @@
+use r_derive::builtin;
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("mean", Expr::Number(0.0)), ("sd", Expr::Number(1.0))]
+}
+
+fn normal(p: &[f64]) -> Option<Normal> {
+    Normal::new(p[0], p[1]).ok()
+}
+
+/// The log of the standard normal distribution function
+///
+/// Far into the lower tail, where the probability itself underflows, the
+/// asymptotic expansion of the Mills ratio is used instead.
+///
+fn ln_standard_cdf(z: f64) -> f64 {
+    let standard = Normal::standard();
+    match z {
+        z if z < -10.0 => {
+            let z2 = z * z;
+            let series = 1.0 - 1.0 / z2 + 3.0 / z2.powi(2) - 15.0 / z2.powi(3) + 105.0 / z2.powi(4);
+            -0.5 * z2 - (-z).ln() - 0.5 * std::f64::consts::TAU.ln() + series.ln()
+        }
+        z if z < 0.0 => standard.cdf(z).ln(),
+        z => (-standard.cdf(-z)).ln_1p(),
+    }
+}
+
+/// Normal Density
+///
+/// The density of the normal distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dnorm(x, mean = 0, sd = 1, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `mean`,`sd`: The mean and standard deviation of the distribution.
+/// * `log`: Whether to produce the log density.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dnorm(c(-1, 0, 1))
+/// ```
+///
+#[doc(alias = "dnorm")]
+#[builtin(sym = "dnorm")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDnorm;
+impl Callable for PrimitiveDnorm {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match normal(p) {
+            Some(d) => d.ln_pdf(x),
+            // a point mass at the mean
+            None if p[1] == 0.0 && x == p[0] => f64::INFINITY,
+            None if p[1] == 0.0 && !x.is_nan() => f64::NEG_INFINITY,
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Normal Distribution Function
+///
+/// The cumulative probability of the normal distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pnorm(q, mean = 0, sd = 1, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `mean`,`sd`: The mean and standard deviation of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pnorm(1.96)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// pnorm(1.96, lower.tail = false)
+/// ```
+///
+#[doc(alias = "pnorm")]
+#[builtin(sym = "pnorm")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePnorm;
+impl Callable for PrimitivePnorm {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        log_probability(args, params(), stack, |q, p, lower| {
+            let (mean, sd) = (p[0], p[1]);
+            let z = match (q - mean) / sd {
+                z if lower => z,
+                z => -z,
+            };
+
+            match sd {
+                sd if sd.is_nan() || sd < 0.0 => f64::NAN,
+                // a point mass at the mean
+                0.0 if (q >= mean) == lower => 0.0,
+                0.0 => f64::NEG_INFINITY,
+                _ if z.is_nan() => f64::NAN,
+                _ => ln_standard_cdf(z),
+            }
+        })
+    }
+}
+
+/// Normal Quantile Function
+///
+/// The quantiles of the normal distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qnorm(p, mean = 0, sd = 1, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `mean`,`sd`: The mean and standard deviation of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qnorm(c(0.025, 0.5, 0.975))
+/// ```
+///
+#[doc(alias = "qnorm")]
+#[builtin(sym = "qnorm")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQnorm;
+impl Callable for PrimitiveQnorm {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| match normal(params) {
+            Some(d) => d.inverse_cdf(p),
+            None => f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+    use crate::r;
+
+    #[test]
+    fn density() {
+        assert_near("dnorm(0)", 0.3989423);
+        assert_near("dnorm(1, log = true)", -1.4189385);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pnorm(1.96)", 0.9750021);
+        assert_near("pnorm(1.96, lower.tail = false)", 0.0249979);
+    }
+
+    #[test]
+    fn log_tails() {
+        assert_near("pnorm(-40, log.p = true)", -804.6084420);
+        assert_near("pnorm(40, lower.tail = false, log.p = true)", -804.6084420);
+        assert_near("pnorm(-3, log.p = true)", -6.6077262);
+        assert_near("pnorm(3, log.p = true)", -0.0013508);
+    }
+
+    #[test]
+    fn point_mass() {
+        assert_eq!(r! { dnorm(c(0, 1), sd = 0) }, r! { c(Inf, 0) });
+        assert_eq!(r! { pnorm(c(-1, 0, 1), sd = 0) }, r! { c(0, 1, 1) });
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qnorm(0.975)", 1.9599640);
+        assert_near("qnorm(pnorm(2, log.p = true), mean = 3, log.p = true)", 5.0);
+    }
+}
@@ -0,0 +1,166 @@
+use r_derive::builtin;
+use statrs::distribution::{Discrete, DiscreteCDF, Poisson};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("lambda", Expr::Missing)]
+}
+
+fn poisson(p: &[f64]) -> Option<Poisson> {
+    Poisson::new(p[0]).ok()
+}
+
+/// Poisson Probabilities
+///
+/// The probability mass function of the Poisson distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dpois(x, lambda, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the probabilities.
+/// * `lambda`: The mean of the distribution.
+/// * `log`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dpois(0:5, lambda = 3)
+/// ```
+///
+#[doc(alias = "dpois")]
+#[builtin(sym = "dpois")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDpois;
+impl Callable for PrimitiveDpois {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match poisson(p) {
+            Some(d) => discrete_density(x, |k| d.ln_pmf(k)),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Poisson Distribution Function
+///
+/// The cumulative probability of the Poisson distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// ppois(q, lambda, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `lambda`: The mean of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// ppois(2, lambda = 3)
+/// ```
+///
+#[doc(alias = "ppois")]
+#[builtin(sym = "ppois")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePpois;
+impl Callable for PrimitivePpois {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match poisson(p) {
+            Some(d) => discrete_probability(q, lower, f64::INFINITY, |k, lower| match lower {
+                true => d.cdf(k),
+                false => d.sf(k),
+            }),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Poisson Quantile Function
+///
+/// The quantiles of the Poisson distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qpois(p, lambda, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `lambda`: The mean of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qpois(c(0.25, 0.5, 0.75), lambda = 3)
+/// ```
+///
+#[doc(alias = "qpois")]
+#[builtin(sym = "qpois")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQpois;
+impl Callable for PrimitiveQpois {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| match poisson(params) {
+            Some(_) if p == 1.0 => f64::INFINITY,
+            Some(d) => d.inverse_cdf(p) as f64,
+            None => f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn probabilities() {
+        assert_near("dpois(2, 3)", 0.2240418);
+        assert_near("dpois(-1, 3)", 0.0);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("ppois(2, 3)", 0.4231901);
+        assert_near("ppois(2, 3, lower.tail = false)", 0.5768099);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qpois(0.5, 3)", 3.0);
+    }
+}
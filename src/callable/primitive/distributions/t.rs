@@ -0,0 +1,164 @@
+use r_derive::builtin;
+use statrs::distribution::{Continuous, ContinuousCDF, StudentsT};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("df", Expr::Missing)]
+}
+
+fn students_t(p: &[f64]) -> Option<StudentsT> {
+    StudentsT::new(0.0, 1.0, p[0]).ok()
+}
+
+/// Student t Density
+///
+/// The density of the Student t distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dt(x, df, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `df`: The degrees of freedom of the distribution.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dt(0, df = 1)
+/// ```
+///
+#[doc(alias = "dt")]
+#[builtin(sym = "dt")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDt;
+impl Callable for PrimitiveDt {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match students_t(p) {
+            Some(d) => d.ln_pdf(x),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Student t Distribution Function
+///
+/// The cumulative probability of the Student t distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// pt(q, df, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `df`: The degrees of freedom of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// pt(2, df = c(1, 5, 30))
+/// ```
+///
+#[doc(alias = "pt")]
+#[builtin(sym = "pt")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePt;
+impl Callable for PrimitivePt {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match students_t(p) {
+            Some(d) if lower => d.cdf(q),
+            Some(d) => d.sf(q),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Student t Quantile Function
+///
+/// The quantiles of the Student t distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qt(p, df, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `df`: The degrees of freedom of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qt(0.975, df = 10)
+/// ```
+///
+#[doc(alias = "qt")]
+#[builtin(sym = "qt")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQt;
+impl Callable for PrimitiveQt {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| {
+            match students_t(params) {
+                Some(d) => d.inverse_cdf(p),
+                None => f64::NAN,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn density() {
+        assert_near("dt(0, 1)", std::f64::consts::FRAC_1_PI);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("pt(2, 5)", 0.9490303);
+        assert_near("pt(2, 5, lower.tail = false)", 0.0509697);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qt(0.975, 10)", 2.2281389);
+    }
+}
@@ -0,0 +1,163 @@
+use r_derive::builtin;
+use statrs::distribution::{Continuous, ContinuousCDF, Uniform};
+
+use super::*;
+use crate::callable::core::*;
+
+fn params() -> Params {
+    vec![("min", Expr::Number(0.0)), ("max", Expr::Number(1.0))]
+}
+
+fn uniform(p: &[f64]) -> Option<Uniform> {
+    Uniform::new(p[0], p[1]).ok()
+}
+
+/// Uniform Density
+///
+/// The density of the uniform distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// dunif(x, min = 0, max = 1, log = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: Values at which to evaluate the density.
+/// * `min`,`max`: The bounds of the distribution.
+/// * `log`: Whether to produce log densities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// dunif(0.5, min = 0, max = 2)
+/// ```
+///
+#[doc(alias = "dunif")]
+#[builtin(sym = "dunif")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDunif;
+impl Callable for PrimitiveDunif {
+    fn formals(&self) -> ExprList {
+        density_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        density(args, params(), stack, |x, p| match uniform(p) {
+            Some(d) => d.ln_pdf(x),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Uniform Distribution Function
+///
+/// The cumulative probability of the uniform distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// punif(q, min = 0, max = 1, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `q`: Quantiles at which to evaluate the distribution function.
+/// * `min`,`max`: The bounds of the distribution.
+/// * `lower.tail`: Whether to produce probabilities `P[X <= q]`, or
+///   otherwise `P[X > q]`.
+/// * `log.p`: Whether to produce log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// punif(0.25)
+/// ```
+///
+#[doc(alias = "punif")]
+#[builtin(sym = "punif")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitivePunif;
+impl Callable for PrimitivePunif {
+    fn formals(&self) -> ExprList {
+        probability_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        probability(args, params(), stack, |q, p, lower| match uniform(p) {
+            Some(d) if lower => d.cdf(q),
+            Some(d) => d.sf(q),
+            None => f64::NAN,
+        })
+    }
+}
+
+/// Uniform Quantile Function
+///
+/// The quantiles of the uniform distribution.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// qunif(p, min = 0, max = 1, lower.tail = true, log.p = false)
+/// ```
+///
+/// ## Arguments
+///
+/// * `p`: Probabilities for which to find quantiles.
+/// * `min`,`max`: The bounds of the distribution.
+/// * `lower.tail`: Whether probabilities are `P[X <= q]`, or otherwise
+///   `P[X > q]`.
+/// * `log.p`: Whether probabilities are given as log probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// qunif(0.3, min = 1, max = 3)
+/// ```
+///
+#[doc(alias = "qunif")]
+#[builtin(sym = "qunif")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQunif;
+impl Callable for PrimitiveQunif {
+    fn formals(&self) -> ExprList {
+        quantile_formals(params())
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        quantile(args, params(), stack, |p, params| match uniform(params) {
+            Some(d) => d.inverse_cdf(p),
+            None => f64::NAN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::assert_near;
+
+    #[test]
+    fn density() {
+        assert_near("dunif(0.5, 0, 2)", 0.5);
+        assert_near("dunif(3, 0, 2)", 0.0);
+    }
+
+    #[test]
+    fn tails() {
+        assert_near("punif(0.25)", 0.25);
+        assert_near("punif(0.25, lower.tail = false)", 0.75);
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_near("qunif(0.3, 1, 3)", 1.6);
+    }
+}
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use r_derive::*;
 
 use crate::callable::core::*;
@@ -6,6 +8,21 @@ use crate::error::Error;
 use crate::lang::*;
 use crate::object::*;
 
+/// The calling environment, the default environment argument of builtins
+pub fn calling_env() -> Expr {
+    Expr::Call(
+        Box::new(Expr::Symbol("environment".to_string())),
+        ExprList::new(),
+    )
+}
+
+fn env_arg(x: Obj, name: &str) -> Result<Rc<Environment>, Signal> {
+    match x {
+        Obj::Environment(env) => Ok(env),
+        _ => Error::Other(format!("'{name}' must be an environment")).into(),
+    }
+}
+
 /// Get an Environment
 ///
 /// Fetches an object's environment.
@@ -63,11 +80,312 @@ impl Callable for PrimitiveEnvironment {
             Obj::Promise(.., e) => Ok(Obj::Environment(e.clone())),
             Obj::Function(.., e) => Ok(Obj::Environment(e.clone())),
             Obj::Environment(e) => Ok(Obj::Environment(e.clone())),
+            Obj::Formula(_, e, _) => Ok(Obj::Environment(e.clone())),
             _ => Error::ArgumentInvalid(String::from("fun")).into(),
         }
     }
 }
 
+/// Create a New Environment
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// new.env(parent = environment())
+/// ```
+///
+/// ## Arguments
+///
+/// `parent`: The parent of the new environment, by default the calling
+///   environment.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// e <- new.env()
+/// assign("x", 1, envir = e)
+/// get("x", envir = e)
+/// ```
+///
+#[doc(alias = "new.env")]
+#[builtin(sym = "new.env")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveNewEnv;
+
+impl Callable for PrimitiveNewEnv {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("parent".to_string()), calling_env())])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let parent = env_arg(args.try_get_named("parent")?.force(stack)?, "parent")?;
+
+        Ok(Obj::Environment(Rc::new(Environment {
+            parent: Some(parent),
+            ..Default::default()
+        })))
+    }
+}
+
+/// Evaluate in a Local Environment
+///
+/// Evaluates an expression in an environment, by default a new child of the
+/// calling environment, so that assignments don't affect the caller.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// local(expr, envir = new.env())
+/// ```
+///
+/// ## Arguments
+///
+/// `expr`: The expression to evaluate.
+/// `envir`: The environment in which to evaluate `expr`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- 1
+/// local({ x <- 2; x * 10 })
+/// x
+/// ```
+///
+#[doc(alias = "local")]
+#[builtin(sym = "local")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveLocal;
+
+impl Callable for PrimitiveLocal {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("expr".to_string()), Expr::Missing),
+            (
+                Some("envir".to_string()),
+                Expr::Call(
+                    Box::new(Expr::Symbol("new.env".to_string())),
+                    ExprList::new(),
+                ),
+            ),
+        ])
+    }
+
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let (args, _ellipsis) = self.match_arg_exprs(args, stack)?;
+        let mut args = Obj::List(args);
+
+        // the expression is taken unevaluated from its promise
+        let expr = match args.try_get_named("expr")? {
+            Obj::Promise(_, Expr::Missing, _) => {
+                return Error::ArgumentMissing("expr".to_string()).into()
+            }
+            Obj::Promise(_, expr, _) => expr,
+            constant => return Ok(constant),
+        };
+
+        let envir = env_arg(args.try_get_named("envir")?.force(stack)?, "envir")?;
+
+        stack.add_frame(expr.clone(), envir);
+        let result = stack.eval_and_finalize(expr);
+        stack.pop_frame_and_return(result)
+    }
+}
+
+/// Coerce to an Environment
+///
+/// Environments are returned as-is, while named lists are converted into a
+/// new environment binding each named element. The new environment's parent
+/// is the empty environment.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// as.environment(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An environment or a named `list`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// e <- as.environment(list(a = 1, b = 2))
+/// get("b", envir = e)
+/// ```
+///
+#[doc(alias = "as.environment")]
+#[builtin(sym = "as.environment")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsEnvironment;
+
+impl Callable for PrimitiveAsEnvironment {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        match args.try_get_named("x")?.force(stack)? {
+            Obj::Environment(env) => Ok(Obj::Environment(env)),
+            Obj::List(list) => {
+                let env = Rc::new(Environment {
+                    parent: Some(Environment::empty()),
+                    ..Default::default()
+                });
+                env.append(list);
+                Ok(Obj::Environment(env))
+            }
+            _ => Error::Other("invalid object for 'as.environment'".into()).into(),
+        }
+    }
+}
+
+/// The Empty Environment
+///
+/// An environment without bindings or a parent, which terminates chains of
+/// environments.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// emptyenv()
+/// ```
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// new.env(parent = emptyenv())
+/// ```
+///
+#[doc(alias = "emptyenv")]
+#[builtin(sym = "emptyenv")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveEmptyEnv;
+
+impl Callable for PrimitiveEmptyEnv {
+    fn call_matched(&self, _args: List, _ellipsis: List, _stack: &mut CallStack) -> EvalResult {
+        Ok(Obj::Environment(Environment::empty()))
+    }
+}
+
+/// The Global Environment
+///
+/// The environment in which top-level expressions are evaluated.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// globalenv()
+/// ```
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- function() globalenv()
+/// f()
+/// ```
+///
+#[doc(alias = "globalenv")]
+#[builtin(sym = "globalenv")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveGlobalEnv;
+
+impl Callable for PrimitiveGlobalEnv {
+    fn call_matched(&self, _args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        Ok(Obj::Environment(stack.global_env()))
+    }
+}
+
+/// The Base Environment
+///
+/// The environment binding builtin functions, the parent of the global
+/// environment.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// baseenv()
+/// ```
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// baseenv()
+/// ```
+///
+#[doc(alias = "baseenv")]
+#[builtin(sym = "baseenv")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveBaseEnv;
+
+impl Callable for PrimitiveBaseEnv {
+    fn call_matched(&self, _args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut env = stack.global_env();
+        while let Some(parent) = env.parent.clone() {
+            env = parent;
+        }
+
+        Ok(Obj::Environment(env))
+    }
+}
+
+/// Get the Name of an Environment
+///
+/// Well-known environments are named, while others have an empty name.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// environmentName(env)
+/// ```
+///
+/// ## Arguments
+///
+/// `env`: An environment.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// environmentName(globalenv())
+/// ```
+///
+#[doc(alias = "environmentName")]
+#[builtin(sym = "environmentName")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveEnvironmentName;
+
+impl Callable for PrimitiveEnvironmentName {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("env".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let name = match args.try_get_named("env")?.force(stack)? {
+            Obj::Environment(env) => vec![env.name.clone().unwrap_or_default()],
+            _ => vec![],
+        };
+
+        Ok(Obj::Vector(Vector::from(name)))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{r, r_expect};
@@ -92,4 +410,70 @@ mod test {
             x() != environment(x)
         "}}
     }
+
+    #[test]
+    fn new_env_parent() {
+        r_expect! {{"
+            e <- new.env()
+            parent(e) == environment()
+        "}}
+        assert_eq!(
+            r! { x <- 1; e <- new.env(parent = emptyenv()); exists("x", envir = e) },
+            r! { false }
+        )
+    }
+
+    #[test]
+    fn local_evaluation() {
+        assert_eq!(
+            r! {{ r#"
+                x <- 1
+                y <- local({ x <- 2; x * 10 })
+                c(x, y)
+            "# }},
+            r! { c(1, 20) }
+        );
+        assert_eq!(
+            r! {{ r#"
+                e <- new.env()
+                local(x <- 3, envir = e)
+                get("x", envir = e)
+            "# }},
+            r! { 3 }
+        )
+    }
+
+    #[test]
+    fn as_environment_from_list() {
+        assert_eq!(
+            r! {{ r#"
+                e <- as.environment(list(a = 1, b = "two"))
+                as.list(e)
+            "# }},
+            r! { list(a = 1, b = "two") }
+        );
+        r_expect! {{"
+            e <- environment()
+            as.environment(e) == e
+        "}}
+    }
+
+    #[test]
+    fn well_known_environments() {
+        r_expect! { globalenv() == environment() }
+        r_expect! { parent(globalenv()) == baseenv() }
+        assert_eq!(
+            r! { c(environmentName(globalenv()), environmentName(baseenv()), environmentName(emptyenv())) },
+            r! { c("R_GlobalEnv", "base", "R_EmptyEnv") }
+        );
+        assert_eq!(r! { environmentName(new.env()) }, r! { "" })
+    }
+
+    #[test]
+    fn global_env_from_function() {
+        r_expect! {{"
+            f <- function() globalenv()
+            f() == environment()
+        "}}
+    }
 }
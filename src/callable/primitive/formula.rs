@@ -0,0 +1,360 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::callable::keywords::KeywordParen;
+use crate::callable::operators::*;
+use crate::error::Error;
+use crate::internal_err;
+use crate::lang::*;
+use crate::object::*;
+
+/// A model term, as the variables which interact within it
+///
+/// The intercept is the term without any variables.
+///
+type Term = Vec<Expr>;
+
+/// The expression of a formula or quoted expression
+fn language_arg(x: Obj) -> Result<Expr, Signal> {
+    match x {
+        Obj::Formula(expr, ..) | Obj::Expr(expr) => Ok(expr),
+        _ => Error::Other("argument must be a formula or expression".into()).into(),
+    }
+}
+
+/// Collect the unique names of variables in an expression, in order of
+/// appearance. The names of called functions are not variables.
+fn variables(expr: &Expr, vars: &mut Vec<String>) {
+    match expr {
+        Expr::Symbol(name) if !vars.contains(name) => vars.push(name.clone()),
+        Expr::Call(what, args) => {
+            if let Expr::Call(..) = **what {
+                variables(what, vars)
+            }
+
+            for arg in args.values.iter() {
+                variables(arg, vars)
+            }
+        }
+        Expr::List(args) => args.values.iter().for_each(|arg| variables(arg, vars)),
+        Expr::Function(_, body) => variables(body, vars),
+        _ => (),
+    }
+}
+
+/// Whether an expression is a call to a primitive
+fn is_call_to<T: Builtin + 'static>(what: &Expr, primitive: T) -> bool {
+    *what == Expr::as_primitive(primitive)
+}
+
+/// The terms added to, or removed from, a model by a formula's right hand
+/// side, in order of appearance
+fn term_operations(expr: &Expr) -> Vec<(Term, bool)> {
+    let negate = |ops: Vec<(Term, bool)>| ops.into_iter().map(|(t, add)| (t, !add)).collect();
+    let added = |ops: Vec<(Term, bool)>| -> Vec<Term> {
+        ops.into_iter()
+            .filter(|(_, add)| *add)
+            .map(|(t, _)| t)
+            .collect()
+    };
+
+    // interactions combine the variables of each pair of terms
+    let interact = |lhs: Vec<Term>, rhs: Vec<Term>| -> Vec<Term> {
+        let mut terms = vec![];
+        for l in lhs.iter() {
+            for r in rhs.iter() {
+                let mut term = l.clone();
+                term.extend(r.iter().filter(|v| !l.contains(v)).cloned());
+                terms.push(term);
+            }
+        }
+        terms
+    };
+
+    match expr {
+        Expr::Number(x) if *x == 0.0 => vec![(vec![], false)],
+        Expr::Number(x) if *x == 1.0 => vec![(vec![], true)],
+        Expr::Integer(0) => vec![(vec![], false)],
+        Expr::Integer(1) => vec![(vec![], true)],
+        Expr::Call(what, args) => match &args.values[..] {
+            [lhs, rhs] if is_call_to(what, InfixAdd) => {
+                let mut ops = term_operations(lhs);
+                ops.extend(term_operations(rhs));
+                ops
+            }
+            [lhs, rhs] if is_call_to(what, InfixSub) => {
+                let mut ops = term_operations(lhs);
+                ops.extend(negate(term_operations(rhs)));
+                ops
+            }
+            [lhs, rhs] if is_call_to(what, InfixMul) => {
+                let lhs = added(term_operations(lhs));
+                let rhs = added(term_operations(rhs));
+                let interactions = interact(lhs.clone(), rhs.clone());
+                lhs.into_iter()
+                    .chain(rhs)
+                    .chain(interactions)
+                    .map(|t| (t, true))
+                    .collect()
+            }
+            [lhs, rhs] if is_call_to(what, InfixColon) => {
+                let lhs = added(term_operations(lhs));
+                let rhs = added(term_operations(rhs));
+                interact(lhs, rhs).into_iter().map(|t| (t, true)).collect()
+            }
+            [x] if is_call_to(what, PrefixSub) => negate(term_operations(x)),
+            [x] if is_call_to(what, KeywordParen) => term_operations(x),
+            _ => vec![(vec![expr.clone()], true)],
+        },
+        _ => vec![(vec![expr.clone()], true)],
+    }
+}
+
+/// Get the Variables of an Expression
+///
+/// Returns the unique names of variables used in a formula or quoted
+/// expression, excluding the names of functions which are called.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// all.vars(expr)
+/// ```
+///
+/// ## Arguments
+///
+/// `expr`: A formula or quoted expression.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// all.vars(y ~ log(x) + z)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// all.vars(quote(sin(x + y)))
+/// ```
+///
+#[doc(alias = "all.vars")]
+#[builtin(sym = "all.vars")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAllVars;
+impl Callable for PrimitiveAllVars {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("expr".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let expr = language_arg(args.try_get_named("expr")?.force(stack)?)?;
+
+        let mut vars = vec![];
+        variables(&expr, &mut vars);
+        Ok(Obj::Vector(Vector::from(vars)))
+    }
+}
+
+/// Get the Terms of a Formula
+///
+/// Describes the model specified by a formula. Terms on the right hand side
+/// are separated by `+` and removed with `-`. `a:b` is the interaction of
+/// `a` and `b`, while `a * b` expands to `a + b + a:b`. The intercept is
+/// included unless removed by `- 1` or `+ 0`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// terms(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A formula.
+///
+/// ## Value
+///
+/// The formula, of class `c("terms", "formula")`, with attributes
+///
+/// * `variables`: A call to `list()` of the response and each variable of
+///     the model's terms.
+/// * `term.labels`: The labels of the model terms, ordered by the number
+///     of variables they involve.
+/// * `response`: `1` when the formula has a response, its left hand side,
+///     otherwise `0`.
+/// * `intercept`: `1` when the model includes an intercept, otherwise `0`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// attr(terms(y ~ a * b - 1), "term.labels")
+/// ```
+///
+#[doc(alias = "terms")]
+#[builtin(sym = "terms")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTerms;
+impl Callable for PrimitiveTerms {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let Obj::Formula(formula, env, _) = args.try_get_named("x")?.force(stack)? else {
+            return Error::Other("argument is not a valid model".into()).into();
+        };
+
+        let Expr::Call(_, operands) = &formula else {
+            return internal_err!();
+        };
+
+        let (response, rhs) = match &operands.values[..] {
+            [lhs, rhs] => (Some(lhs), rhs),
+            [rhs] => (None, rhs),
+            _ => return internal_err!(),
+        };
+
+        // variables are the response followed by those of each term
+        let operations = term_operations(rhs);
+        let mut vars: Vec<Expr> = response.into_iter().cloned().collect();
+        for var in operations.iter().flat_map(|(term, _)| term.iter()) {
+            if !vars.contains(var) {
+                vars.push(var.clone())
+            }
+        }
+
+        // the intercept is included unless removed
+        let mut terms: Vec<Term> = vec![vec![]];
+        for (term, add) in operations {
+            let position = terms
+                .iter()
+                .position(|t| t.len() == term.len() && t.iter().all(|v| term.contains(v)));
+
+            match (position, add) {
+                (None, true) => terms.push(term),
+                (Some(i), false) => {
+                    terms.remove(i);
+                }
+                _ => (),
+            }
+        }
+
+        let intercept = terms.iter().any(|t| t.is_empty());
+        terms.retain(|t| !t.is_empty());
+        terms.sort_by_key(|t| t.len());
+        let labels: Vec<String> = terms
+            .iter()
+            .map(|t| {
+                t.iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(":")
+            })
+            .collect();
+
+        let variables = Expr::Call(
+            Box::new(Expr::Symbol("list".to_string())),
+            ExprList::from(vars.into_iter().map(|v| (None, v)).collect::<Vec<_>>()),
+        );
+
+        let attrs = Attributes::default();
+        attrs.set("variables", Obj::Expr(variables));
+        attrs.set("term.labels", Obj::Vector(Vector::from(labels)));
+        attrs.set(
+            "response",
+            Obj::Vector(Vector::from(vec![response.is_some() as i32])),
+        );
+        attrs.set(
+            "intercept",
+            Obj::Vector(Vector::from(vec![intercept as i32])),
+        );
+        attrs.set(
+            "class",
+            Obj::Vector(Vector::from(vec![
+                "terms".to_string(),
+                "formula".to_string(),
+            ])),
+        );
+
+        Ok(Obj::Formula(formula, env, attrs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{r, r_expect};
+
+    #[test]
+    fn formulas_quote_their_operands() {
+        r_expect! {{"
+            f <- y ~ undefined_variable + z
+            class(f) == \"formula\"
+        "}}
+        assert_eq!(r! { y ~ x + z }, r! { quote(y ~ x + z) |> eval() })
+    }
+
+    #[test]
+    fn formulas_capture_environment() {
+        r_expect! {{"
+            f <- function() { a <- 1; ~ a }
+            get(\"a\", envir = environment(f())) == 1
+        "}}
+    }
+
+    #[test]
+    fn one_sided_formulas() {
+        assert_eq!(r! { all.vars(~ x + log(z)) }, r! { c("x", "z") });
+        assert_eq!(r! { attr(terms(~ x), "response") }, r! { 0L })
+    }
+
+    #[test]
+    fn all_vars_of_expressions() {
+        assert_eq!(r! { all.vars(y ~ log(x) + x:z) }, r! { c("y", "x", "z") });
+        assert_eq!(r! { all.vars(quote(sin(a + b))) }, r! { c("a", "b") });
+        assert!(r! { all.vars(1) }.is_err())
+    }
+
+    #[test]
+    fn terms_of_formulas() {
+        assert_eq!(
+            r! { attr(terms(y ~ a * b), "term.labels") },
+            r! { c("a", "b", "a:b") }
+        );
+        assert_eq!(
+            r! { attr(terms(y ~ a + log(b) - a), "term.labels") },
+            r! { "log(b)" }
+        );
+        assert_eq!(
+            r! { attr(terms(y ~ a + log(b) - a), "variables") },
+            r! { quote(list(y, a, log(b))) }
+        );
+        assert_eq!(r! { attr(terms(y ~ a), "response") }, r! { 1L });
+    }
+
+    #[test]
+    fn terms_are_formulas() {
+        assert_eq!(r! { class(terms(y ~ x + z)) }, r! { c("terms", "formula") });
+        assert_eq!(r! { all.vars(terms(y ~ x)) }, r! { c("y", "x") });
+    }
+
+    #[test]
+    fn terms_intercept() {
+        assert_eq!(r! { attr(terms(y ~ a), "intercept") }, r! { 1L });
+        assert_eq!(r! { attr(terms(y ~ a - 1), "intercept") }, r! { 0L });
+        assert_eq!(r! { attr(terms(y ~ 0 + a), "intercept") }, r! { 0L });
+        assert_eq!(r! { attr(terms(y ~ 0 + a), "term.labels") }, r! { "a" });
+    }
+
+    #[test]
+    fn lambda_shorthand() {
+        assert_eq!(r! {{ r#"(\(x) x + 1)(2)"# }}, r! { 3 });
+        assert_eq!(
+            r! {{ r#"sapply(1:3, \(i, j = 2) i * j)"# }},
+            r! { c(2, 4, 6) }
+        );
+    }
+}
@@ -0,0 +1,840 @@
+use r_derive::*;
+
+use super::c::combine;
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+type Elements = Vec<(Option<String>, Obj)>;
+
+/// The elements of a vector or list, alongside their names
+fn elements(x: Obj, name: &str) -> Result<Elements, Signal> {
+    match x {
+        Obj::Null => Ok(vec![]),
+        Obj::List(l) => Ok(l.materialize().values.borrow().to_vec()),
+        Obj::Vector(v) => {
+            let names: Option<Vec<String>> = match v.attributes().get("names") {
+                Some(Obj::Vector(names)) => Some(names.into()),
+                _ => None,
+            };
+
+            Ok((0..v.len())
+                .map(|i| {
+                    let name = names
+                        .as_ref()
+                        .and_then(|names| names.get(i).cloned())
+                        .filter(|name| !name.is_empty());
+                    let value = v.get(i).map_or(Obj::Null, Obj::Vector);
+                    (name, value)
+                })
+                .collect())
+        }
+        _ => Error::Other(format!("'{name}' must be a vector or list")).into(),
+    }
+}
+
+/// The names by which results of applying a function over `x` are known
+///
+/// Elements keep their own names. Unnamed `character` vectors are named
+/// by their values when `use_values` is set.
+///
+fn result_names(x: &Obj, elements: &Elements, use_values: bool) -> Vec<Option<String>> {
+    match x {
+        Obj::Vector(Vector::Character(_))
+            if use_values && elements.iter().all(|(k, _)| k.is_none()) =>
+        {
+            elements
+                .iter()
+                .map(|(_, v)| match v {
+                    Obj::Vector(v @ Vector::Character(_)) => Some(String::from(v.clone())),
+                    _ => None,
+                })
+                .collect()
+        }
+        _ => elements.iter().map(|(k, _)| k.clone()).collect(),
+    }
+}
+
+/// Find a function, either given directly or by name
+fn function(f: Obj, stack: &mut CallStack) -> EvalResult {
+    let f = match f {
+        Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => {
+            stack.env().get(String::from(v))?
+        }
+        f => f,
+    };
+
+    match f {
+        Obj::Function(..) => Ok(f),
+        _ => Error::Other("'FUN' is not a function".into()).into(),
+    }
+}
+
+/// Call a function with a leading argument, followed by forwarded arguments
+fn call_with_extra(f: &Obj, x: Obj, extra: &Elements, stack: &mut CallStack) -> EvalResult {
+    let args = std::iter::once((None, x))
+        .chain(extra.iter().cloned())
+        .collect();
+    stack.call_with(f.clone(), args)
+}
+
+/// Construct a list of results, named if any result is named
+fn named_list(results: Vec<Obj>, names: Vec<Option<String>>) -> Obj {
+    Obj::List(List::from(
+        names.into_iter().zip(results).collect::<Elements>(),
+    ))
+}
+
+/// Simplify a list of results to a vector
+///
+/// Results which are all atomic vectors of length one are concatenated
+/// into a vector. With `matrices`, results which are all atomic vectors of
+/// some greater common length are concatenated into the columns of a
+/// matrix. Otherwise, results are left as a list.
+///
+fn simplify(
+    results: Vec<Obj>,
+    names: Vec<Option<String>>,
+    matrices: bool,
+    stack: &mut CallStack,
+) -> EvalResult {
+    let lengths: Option<Vec<usize>> = results
+        .iter()
+        .map(|r| match r {
+            Obj::Vector(v) => Some(v.len()),
+            _ => None,
+        })
+        .collect();
+
+    let length = match lengths.as_deref() {
+        Some([first, rest @ ..]) if rest.iter().all(|n| n == first) => *first,
+        _ => return Ok(named_list(results, names)),
+    };
+
+    let n = results.len();
+    match length {
+        1 => {
+            let result = combine(results, stack)?;
+            match names.iter().any(|name| name.is_some()) {
+                true => {
+                    let names: Vec<String> =
+                        names.into_iter().map(Option::unwrap_or_default).collect();
+                    result.set_attr("names", Obj::Vector(Vector::from(names)))
+                }
+                false => Ok(result),
+            }
+        }
+        k if k > 1 && matrices => {
+            let dim = Obj::Vector(Vector::from(vec![k as i32, n as i32]));
+            combine(results, stack)?
+                .set_attr("names", Obj::Null)?
+                .set_attr("dim", dim)
+        }
+        _ => Ok(named_list(results, names)),
+    }
+}
+
+/// Apply a Function over a List
+///
+/// Calls a function with each element of a vector or list, collecting
+/// the results into a list.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// lapply(X, FUN, ...)
+/// ```
+///
+/// ## Arguments
+///
+/// `X`: A vector or list.
+/// `FUN`: A function, or the name of a function, to call with each element.
+/// `...`: Further arguments passed on to each call of `FUN`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// lapply(list(a = 1:3, b = 4:6), sum)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// lapply(1:3, function(x, y) x * y, y = 10)
+/// ```
+///
+#[doc(alias = "lapply")]
+#[builtin(sym = "lapply")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveLapply;
+
+impl Callable for PrimitiveLapply {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("X".to_string()), Expr::Missing),
+            (Some("FUN".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = elements(args.try_get_named("X")?.force(stack)?, "X")?;
+        let f = function(args.try_get_named("FUN")?.force(stack)?, stack)?;
+        let extra = force_promises(ellipsis, stack)?;
+
+        let (names, values): (Vec<_>, Vec<_>) = x.into_iter().unzip();
+        let results = values
+            .into_iter()
+            .map(|xi| call_with_extra(&f, xi, &extra, stack))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(named_list(results, names))
+    }
+}
+
+/// Apply a Function over a List, Simplifying the Result
+///
+/// Calls a function with each element of a vector or list. When every
+/// result is an atomic vector of length one, results are concatenated into
+/// a vector, and when every result is an atomic vector of some greater
+/// common length, they form the columns of a matrix. Otherwise, results
+/// are collected into a list.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sapply(X, FUN, ..., simplify = true, USE.NAMES = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `X`: A vector or list.
+/// `FUN`: A function, or the name of a function, to call with each element.
+/// `...`: Further arguments passed on to each call of `FUN`.
+/// `simplify`: Whether to simplify results to a vector or matrix.
+/// `USE.NAMES`: Whether to name the results of an unnamed `character`
+///   vector by its values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sapply(1:3, function(x) x^2)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// sapply(c("a", "bb"), paste, "!")
+/// ```
+///
+#[doc(alias = "sapply")]
+#[builtin(sym = "sapply")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSapply;
+
+impl Callable for PrimitiveSapply {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("X".to_string()), Expr::Missing),
+            (Some("FUN".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+            (Some("simplify".to_string()), Expr::Bool(true)),
+            (Some("USE.NAMES".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("X")?.force(stack)?;
+        let f = function(args.try_get_named("FUN")?.force(stack)?, stack)?;
+        let simplify_results: bool = args.try_get_named("simplify")?.force(stack)?.try_into()?;
+        let use_names: bool = args.try_get_named("USE.NAMES")?.force(stack)?.try_into()?;
+        let extra = force_promises(ellipsis, stack)?;
+
+        let elements = elements(x.clone(), "X")?;
+        let names = match use_names {
+            true => result_names(&x, &elements, true),
+            false => vec![None; elements.len()],
+        };
+
+        let results = elements
+            .into_iter()
+            .map(|(_, xi)| call_with_extra(&f, xi, &extra, stack))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match simplify_results && !results.is_empty() {
+            true => simplify(results, names, true, stack),
+            false => Ok(named_list(results, names)),
+        }
+    }
+}
+
+/// The name of the type of an atomic vector
+fn type_name(x: &Vector) -> &'static str {
+    match x {
+        Vector::Logical(_) => "logical",
+        Vector::Integer(_) => "integer",
+        Vector::Double(_) => "double",
+        Vector::Character(_) => "character",
+    }
+}
+
+/// Coerce a result to the type of a template, where it can be without
+/// losing information
+fn conform(result: Obj, template: &Vector) -> EvalResult {
+    use Vector::*;
+    let result = match result {
+        Obj::Vector(v) => v,
+        _ => {
+            let msg = format!("values must be type '{}'", type_name(template));
+            return Error::Other(msg).into();
+        }
+    };
+
+    let result = match (template, &result) {
+        (Logical(_), Logical(_))
+        | (Integer(_), Logical(_) | Integer(_))
+        | (Double(_), Logical(_) | Integer(_) | Double(_))
+        | (Character(_), Character(_)) => Obj::Vector(result),
+        _ => {
+            let msg = format!(
+                "values must be type '{}', but FUN(X[[i]]) result is type '{}'",
+                type_name(template),
+                type_name(&result)
+            );
+            return Error::Other(msg).into();
+        }
+    };
+
+    match template {
+        Logical(_) => result.as_logical(),
+        Integer(_) => result.as_integer(),
+        Double(_) => result.as_double(),
+        Character(_) => result.as_character(),
+    }
+}
+
+/// Apply a Function over a List, Checking Results Against a Template
+///
+/// Calls a function with each element of a vector or list, where each
+/// result must match the type and length of a template value. Results of
+/// length one are concatenated into a vector, while longer results form
+/// the columns of a matrix.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// vapply(X, FUN, FUN.VALUE, ..., USE.NAMES = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `X`: A vector or list.
+/// `FUN`: A function, or the name of a function, to call with each element.
+/// `FUN.VALUE`: An atomic vector, whose type and length each result must
+///   match. `logical` and `integer` results may be used where `integer`s or
+///   `double`s are expected.
+/// `...`: Further arguments passed on to each call of `FUN`.
+/// `USE.NAMES`: Whether to name the results of an unnamed `character`
+///   vector by its values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// vapply(list(1:3, 4:9), length, 1L)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// vapply(1:3, function(x) c(x, x^2), c(0, 0))
+/// ```
+///
+#[doc(alias = "vapply")]
+#[builtin(sym = "vapply")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveVapply;
+
+impl Callable for PrimitiveVapply {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("X".to_string()), Expr::Missing),
+            (Some("FUN".to_string()), Expr::Missing),
+            (Some("FUN.VALUE".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+            (Some("USE.NAMES".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("X")?.force(stack)?;
+        let f = function(args.try_get_named("FUN")?.force(stack)?, stack)?;
+        let Obj::Vector(template) = args.try_get_named("FUN.VALUE")?.force(stack)? else {
+            return Error::Other("'FUN.VALUE' must be a vector".into()).into();
+        };
+        let use_names: bool = args.try_get_named("USE.NAMES")?.force(stack)?.try_into()?;
+        let extra = force_promises(ellipsis, stack)?;
+
+        let elements = elements(x.clone(), "X")?;
+        let names = match use_names {
+            true => result_names(&x, &elements, true),
+            false => vec![None; elements.len()],
+        };
+
+        let mut results = Vec::with_capacity(elements.len());
+        for (_, xi) in elements {
+            let result = conform(call_with_extra(&f, xi, &extra, stack)?, &template)?;
+            let n = result.len().unwrap_or_default();
+            if n != template.len() {
+                let msg = format!(
+                    "values must be length {}, but FUN(X[[i]]) result is length {n}",
+                    template.len()
+                );
+                return Error::Other(msg).into();
+            }
+
+            results.push(result);
+        }
+
+        if results.is_empty() {
+            let empty = template
+                .subset(Subset::from(Vec::<usize>::new()))
+                .materialize();
+            return Ok(Obj::Vector(empty.with_attributes(Attributes::default())));
+        }
+
+        match template.len() {
+            1 => simplify(results, names, false, stack),
+            _ => simplify(results, vec![None; names.len()], true, stack),
+        }
+    }
+}
+
+/// Apply a Function over Multiple Lists
+///
+/// Calls a function with the corresponding elements of each of several
+/// vectors or lists, recycling shorter arguments, and collects the results
+/// into a list.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// Map(f, ...)
+/// ```
+///
+/// ## Arguments
+///
+/// `f`: A function, or the name of a function.
+/// `...`: Vectors or lists whose elements are passed to `f`. Argument
+///   names are used to name the arguments of each call.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// Map(function(x, y) x + y, 1:3, 4:6)
+/// ```
+///
+#[doc(alias = "Map")]
+#[builtin(sym = "Map")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMap;
+
+impl Callable for PrimitiveMap {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("f".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let f = function(args.try_get_named("f")?.force(stack)?, stack)?;
+
+        let mut arguments = vec![];
+        for (i, (name, x)) in force_promises(ellipsis, stack)?.into_iter().enumerate() {
+            let xs = elements(x.clone(), &format!("..{}", i + 1))?;
+            arguments.push((name, x, xs));
+        }
+
+        let n = match arguments.iter().map(|(_, _, xs)| xs.len()).min() {
+            None | Some(0) => 0,
+            Some(_) => arguments
+                .iter()
+                .map(|(_, _, xs)| xs.len())
+                .max()
+                .unwrap_or(0),
+        };
+
+        let names = match arguments.first() {
+            Some((_, x, xs)) => result_names(x, xs, true)
+                .into_iter()
+                .cycle()
+                .take(n)
+                .collect(),
+            None => vec![],
+        };
+
+        let results = (0..n)
+            .map(|i| {
+                let args = arguments
+                    .iter()
+                    .map(|(name, _, xs)| (name.clone(), xs[i % xs.len()].1.clone()))
+                    .collect();
+                stack.call_with(f.clone(), args)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(named_list(results, names))
+    }
+}
+
+/// Reduce a List with a Binary Function
+///
+/// Successively combines the elements of a vector or list using a function
+/// of two arguments, from the left or from the right.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// Reduce(f, x, init, right = false, accumulate = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `f`: A function, or the name of a function, of two arguments.
+/// `x`: A vector or list.
+/// `init`: An optional initial value. When not provided, the first (or
+///   last, when reducing from the right) element is used.
+/// `right`: Whether to reduce from the right.
+/// `accumulate`: Whether to return every intermediate result, rather than
+///   only the last. Intermediate results of length one are simplified to
+///   a vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// Reduce(`+`, 1:5)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// Reduce(`+`, 1:5, accumulate = true)
+/// ```
+///
+#[doc(alias = "Reduce")]
+#[builtin(sym = "Reduce")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveReduce;
+
+impl Callable for PrimitiveReduce {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("f".to_string()), Expr::Missing),
+            (Some("x".to_string()), Expr::Missing),
+            (Some("init".to_string()), Expr::Missing),
+            (Some("right".to_string()), Expr::Bool(false)),
+            (Some("accumulate".to_string()), Expr::Bool(false)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let f = function(args.try_get_named("f")?.force(stack)?, stack)?;
+        let x = elements(args.try_get_named("x")?.force(stack)?, "x")?;
+        let init = match args.try_get_named("init") {
+            Ok(init) => Some(init.force(stack)?),
+            Err(_) => None,
+        };
+        let right: bool = args.try_get_named("right")?.force(stack)?.try_into()?;
+        let accumulate: bool = args.try_get_named("accumulate")?.force(stack)?.try_into()?;
+
+        let mut values: Vec<Obj> = x.into_iter().map(|(_, v)| v).collect();
+        if right {
+            values.reverse();
+        }
+
+        let mut values = init.into_iter().chain(values);
+        let Some(mut acc) = values.next() else {
+            return Ok(Obj::Null);
+        };
+
+        let mut steps = vec![acc.clone()];
+        for value in values {
+            let args = match right {
+                true => vec![(None, value), (None, acc)],
+                false => vec![(None, acc), (None, value)],
+            };
+
+            acc = stack.call_with(f.clone(), args)?;
+            if accumulate {
+                steps.push(acc.clone());
+            }
+        }
+
+        if !accumulate {
+            return Ok(acc);
+        }
+
+        if right {
+            steps.reverse();
+        }
+
+        let names = vec![None; steps.len()];
+        simplify(steps, names, false, stack)
+    }
+}
+
+/// Filter Elements by a Predicate
+///
+/// Keeps the elements of a vector or list for which a predicate function
+/// returns `true`. Elements for which it returns `false` or `NA` are
+/// removed.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// Filter(f, x)
+/// ```
+///
+/// ## Arguments
+///
+/// `f`: A function, or the name of a function, returning a `logical`.
+/// `x`: A vector or list.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// Filter(function(x) x %% 2 == 0, 1:10)
+/// ```
+///
+#[doc(alias = "Filter")]
+#[builtin(sym = "Filter")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveFilter;
+
+impl Callable for PrimitiveFilter {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("f".to_string()), Expr::Missing),
+            (Some("x".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let f = function(args.try_get_named("f")?.force(stack)?, stack)?;
+        let x = args.try_get_named("x")?.force(stack)?;
+
+        let mut indices = vec![];
+        for (i, (_, xi)) in elements(x.clone(), "x")?.into_iter().enumerate() {
+            let keep = match stack.call_with(f.clone(), vec![(None, xi)])?.as_logical()? {
+                Obj::Vector(Vector::Logical(v)) => v.iter().next() == Some(OptionNA::Some(true)),
+                _ => false,
+            };
+
+            if keep {
+                indices.push(i);
+            }
+        }
+
+        match x {
+            Obj::Vector(v) => Ok(Obj::Vector(v.subset(Subset::from(indices)).materialize())),
+            Obj::List(l) => Ok(Obj::List(l.subset(Subset::from(indices)).materialize())),
+            _ => Ok(Obj::Null),
+        }
+    }
+}
+
+/// Call a Function with a List of Arguments
+///
+/// Calls a function with the elements of a list as its arguments. Names of
+/// list elements are used to name arguments.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// do.call(what, args)
+/// ```
+///
+/// ## Arguments
+///
+/// `what`: A function, or the name of a function.
+/// `args`: A list of arguments.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// do.call(paste, list("a", "b", sep = "-"))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// do.call("sum", list(1, 2, 3))
+/// ```
+///
+#[doc(alias = "do.call")]
+#[builtin(sym = "do.call")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDoCall;
+
+impl Callable for PrimitiveDoCall {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("what".to_string()), Expr::Missing),
+            (Some("args".to_string()), Expr::Null),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let f = function(args.try_get_named("what")?.force(stack)?, stack)?;
+        let args = elements(args.try_get_named("args")?.force(stack)?, "args")?;
+        stack.call_with(f, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn lapply_named_list() {
+        assert_eq!(
+            r! { lapply(list(a = 1:3, b = 4:6), sum) },
+            r! { list(a = 6L, b = 15L) }
+        )
+    }
+
+    #[test]
+    fn lapply_forwards_ellipsis() {
+        assert_eq!(
+            r! { lapply(1:2, function(x, y) x * y, y = 10) },
+            r! { list(10, 20) }
+        )
+    }
+
+    #[test]
+    fn lapply_function_by_name() {
+        assert_eq!(r! { lapply(list(1:2), "length") }, r! { list(2L) })
+    }
+
+    #[test]
+    fn sapply_simplifies_to_vector() {
+        assert_eq!(r! { sapply(1:3, function(x) x * 2L) }, r! { c(2L, 4L, 6L) });
+        assert_eq!(
+            r! { sapply(c("a", "bb"), function(x) x) },
+            r! { c(a = "a", bb = "bb") }
+        )
+    }
+
+    #[test]
+    fn sapply_simplifies_to_matrix() {
+        assert_eq!(
+            r! { dim(sapply(1:3, function(x) c(x, x))) },
+            r! { c(2L, 3L) }
+        )
+    }
+
+    #[test]
+    fn sapply_without_simplifying() {
+        assert_eq!(
+            r! { sapply(1:2, function(x) x, simplify = false) },
+            r! { list(1L, 2L) }
+        );
+        assert_eq!(r! { sapply(1:2, function(x) 1:x) }, r! { list(1L, 1:2) })
+    }
+
+    #[test]
+    fn vapply_checks_template() {
+        assert_eq!(r! { vapply(list(1:3, 4:9), length, 1L) }, r! { c(3L, 6L) });
+        assert_eq!(r! { vapply(1:2, function(x) x, 0) }, r! { c(1, 2) });
+        assert!(r! { vapply(1:2, function(x) "a", 0) }.is_err());
+        assert!(r! { vapply(1:2, function(x) c(x, x), 0) }.is_err())
+    }
+
+    #[test]
+    fn vapply_empty() {
+        assert_eq!(r! { vapply(list(), length, 1L) }, r! { c(1L)[0] })
+    }
+
+    #[test]
+    fn map_recycles() {
+        assert_eq!(
+            r! { Map(function(x, y) x + y, 1:4, 1:2) },
+            r! { list(2L, 4L, 4L, 6L) }
+        );
+        assert_eq!(
+            r! { Map(function(x, y) y, y = c(a = 1), x = 2) },
+            r! { list(a = 1) }
+        )
+    }
+
+    #[test]
+    fn reduce_left_and_right() {
+        assert_eq!(
+            r! { Reduce(function(a, b) paste(a, b), c("a", "b", "c")) },
+            r! { "a b c" }
+        );
+        assert_eq!(
+            r! { Reduce(function(a, b) paste(a, b), c("a", "b", "c"), right = true) },
+            r! { "a b c" }
+        );
+        assert_eq!(
+            r! { Reduce(function(a, b) paste(b, a), c("a", "b"), init = "z") },
+            r! { "b a z" }
+        )
+    }
+
+    #[test]
+    fn reduce_accumulate() {
+        assert_eq!(
+            r! { Reduce(function(a, b) a + b, 1:4, accumulate = true) },
+            r! { c(1L, 3L, 6L, 10L) }
+        );
+        assert_eq!(
+            r! { Reduce(function(a, b) a + b, 1:3, accumulate = true, right = true) },
+            r! { c(6L, 5L, 3L) }
+        )
+    }
+
+    #[test]
+    fn reduce_empty() {
+        assert_eq!(r! { Reduce(function(a, b) a + b, list()) }, r! { NULL });
+        assert_eq!(
+            r! { Reduce(function(a, b) a + b, list(), init = 0) },
+            r! { 0 }
+        )
+    }
+
+    #[test]
+    fn filter_values() {
+        assert_eq!(
+            r! { Filter(function(x) x > 2, c(a = 1, b = 3, c = 5)) },
+            r! { c(b = 3, c = 5) }
+        );
+        assert_eq!(
+            r! { Filter(function(x) length(x) > 1, list(1, 1:2)) },
+            r! { list(1:2) }
+        )
+    }
+
+    #[test]
+    fn do_call_keeps_names() {
+        assert_eq!(
+            r! { do.call(paste, list("a", "b", sep = "-")) },
+            r! { "a-b" }
+        );
+        assert_eq!(r! { do.call("sum", list(1, 2, 3)) }, r! { 6 })
+    }
+}
@@ -0,0 +1,126 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+use super::matrix::reshape;
+
+/// Get the First Elements of an Object
+///
+/// Returns the first elements of a vector or list, the first rows of a
+/// matrix or the first rows of a data frame.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// head(x, n = 6)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector, list, matrix or data frame.
+/// `n`: The number of elements (or rows) to keep. When negative, all but
+///   the last `-n` elements are kept.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// head(1:10, 3)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// head(data.frame(x = 1:10, y = 11:20), n = -8)
+/// ```
+///
+#[doc(alias = "head")]
+#[builtin(sym = "head")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveHead;
+
+impl Callable for PrimitiveHead {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("n".to_string()), Expr::Number(6.0)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let n: i32 = args.try_get_named("n")?.force(stack)?.try_into()?;
+
+        let len = match x.dim() {
+            Some(dim) if dim.len() == 2 => dim[0],
+            _ => x.len().unwrap_or(0),
+        };
+
+        let k = match n {
+            n if n >= 0 => (n as usize).min(len),
+            n => len.saturating_sub(n.unsigned_abs() as usize),
+        };
+
+        match x {
+            Obj::List(l) if l.is_data_frame() => {
+                let rows = (0..k).map(Some).collect();
+                Ok(Obj::List(l.select(Some(rows), (0..l.len()).collect())))
+            }
+            Obj::List(l) => Ok(Obj::List(l.subset(Subset::Range(0..k)).materialize())),
+            Obj::Vector(v) => match v.attributes().dim() {
+                Some(dim) if dim.len() == 2 => {
+                    let (nrow, ncol) = (dim[0], dim[1]);
+                    let indices = (0..ncol).flat_map(|j| (0..k).map(move |i| i + j * nrow));
+                    Ok(Obj::Vector(reshape(v, indices, &[k, ncol])))
+                }
+                _ => Ok(Obj::Vector(v.subset(Subset::Range(0..k)).materialize())),
+            },
+            Obj::Null => Ok(Obj::Null),
+            _ => Error::Other("cannot take the head of this object".into()).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{r, r_expect};
+
+    #[test]
+    fn vector_head() {
+        assert_eq!(r! { head(1:10, 3) }, r! { 1:3 })
+    }
+
+    #[test]
+    fn default_length() {
+        r_expect! { length(head(1:10)) == 6 }
+    }
+
+    #[test]
+    fn negative_length() {
+        assert_eq!(r! { head(1:10, -7) }, r! { 1:3 })
+    }
+
+    #[test]
+    fn longer_than_vector() {
+        assert_eq!(r! { head(1:3, 10) }, r! { 1:3 })
+    }
+
+    #[test]
+    fn data_frame_head() {
+        assert_eq!(
+            r! { head(data.frame(x = 1:10, y = 11:20), 2)$y },
+            r! { c(11L, 12L) }
+        )
+    }
+
+    #[test]
+    fn matrix_head() {
+        r_expect! {{r#"
+            m <- head(matrix(1:6, nrow = 3), 1)
+            nrow(m) == 1 && ncol(m) == 2 && m[1, 2] == 4
+        "#}}
+    }
+}
@@ -39,6 +39,9 @@ impl Callable for PrimitiveLength {
     fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
         let mut args = Obj::List(args);
         let x = args.try_get_named("x")?.force(stack)?;
+        if let Some(result) = stack.dispatch("length", &x, vec![(None, x.clone())]) {
+            return result;
+        }
 
         let length: usize = match x {
             Obj::Vector(ref vec) => match vec {
@@ -47,8 +50,13 @@ impl Callable for PrimitiveLength {
                 Vector::Logical(rep) => rep.len(),
                 Vector::Character(rep) => rep.len(),
             },
-            Obj::List(_) => todo!("Not implemented yet"),
+            Obj::List(ref list) => list.len(),
             Obj::Environment(env) => env.len(),
+            // calls are measured as their function and arguments
+            Obj::Formula(Expr::Call(_, args), ..) | Obj::Expr(Expr::Call(_, args)) => {
+                1 + args.len()
+            }
+            Obj::Formula(..) | Obj::Expr(_) => 1,
             _ => return Error::Other("Argument 'x' does not have a length".into()).into(),
         };
 
@@ -80,11 +88,10 @@ mod tests {
     fn character() {
         r_expect!(length(c("a", "b", "c", "d")) == 4)
     }
-    // TODO
-    // #[test]
-    // fn list() {
-    //     r_expect!(length(list(1, 2, 3, 5) == 5))
-    // }
+    #[test]
+    fn list() {
+        r_expect!(length(list(1, 2, 3, 5)) == 4)
+    }
     #[test]
     fn environment() {
         r_expect! {{"
@@ -104,10 +111,23 @@ mod tests {
         }}
     }
     #[test]
+    fn dispatch() {
+        r_expect! {{r#"
+            length.a <- function(x) 10
+            length(structure(1, class = "a")) == 10
+        "#}}
+    }
+    #[test]
     fn null() {
         assert_eq!(
             r! {length(null)},
             EvalResult::Err(Error::Other("Argument 'x' does not have a length".to_string()).into())
         )
     }
+    #[test]
+    fn language() {
+        assert_eq!(r! { length(y ~ x) }, r! { 3L });
+        assert_eq!(r! { length(~ x) }, r! { 2L });
+        assert_eq!(r! { length(quote(f(a, b, c))) }, r! { 4L });
+    }
 }
@@ -0,0 +1,844 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// Apply a function over the values of a numeric vector, producing doubles
+///
+/// `integer` and `logical` values are first coerced to `double`s. `NA`s
+/// are propagated and attributes are kept. Warns when `NaN`s are produced
+/// from values that are not themselves `NaN`.
+///
+fn map_double<F>(x: Obj, stack: &mut CallStack, f: F) -> EvalResult
+where
+    F: Fn(f64) -> f64,
+{
+    let mut any_nan = false;
+    let mut f = |x: f64| {
+        let y = f(x);
+        any_nan |= y.is_nan() && !x.is_nan();
+        y
+    };
+
+    let result = match x {
+        Obj::Vector(Vector::Double(v)) => v.map_numeric(|x: Double| x.map(&mut f)),
+        Obj::Vector(Vector::Integer(v)) => v.map_numeric(|x: Integer| x.map(|x| f(x as f64))),
+        Obj::Vector(Vector::Logical(v)) => v.map_numeric(|x: Integer| x.map(|x| f(x as f64))),
+        _ => return non_numeric(),
+    };
+
+    if any_nan {
+        stack.warn("NaNs produced".to_string())?;
+    }
+
+    Ok(Obj::Vector(Vector::Double(result)))
+}
+
+fn non_numeric() -> EvalResult {
+    Error::Other("non-numeric argument to mathematical function".into()).into()
+}
+
+/// Round a value to a number of decimal digits
+///
+/// Of the nearest representable values with the given number of digits,
+/// the one closest to `x` is chosen, with ties going to the even digit.
+///
+fn round_digits(x: f64, digits: f64) -> f64 {
+    if !x.is_finite() || digits.is_nan() {
+        return x;
+    }
+
+    let p = 10f64.powf(digits.trunc().min(308.0));
+    if !(x * p).is_finite() {
+        return x;
+    }
+
+    let (lo, hi) = ((x * p).floor(), (x * p).ceil());
+    let (dlo, dhi) = ((x - lo / p).abs(), (hi / p - x).abs());
+    let rounded = match dlo.partial_cmp(&dhi) {
+        Some(std::cmp::Ordering::Less) => lo,
+        Some(std::cmp::Ordering::Greater) => hi,
+        _ if lo % 2.0 == 0.0 => lo,
+        _ => hi,
+    };
+
+    rounded / p
+}
+
+/// Round a value to a number of significant digits
+fn signif_digits(x: f64, digits: f64) -> f64 {
+    if !x.is_finite() || x == 0.0 || digits.is_nan() {
+        return x;
+    }
+
+    let digits = digits.trunc().max(1.0);
+    let magnitude = x.abs().log10().floor();
+    round_digits(x, digits - 1.0 - magnitude)
+}
+
+/// Absolute Values
+///
+/// The absolute values of a numeric vector. `integer` and `logical` values
+/// produce `integer`s.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// abs(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// abs(c(-2, 0, 1.5))
+/// ```
+///
+#[doc(alias = "abs")]
+#[builtin(sym = "abs")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAbs;
+
+impl Callable for PrimitiveAbs {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let result = match x {
+            Obj::Vector(Vector::Double(v)) => {
+                Vector::Double(v.map_numeric(|x: Double| x.map(f64::abs)))
+            }
+            Obj::Vector(Vector::Integer(v)) => {
+                Vector::Integer(v.map_numeric(|x: Integer| x.map(i32::abs)))
+            }
+            Obj::Vector(Vector::Logical(v)) => {
+                Vector::Integer(v.map_numeric(|x: Integer| x.map(i32::abs)))
+            }
+            _ => return non_numeric(),
+        };
+
+        Ok(Obj::Vector(result))
+    }
+}
+
+/// Square Roots
+///
+/// The square roots of a numeric vector. Negative values produce `NaN`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sqrt(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sqrt(c(1, 4, 9))
+/// ```
+///
+#[doc(alias = "sqrt")]
+#[builtin(sym = "sqrt")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSqrt;
+
+impl Callable for PrimitiveSqrt {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::sqrt)
+    }
+}
+
+/// Exponentials
+///
+/// The exponential function, `e` raised to the power of each value.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// exp(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// exp(c(0, 1))
+/// ```
+///
+#[doc(alias = "exp")]
+#[builtin(sym = "exp")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveExp;
+
+impl Callable for PrimitiveExp {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::exp)
+    }
+}
+
+/// Logarithms
+///
+/// Logarithms of a numeric vector, by default natural logarithms.
+/// Negative values produce `NaN`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// log(x, base = exp(1))
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `base`: The base of the logarithm.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// log(c(1, 10, 100))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// log(8, base = 2)
+/// ```
+///
+#[doc(alias = "log")]
+#[builtin(sym = "log")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveLog;
+
+impl Callable for PrimitiveLog {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("base".to_string()), Expr::Number(std::f64::consts::E)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let base: f64 = args.try_get_named("base")?.force(stack)?.try_into()?;
+
+        match base {
+            std::f64::consts::E => map_double(x, stack, f64::ln),
+            base => map_double(x, stack, |x| x.ln() / base.ln()),
+        }
+    }
+}
+
+/// Base 2 Logarithms
+///
+/// Logarithms of a numeric vector in base `2`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// log2(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// log2(c(1, 2, 1024))
+/// ```
+///
+#[doc(alias = "log2")]
+#[builtin(sym = "log2")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveLog2;
+
+impl Callable for PrimitiveLog2 {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::log2)
+    }
+}
+
+/// Base 10 Logarithms
+///
+/// Logarithms of a numeric vector in base `10`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// log10(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// log10(c(1, 10, 1000))
+/// ```
+///
+#[doc(alias = "log10")]
+#[builtin(sym = "log10")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveLog10;
+
+impl Callable for PrimitiveLog10 {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::log10)
+    }
+}
+
+/// Round Down
+///
+/// The largest whole numbers not greater than each value.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// floor(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// floor(c(-1.5, 1.5))
+/// ```
+///
+#[doc(alias = "floor")]
+#[builtin(sym = "floor")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveFloor;
+
+impl Callable for PrimitiveFloor {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::floor)
+    }
+}
+
+/// Round Up
+///
+/// The smallest whole numbers not less than each value.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// ceiling(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// ceiling(c(-1.5, 1.5))
+/// ```
+///
+#[doc(alias = "ceiling")]
+#[builtin(sym = "ceiling")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCeiling;
+
+impl Callable for PrimitiveCeiling {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::ceil)
+    }
+}
+
+/// Round Towards Zero
+///
+/// The whole number parts of each value, rounding towards zero.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// trunc(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// trunc(c(-1.5, 1.5))
+/// ```
+///
+#[doc(alias = "trunc")]
+#[builtin(sym = "trunc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTrunc;
+
+impl Callable for PrimitiveTrunc {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::trunc)
+    }
+}
+
+/// Round to Decimal Places
+///
+/// Round values to a number of decimal places. Values halfway between
+/// two representable values are rounded to the even digit.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// round(x, digits = 0)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `digits`: The number of decimal places to keep. Negative values round
+///   to tens, hundreds, and so on.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// round(c(0.5, 1.5, 2.5))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// round(3.14159, digits = 2)
+/// ```
+///
+#[doc(alias = "round")]
+#[builtin(sym = "round")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRound;
+
+impl Callable for PrimitiveRound {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("digits".to_string()), Expr::Number(0.0)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let digits: f64 = args.try_get_named("digits")?.force(stack)?.try_into()?;
+        map_double(x, stack, |x| round_digits(x, digits))
+    }
+}
+
+/// Round to Significant Digits
+///
+/// Round values to a number of significant digits.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// signif(x, digits = 6)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `digits`: The number of significant digits to keep, at least `1`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// signif(123456.789, digits = 3)
+/// ```
+///
+#[doc(alias = "signif")]
+#[builtin(sym = "signif")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSignif;
+
+impl Callable for PrimitiveSignif {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("digits".to_string()), Expr::Number(6.0)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let digits: f64 = args.try_get_named("digits")?.force(stack)?.try_into()?;
+        map_double(x, stack, |x| signif_digits(x, digits))
+    }
+}
+
+/// Sine
+///
+/// The sine of angles in radians.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sin(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector of angles in radians.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sin(c(0, 1.5707963))
+/// ```
+///
+#[doc(alias = "sin")]
+#[builtin(sym = "sin")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSin;
+
+impl Callable for PrimitiveSin {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::sin)
+    }
+}
+
+/// Cosine
+///
+/// The cosine of angles in radians.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// cos(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector of angles in radians.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// cos(c(0, 3.1415927))
+/// ```
+///
+#[doc(alias = "cos")]
+#[builtin(sym = "cos")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCos;
+
+impl Callable for PrimitiveCos {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::cos)
+    }
+}
+
+/// Tangent
+///
+/// The tangent of angles in radians.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// tan(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector of angles in radians.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// tan(c(0, 0.7853982))
+/// ```
+///
+#[doc(alias = "tan")]
+#[builtin(sym = "tan")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTan;
+
+impl Callable for PrimitiveTan {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::tan)
+    }
+}
+
+/// Arcsine
+///
+/// The arcsine, in radians. Values outside `[-1, 1]` produce `NaN`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// asin(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// asin(c(0, 1))
+/// ```
+///
+#[doc(alias = "asin")]
+#[builtin(sym = "asin")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAsin;
+
+impl Callable for PrimitiveAsin {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::asin)
+    }
+}
+
+/// Arccosine
+///
+/// The arccosine, in radians. Values outside `[-1, 1]` produce `NaN`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// acos(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// acos(c(0, 1))
+/// ```
+///
+#[doc(alias = "acos")]
+#[builtin(sym = "acos")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAcos;
+
+impl Callable for PrimitiveAcos {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::acos)
+    }
+}
+
+/// Arctangent
+///
+/// The arctangent, in radians.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// atan(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// atan(c(0, 1))
+/// ```
+///
+#[doc(alias = "atan")]
+#[builtin(sym = "atan")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAtan;
+
+impl Callable for PrimitiveAtan {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        map_double(x, stack, f64::atan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::context::Context;
+    use crate::error::Error;
+    use crate::lang::CallStack;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn elementwise() {
+        assert_eq!(r! { sqrt(c(1, 4, 9)) }, r! { c(1, 2, 3) });
+        assert_eq!(r! { exp(0) }, r! { 1 });
+        assert_eq!(r! { log(c(1, 8, 64), base = 2) }, r! { c(0, 3, 6) });
+        assert_eq!(r! { log10(1000) }, r! { 3 });
+        r_expect! { log(exp(2)) == 2 }
+        r_expect! { cos(0) == 1 && sin(0) == 0 && atan(0) == 0 }
+    }
+
+    #[test]
+    fn integers_and_logicals() {
+        assert_eq!(r! { abs(c(-2L, 3L)) }, r! { c(2L, 3L) });
+        assert_eq!(r! { abs(true) }, r! { 1L });
+        assert_eq!(r! { sqrt(4L) }, r! { 2 });
+        assert_eq!(r! { floor(true) }, r! { 1 });
+    }
+
+    #[test]
+    fn missing_values() {
+        assert_eq!(r! { sqrt(c(4, NA)) }, r! { c(2, NA) });
+        assert_eq!(r! { abs(c(NA, -1L)) }, r! { c(NA, 1L) });
+    }
+
+    #[test]
+    fn keeps_attributes() {
+        assert_eq!(r! { floor(c(a = 1.5, b = -1.5)) }, r! { c(a = 1, b = -2) });
+        r_expect! { dim(sqrt(matrix(1:4, nrow = 2)))[1] == 2 }
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(r! { round(c(0.5, 1.5, 2.5, -0.5)) }, r! { c(0, 2, 2, 0) });
+        assert_eq!(r! { round(0.15, 1) }, r! { 0.1 });
+        assert_eq!(r! { round(3.14159, digits = 2) }, r! { 3.14 });
+        assert_eq!(r! { round(1234, digits = -2) }, r! { 1200 });
+        assert_eq!(r! { signif(123456.789, digits = 3) }, r! { 123000 });
+        assert_eq!(r! { signif(0.00123456, 2) }, r! { 0.0012 });
+        assert_eq!(r! { ceiling(c(-1.5, 1.5)) }, r! { c(-1, 2) });
+        assert_eq!(r! { trunc(c(-1.5, 1.5)) }, r! { c(-1, 1) });
+    }
+
+    #[test]
+    fn non_numeric() {
+        assert_eq!(
+            r! { sqrt("a") },
+            Error::Other("non-numeric argument to mathematical function".into()).into()
+        )
+    }
+
+    #[test]
+    fn nans_produced() {
+        let mut stack = CallStack::default();
+        let expr = stack.parse("sqrt(-1)").expect("parse failed");
+        let value: f64 = stack.eval_and_finalize(expr).unwrap().try_into().unwrap();
+
+        assert!(value.is_nan());
+        assert_eq!(
+            stack.flush_warnings(),
+            Some("Warning message:\nNaNs produced\n".to_string())
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Matrix Multiplication
+///
+/// Multiplies two conformable matrices. A vector is promoted to either a
+/// row or a column matrix, whichever makes the arguments conformable, such
+/// that two vectors produce their inner product.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// x %*% y
+/// ```
+///
+/// ## Arguments
+///
+/// `x`, `y`: `numeric` matrices or vectors.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// matrix(1:4, 2) %*% matrix(1:4, 2)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// 1:3 %*% 1:3
+/// ```
+///
+#[doc(alias = "%*%")]
+#[builtin(sym = "%*%")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMatMul;
+
+impl Callable for PrimitiveMatMul {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("y".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let y = args.try_get_named("y")?.force(stack)?;
+
+        let err = || Error::Other("requires numeric matrix/vector arguments".into()).into();
+        let (Obj::Vector(xv), Obj::Vector(yv)) = (&x, &y) else {
+            return err();
+        };
+
+        if let (Vector::Character(_), _) | (_, Vector::Character(_)) = (xv, yv) {
+            return err();
+        }
+
+        let (xdim, ydim) = (xv.attributes().dim(), yv.attributes().dim());
+        if xdim.as_ref().is_some_and(|d| d.len() != 2)
+            || ydim.as_ref().is_some_and(|d| d.len() != 2)
+        {
+            return err();
+        }
+
+        // vectors become whichever of a row or column conforms
+        let (xn, yn) = (xv.len(), yv.len());
+        let (n, k) = match (&xdim, &ydim) {
+            (Some(d), _) => (d[0], d[1]),
+            (None, Some(d)) if d[0] == xn => (1, xn),
+            (None, Some(_)) => (xn, 1),
+            (None, None) => (1, xn),
+        };
+
+        let (yk, m) = match (&ydim, &xdim) {
+            (Some(d), _) => (d[0], d[1]),
+            (None, _) if k == yn => (yn, 1),
+            (None, _) if k == 1 => (1, yn),
+            (None, _) => (yn, 1),
+        };
+
+        if k != yk {
+            return Error::Other("non-conformable arguments".into()).into();
+        }
+
+        let xs: Vec<f64> = x.try_into()?;
+        let ys: Vec<f64> = y.try_into()?;
+
+        let mut result = vec![0.0; n * m];
+        for j in 0..m {
+            for i in 0..n {
+                result[j * n + i] = (0..k).map(|l| xs[l * n + i] * ys[j * k + l]).sum();
+            }
+        }
+
+        let result = Vector::from(result);
+        result
+            .attributes()
+            .set("dim", Obj::Vector(vec![n as i32, m as i32].into()));
+
+        Ok(Obj::Vector(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn matrix_product() {
+        r_expect! {{r#"
+            m <- matrix(c(1, 2, 3, 4), nrow = 2) %*% matrix(c(5, 6, 7, 8), nrow = 2)
+            m[1, 1] == 23 && m[2, 1] == 34 && m[1, 2] == 31 && m[2, 2] == 46
+        "#}}
+    }
+
+    #[test]
+    fn inner_product() {
+        assert_eq!(r! { dim(c(1, 2, 3) %*% c(1, 2, 3)) }, r! { c(1L, 1L) });
+        r_expect! { (c(1, 2, 3) %*% c(1, 2, 3))[1, 1] == 14 }
+    }
+
+    #[test]
+    fn matrix_vector_product() {
+        assert_eq!(
+            r! { dim(matrix(1:6, nrow = 2) %*% c(1, 1, 1)) },
+            r! { c(2L, 1L) }
+        );
+        assert_eq!(
+            r! { dim(c(1, 1) %*% matrix(1:6, nrow = 2)) },
+            r! { c(1L, 3L) }
+        );
+    }
+
+    #[test]
+    fn non_conformable() {
+        assert_eq!(
+            r! { matrix(1:6, nrow = 2) %*% matrix(1:6, nrow = 2) },
+            Error::Other("non-conformable arguments".to_string()).into()
+        )
+    }
+}
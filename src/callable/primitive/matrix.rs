@@ -0,0 +1,184 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::Integer;
+use crate::object::*;
+
+/// Create a Matrix
+///
+/// Creates a matrix from a vector of values, recycling the values to fill
+/// every cell. Values fill the matrix column by column, or row by row when
+/// `byrow` is `TRUE`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// matrix(data = NA, nrow, ncol, byrow = FALSE)
+/// ```
+///
+/// ## Arguments
+///
+/// `data`: A vector of values.
+/// `nrow`: The number of rows. When omitted, it is derived from `data` and
+///   `ncol`.
+/// `ncol`: The number of columns. When omitted, it is derived from `data`
+///   and `nrow`.
+/// `byrow`: Whether values fill the matrix row by row.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// matrix(1:6, nrow = 2)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// matrix(1:6, ncol = 2, byrow = true)
+/// ```
+///
+#[doc(alias = "matrix")]
+#[builtin(sym = "matrix")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMatrix;
+
+impl Callable for PrimitiveMatrix {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("data".to_string()), Expr::NA),
+            (Some("nrow".to_string()), Expr::Missing),
+            (Some("ncol".to_string()), Expr::Missing),
+            (Some("byrow".to_string()), Expr::Bool(false)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let Obj::Vector(data) = args.try_get_named("data")?.force(stack)? else {
+            return Error::Other("'data' must be of a vector type".into()).into();
+        };
+
+        let nrow = extent(&mut args, "nrow", stack)?;
+        let ncol = extent(&mut args, "ncol", stack)?;
+        let byrow: bool = args.try_get_named("byrow")?.force(stack)?.try_into()?;
+
+        // omitted extents are derived from the length of the data
+        let n = data.len();
+        let (nrow, ncol) = match (nrow, ncol) {
+            (Some(nrow), Some(ncol)) => (nrow, ncol),
+            (Some(nrow), None) => (nrow, n.div_ceil(nrow.max(1))),
+            (None, Some(ncol)) => (n.div_ceil(ncol.max(1)), ncol),
+            (None, None) => (n, 1),
+        };
+
+        let indices = (0..nrow * ncol).map(|k| match byrow {
+            true => (k % nrow) * ncol + k / nrow,
+            false => k,
+        });
+
+        Ok(Obj::Vector(reshape(data, indices, &[nrow, ncol])))
+    }
+}
+
+/// Get an optional, non-negative dimension extent argument
+fn extent(args: &mut Obj, name: &str, stack: &mut CallStack) -> Result<Option<usize>, Signal> {
+    let Ok(value) = args.try_get_named(name) else {
+        return Ok(None);
+    };
+
+    let value: i32 = value.force(stack)?.try_into()?;
+    match value {
+        value if value < 0 => Error::Other(format!("invalid '{name}' value (< 0)")).into(),
+        value => Ok(Some(value as usize)),
+    }
+}
+
+/// Rearrange the values of a vector into an array
+///
+/// Each (0-indexed) position of the data is taken in turn to fill the
+/// array in column-major order, recycling the data as needed. The names
+/// of the data are discarded.
+///
+pub fn reshape(data: Vector, indices: impl Iterator<Item = usize>, dim: &[usize]) -> Vector {
+    // values are recycled, filling with NA when there is no data
+    let data = match data.len() {
+        0 => Vector::from(vec![OptionNA::<bool>::NA]),
+        _ => data,
+    };
+
+    let n = data.len();
+    let indices: Vec<Integer> = indices.map(|i| OptionNA::Some((i % n) as i32)).collect();
+    let result = data.subset(Subset::Indices(indices.into())).materialize();
+
+    let dim: Vec<i32> = dim.iter().map(|d| *d as i32).collect();
+    result.attributes().set("names", Obj::Null);
+    result.attributes().set("dim", Obj::Vector(dim.into()));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn fills_by_column() {
+        r_expect! {{r#"
+            m <- matrix(1:6, nrow = 2)
+            m[1, 2] == 3 && m[2, 1] == 2
+        "#}}
+    }
+
+    #[test]
+    fn fills_by_row() {
+        r_expect! {{r#"
+            m <- matrix(1:6, nrow = 2, byrow = true)
+            m[1, 2] == 2 && m[2, 1] == 4
+        "#}}
+    }
+
+    #[test]
+    fn derives_extents() {
+        assert_eq!(r! { dim(matrix(1:6, ncol = 2)) }, r! { c(3L, 2L) });
+        assert_eq!(r! { dim(matrix(1:6)) }, r! { c(6L, 1L) });
+    }
+
+    #[test]
+    fn recycles_data() {
+        r_expect! {{r#"
+            m <- matrix(0, nrow = 2, ncol = 2)
+            length(m) == 4 && m[2, 2] == 0
+        "#}}
+    }
+
+    #[test]
+    fn implicit_class() {
+        assert_eq!(r! { class(matrix(1:4, 2)) }, r! { c("matrix", "array") })
+    }
+
+    #[test]
+    fn negative_extent() {
+        assert_eq!(
+            r! { matrix(1:4, nrow = -1) },
+            Error::Other("invalid 'nrow' value (< 0)".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", r! { matrix(c(1, 20, 3, 4), nrow = 2) }.unwrap()),
+            "     [,1] [,2]\n[1,]    1    3\n[2,]   20    4"
+        )
+    }
+
+    #[test]
+    fn display_character() {
+        assert_eq!(
+            format!("{}", r! { matrix(c("a", "bb"), nrow = 1) }.unwrap()),
+            "     [,1] [,2]\n[1,] \"a\"  \"bb\""
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use r_derive::*;
+use std::io::Write;
+
+use super::stop::condition_message;
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Emit a Message
+///
+/// Signals a message. When a `message` handler has been established by
+/// `tryCatch()`, evaluation is interrupted and the handler is called.
+/// Otherwise, the message is written to the console.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// message(...)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects which are coerced to `character` and concatenated to
+///   produce the message.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// message("processed ", 3, " items")
+/// ```
+///
+#[doc(alias = "message")]
+#[builtin(sym = "message")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMessage;
+impl Callable for PrimitiveMessage {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(None, Expr::Ellipsis(None))])
+    }
+
+    fn call_matched(&self, _args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let message = condition_message(ellipsis, stack)?;
+
+        if stack.is_handled("message") {
+            return Cond::Message(message).into();
+        }
+
+        writeln!(stack.session.output, "{message}").ok();
+        Ok(Obj::Null)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::r;
+
+    #[test]
+    fn returns_null() {
+        assert_eq!(r! { message("hello") }, r! { null })
+    }
+}
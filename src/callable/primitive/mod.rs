@@ -1,9 +1,9 @@
 mod c;
-pub use c::PrimitiveC;
+pub use c::*;
 mod callstack;
-pub use callstack::PrimitiveCallstack;
+pub use callstack::*;
 mod environment;
-pub use environment::PrimitiveEnvironment;
+pub use environment::*;
 mod eval;
 pub use eval::PrimitiveEval;
 mod list;
@@ -15,7 +15,7 @@ pub use parent::PrimitiveParent;
 mod paste;
 pub use paste::PrimitivePaste;
 mod print;
-pub use print::PrimitivePrint;
+pub use print::{print_value, PrimitivePrint};
 mod q;
 pub use q::PrimitiveQ;
 mod quote;
@@ -30,3 +30,97 @@ mod sum;
 pub use sum::PrimitiveSum;
 mod length;
 pub use length::PrimitiveLength;
+mod stop;
+pub use stop::PrimitiveStop;
+mod warning;
+pub use warning::PrimitiveWarning;
+mod message;
+pub use message::PrimitiveMessage;
+mod try_catch;
+pub use try_catch::PrimitiveTryCatch;
+mod condition;
+pub use condition::*;
+mod on_exit;
+pub use on_exit::PrimitiveOnExit;
+mod attr;
+pub use attr::PrimitiveAttr;
+mod attr_assign;
+pub use attr_assign::PrimitiveAttrAssign;
+mod attributes;
+pub use attributes::PrimitiveAttributes;
+mod names_assign;
+pub use names_assign::PrimitiveNamesAssign;
+mod structure;
+pub use structure::PrimitiveStructure;
+mod class;
+pub use class::PrimitiveClass;
+mod class_assign;
+pub use class_assign::PrimitiveClassAssign;
+mod unclass;
+pub use unclass::PrimitiveUnclass;
+mod use_method;
+pub use use_method::PrimitiveUseMethod;
+mod next_method;
+pub use next_method::PrimitiveNextMethod;
+mod dim;
+pub use dim::PrimitiveDim;
+mod dim_assign;
+pub use dim_assign::PrimitiveDimAssign;
+mod matrix;
+pub use matrix::PrimitiveMatrix;
+mod array;
+pub use array::PrimitiveArray;
+mod nrow;
+pub use nrow::PrimitiveNrow;
+mod ncol;
+pub use ncol::PrimitiveNcol;
+mod t;
+pub use t::PrimitiveT;
+mod matmul;
+pub use matmul::PrimitiveMatMul;
+mod data_frame;
+pub use data_frame::PrimitiveDataFrame;
+mod head;
+pub use head::PrimitiveHead;
+mod cbind;
+pub use cbind::PrimitiveCbind;
+mod rbind;
+pub use rbind::PrimitiveRbind;
+mod read_csv;
+pub use read_csv::PrimitiveReadCsv;
+mod write_csv;
+pub use write_csv::PrimitiveWriteCsv;
+mod set_seed;
+pub use set_seed::PrimitiveSetSeed;
+mod sample;
+pub use sample::PrimitiveSample;
+mod rbinom;
+pub use rbinom::PrimitiveRbinom;
+mod rpois;
+pub use rpois::PrimitiveRpois;
+mod rexp;
+pub use rexp::PrimitiveRexp;
+mod distributions;
+pub use distributions::*;
+mod math;
+pub use math::*;
+mod summary;
+pub use summary::*;
+mod sort;
+pub use sort::*;
+mod functional;
+pub use functional::*;
+mod strings;
+pub use strings::*;
+mod sprintf;
+pub use sprintf::*;
+mod pattern;
+pub use pattern::*;
+mod coerce;
+pub use coerce::*;
+mod types;
+pub use types::*;
+mod variables;
+pub use variables::*;
+mod formula;
+pub use formula::*;
@@ -58,14 +58,18 @@ impl Callable for PrimitiveNames {
 
     fn call_matched(&self, args: List, mut _ellipsis: List, stack: &mut CallStack) -> EvalResult {
         let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        if let Some(result) = stack.dispatch("names", &x, vec![(None, x.clone())]) {
+            return result;
+        }
 
         use Obj::*;
         match x {
             Null => Ok(Null),
             Promise(..) => Ok(Null),
-            Vector(..) => Ok(Null), // named vectors currently not supported...
-            Expr(..) => Ok(Null),   // handle arg lists?
+            x @ Vector(..) => Ok(x.get_attr("names").unwrap_or_default()),
+            Expr(..) => Ok(Null),     // handle arg lists?
             Function(..) => Ok(Null), // return formals?
+            Formula(..) => Ok(Null),
             List(x) => {
                 Ok(x.values
                     .borrow()
@@ -104,6 +108,16 @@ mod test {
         assert_eq!(r! { x <- 3; names(environment()) }, r! { "x" })
     }
 
+    #[test]
+    fn from_vector() {
+        assert_eq!(r! { names(c(a = 1, b = 2)) }, r! { c("a", "b") })
+    }
+
+    #[test]
+    fn from_unnamed_vector() {
+        assert_eq!(r! { names(c(1, 2)) }, r! { null })
+    }
+
     #[test]
     fn from_list() {
         assert_eq!(
@@ -111,4 +125,15 @@ mod test {
             r! { c("a", "b", NA, "d") }
         )
     }
+
+    #[test]
+    fn dispatch() {
+        assert_eq!(
+            r! {{r#"
+                names.a <- function(x) "method"
+                names(structure(1, class = "a"))
+            "#}},
+            r! { "method" }
+        )
+    }
 }
@@ -0,0 +1,184 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Set Names of an Object
+///
+/// Set the element names of a vector or `list`. Names shorter than the
+/// object are padded with `NA`s, and setting names to `NULL` removes them.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// names(x) <- value
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose names to set.
+/// `value`: A `character` vector of names, or `NULL`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- 1:3
+/// names(x) <- c("a", "b", "c")
+/// x
+/// ```
+///
+#[doc(alias = "names<-")]
+#[builtin(sym = "names<-")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveNamesAssign;
+
+impl Callable for PrimitiveNamesAssign {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("value".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let value = args.try_get_named("value")?.force(stack)?;
+        x.set_attr("names", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn vector_names() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1:3
+                names(x) <- c("a", "b", "c")
+                names(x)
+            "#}},
+            r! { c("a", "b", "c") }
+        )
+    }
+
+    #[test]
+    fn padded_with_na() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1:3
+                names(x) <- "a"
+                names(x)
+            "#}},
+            r! { c("a", NA, NA) }
+        )
+    }
+
+    #[test]
+    fn too_many_names() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1:2
+                names(x) <- c("a", "b", "c")
+            "#}},
+            Error::Other(
+                "'names' attribute [3] must be the same length as the vector [2]".to_string()
+            )
+            .into()
+        )
+    }
+
+    #[test]
+    fn remove_names() {
+        assert_eq!(
+            r! {{r#"
+                x <- c(a = 1, b = 2)
+                names(x) <- null
+                names(x)
+            "#}},
+            r! { null }
+        )
+    }
+
+    #[test]
+    fn list_names() {
+        r_expect! {{r#"
+            l <- list(1, 2)
+            names(l) <- c("a", "b")
+            l$a == 1 & l$b == 2
+        "#}}
+    }
+
+    #[test]
+    fn nested_target() {
+        assert_eq!(
+            r! {{r#"
+                l <- list(a = 1:2)
+                names(l$a) <- c("x", "y")
+                l$a
+            "#}},
+            r! { c(x = 1L, y = 2L) }
+        )
+    }
+
+    #[test]
+    fn indexed_names() {
+        assert_eq!(
+            r! {{r#"
+                x <- c(a = 1, b = 2, c = 3)
+                names(x)[2] <- "B"
+                x
+            "#}},
+            r! { c(a = 1, B = 2, c = 3) }
+        );
+        assert_eq!(
+            r! {{r#"
+                l <- list(a = 1, b = 2)
+                names(l)[[1]] <- "A"
+                names(l)
+            "#}},
+            r! { c("A", "b") }
+        )
+    }
+
+    #[test]
+    fn indexed_names_of_unnamed() {
+        assert_eq!(
+            r! {{r#"
+                x <- 1:3
+                names(x)[2] <- "z"
+                names(x)
+            "#}},
+            r! { c(NA, "z", NA) }
+        )
+    }
+
+    #[test]
+    fn index_by_name() {
+        assert_eq!(
+            r! {{r#"
+                x <- c(1, 2, 3)
+                names(x) <- c("a", "b", "c")
+                x[c("c", "a")]
+            "#}},
+            r! { c(c = 3, a = 1) }
+        )
+    }
+
+    #[test]
+    fn names_kept_by_arithmetic() {
+        assert_eq!(
+            r! {{r#"
+                x <- c(a = 1, b = 2)
+                names(x * 2)
+            "#}},
+            r! { c("a", "b") }
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Get the Number of Columns of a Matrix
+///
+/// Returns the number of columns of a matrix, array or data frame, or `NULL`
+/// for objects without dimensions.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// ncol(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A matrix, array or data frame.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// ncol(matrix(1:6, nrow = 2))
+/// ```
+///
+#[doc(alias = "ncol")]
+#[builtin(sym = "ncol")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveNcol;
+
+impl Callable for PrimitiveNcol {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        match x.dim().and_then(|dim| dim.get(1).copied()) {
+            Some(extent) => Ok(Obj::Vector(vec![extent as i32].into())),
+            None => Ok(Obj::Null),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn matrix_ncol() {
+        assert_eq!(r! { ncol(matrix(1:6, nrow = 2)) }, r! { 3L })
+    }
+
+    #[test]
+    fn vector_ncol() {
+        assert_eq!(r! { ncol(1:6) }, r! { null })
+    }
+}
@@ -0,0 +1,119 @@
+use r_derive::*;
+
+use super::use_method::builtin_method;
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Dispatch to the Next S3 Method
+///
+/// Called from within an S3 method, `NextMethod()` calls the method for the
+/// next of the object's classes, or the default method if no other method
+/// is found. The next method is called with the same arguments as the
+/// current method.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// NextMethod()
+/// ```
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// describe <- function(x) UseMethod("describe")
+/// describe.default <- function(x) "a thing"
+/// describe.dog <- function(x) paste("a dog and", NextMethod())
+/// describe(structure(list(), class = "dog"))
+/// ```
+///
+#[doc(alias = "NextMethod")]
+#[builtin(sym = "NextMethod")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveNextMethod;
+
+impl Callable for PrimitiveNextMethod {
+    fn call_matched(&self, _args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        // the method was called from a frame recording the dispatch state
+        let i = stack.origin_frame();
+        let dispatch = match i {
+            0 => None,
+            i => stack.frames[i - 1].dispatch.clone(),
+        };
+
+        let Some(Dispatch {
+            generic,
+            classes,
+            args,
+        }) = dispatch
+        else {
+            return Error::Other("NextMethod called from outside a method dispatch".into()).into();
+        };
+
+        let env = stack.env();
+        let (method, classes) = match stack.find_method(&generic, &classes, &[env]) {
+            Some(found) => found,
+            None => match builtin_method(&generic) {
+                Some(method) => (method, vec![]),
+                None => return Error::Other(format!("no more methods for '{generic}'")).into(),
+            },
+        };
+
+        let dispatch = Dispatch {
+            generic,
+            classes,
+            args,
+        };
+
+        stack.call_method(method, dispatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn next_class() {
+        r_expect! {{r#"
+            f <- function(x) UseMethod("f")
+            f.a <- function(x) paste("a", NextMethod())
+            f.b <- function(x) paste("b", NextMethod())
+            f.default <- function(x) "default"
+            f(structure(1, class = c("a", "b"))) == "a b default"
+        "#}}
+    }
+
+    #[test]
+    fn next_builtin() {
+        r_expect! {{r#"
+            length.a <- function(x) NextMethod() * 10
+            length(structure(c(1, 2), class = "a")) == 20
+        "#}}
+    }
+
+    #[test]
+    fn no_more_methods() {
+        assert_eq!(
+            r! {{r#"
+                f <- function(x) UseMethod("f")
+                f.a <- function(x) NextMethod()
+                f(structure(1, class = "a"))
+            "#}},
+            Error::Other("no more methods for 'f'".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn outside_dispatch() {
+        assert_eq!(
+            r! { NextMethod() },
+            Error::Other("NextMethod called from outside a method dispatch".to_string()).into()
+        )
+    }
+}
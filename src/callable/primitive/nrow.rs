@@ -0,0 +1,62 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Get the Number of Rows of a Matrix
+///
+/// Returns the number of rows of a matrix, array or data frame, or `NULL`
+/// for objects without dimensions.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// nrow(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A matrix, array or data frame.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// nrow(matrix(1:6, nrow = 2))
+/// ```
+///
+#[doc(alias = "nrow")]
+#[builtin(sym = "nrow")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveNrow;
+
+impl Callable for PrimitiveNrow {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        match x.dim().and_then(|dim| dim.first().copied()) {
+            Some(extent) => Ok(Obj::Vector(vec![extent as i32].into())),
+            None => Ok(Obj::Null),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn matrix_nrow() {
+        assert_eq!(r! { nrow(matrix(1:6, nrow = 2)) }, r! { 2L })
+    }
+
+    #[test]
+    fn vector_nrow() {
+        assert_eq!(r! { nrow(1:6) }, r! { null })
+    }
+}
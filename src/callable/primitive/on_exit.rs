@@ -0,0 +1,130 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Register an Expression to Evaluate on Exit
+///
+/// Registers an expression to be evaluated when the current function call
+/// exits, for example to restore state that the function has modified.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// on.exit(expr = NULL, add = FALSE, after = TRUE)
+/// ```
+///
+/// ## Arguments
+///
+/// `expr`: An expression to evaluate when the function exits.
+/// `add`: Whether `expr` should be added to previously registered
+///   expressions, or replace them.
+/// `after`: When `add` is `TRUE`, whether `expr` should be evaluated after
+///   previously registered expressions, or before them.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- fn() {
+///   on.exit(print("exiting"))
+///   print("running")
+/// }
+/// f()
+/// ```
+///
+#[doc(alias = "on.exit")]
+#[builtin(sym = "on.exit")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveOnExit;
+impl Callable for PrimitiveOnExit {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some(String::from("expr")), Expr::Null),
+            (Some(String::from("add")), Expr::Bool(false)),
+            (Some(String::from("after")), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+
+        // capture the expression unevaluated
+        let expr = match args.try_get_named("expr")? {
+            Obj::Promise(_, expr, _) => expr,
+            _ => Expr::Null,
+        };
+
+        let add: bool = args.try_get_named("add")?.force(stack)?.try_into()?;
+        let after: bool = args.try_get_named("after")?.force(stack)?.try_into()?;
+
+        let i = stack.origin_frame();
+        let on_exit = &mut stack.frames[i].on_exit;
+
+        match (add, after) {
+            (false, _) => *on_exit = vec![expr],
+            (true, true) => on_exit.push(expr),
+            (true, false) => on_exit.insert(0, expr),
+        }
+
+        on_exit.retain(|e| *e != Expr::Null);
+        Ok(Obj::Null)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn evaluated_in_function_env() {
+        r_expect! {{"
+            f <- fn() {
+              x <- 1
+              on.exit(x <- 10)
+              environment()
+            }
+            e <- f()
+            eval(quote(x), e) == 10
+        "}}
+    }
+
+    #[test]
+    fn add_and_after() {
+        r_expect! {{"
+            f <- fn() {
+              x <- ''
+              on.exit(x <- paste(x, 'a', sep = ''))
+              on.exit(x <- paste(x, 'b', sep = ''), add = true)
+              on.exit(x <- paste(x, 'c', sep = ''), add = true, after = false)
+              environment()
+            }
+            eval(quote(x), f()) == 'cab'
+        "}}
+    }
+
+    #[test]
+    fn evaluated_when_error_is_caught() {
+        r_expect! {{"
+            f <- fn() {
+              e <- environment()
+              g <- fn() { on.exit(eval(quote(x <- 2), e)); stop('!') }
+              x <- 1
+              tryCatch(g(), error = fn(err) 0)
+              x
+            }
+            f() == 2
+        "}}
+    }
+
+    #[test]
+    fn handler_errors_propagate() {
+        assert_eq!(
+            r! { f <- fn() { on.exit(stop("in handler")); 1 }; f() },
+            Error::Other("in handler".to_string()).into()
+        )
+    }
+}
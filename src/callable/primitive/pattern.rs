@@ -0,0 +1,670 @@
+use r_derive::*;
+use regex::{NoExpand, Regex, RegexBuilder};
+
+use super::strings::{characters, with_names};
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// Compile a regular expression
+///
+/// With `fixed`, the pattern is matched literally.
+///
+pub fn compile(pattern: &str, fixed: bool, ignore_case: bool) -> Result<Regex, Signal> {
+    let pattern = match fixed {
+        true => regex::escape(pattern),
+        false => pattern.to_string(),
+    };
+
+    match RegexBuilder::new(&pattern)
+        .case_insensitive(ignore_case)
+        .build()
+    {
+        Ok(re) => Ok(re),
+        Err(_) => Error::Other(format!("invalid regular expression '{pattern}'")).into(),
+    }
+}
+
+/// Compile the `pattern` argument of a pattern matching function
+///
+/// Only the first pattern is used. A missing pattern produces `None`, such
+/// that every result is missing.
+///
+fn pattern_arg(args: &mut Obj, stack: &mut CallStack) -> Result<Option<Regex>, Signal> {
+    let pattern = characters(args.try_get_named("pattern")?.force(stack)?, "pattern")?;
+    let ignore_case: bool = args
+        .try_get_named("ignore.case")?
+        .force(stack)?
+        .try_into()?;
+    let fixed: bool = args.try_get_named("fixed")?.force(stack)?.try_into()?;
+
+    if pattern.len() > 1 {
+        stack.warn(
+            "argument 'pattern' has length > 1 and only the first element will be used".into(),
+        )?;
+    }
+
+    match pattern.into_iter().next() {
+        Some(OptionNA::Some(pattern)) => Ok(Some(compile(&pattern, fixed, ignore_case)?)),
+        Some(OptionNA::NA) => Ok(None),
+        None => Error::Other("invalid 'pattern' argument".into()).into(),
+    }
+}
+
+fn pattern_formals(before: &[&str]) -> ExprList {
+    let mut formals = vec![(Some("pattern".to_string()), Expr::Missing)];
+    formals.extend(
+        before
+            .iter()
+            .map(|name| (Some(name.to_string()), Expr::Missing)),
+    );
+    formals.push((Some("ignore.case".to_string()), Expr::Bool(false)));
+    formals.push((Some("fixed".to_string()), Expr::Bool(false)));
+    ExprList::from(formals)
+}
+
+/// Translate a replacement into the syntax of the regex engine
+///
+/// Backreferences to capture groups, `\1` through `\9`, refer to groups by
+/// number, and any other escaped character is taken literally.
+///
+fn replacement(x: &str) -> String {
+    let mut result = String::with_capacity(x.len());
+    let mut chars = x.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(d) if d.is_ascii_digit() => result.push_str(&format!("${{{d}}}")),
+                Some('$') => result.push_str("$$"),
+                Some(c) => result.push(c),
+                None => result.push('\\'),
+            },
+            '$' => result.push_str("$$"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// The 1-indexed character position of a byte offset into a string
+fn char_position(x: &str, byte: usize) -> i32 {
+    x[..byte].chars().count() as i32 + 1
+}
+
+/// Attach the lengths of matches to their positions
+fn with_match_length(positions: Vec<Integer>, lengths: Vec<Integer>) -> Vector {
+    let result = Vector::from(positions);
+    result
+        .attributes()
+        .set("match.length", Obj::Vector(Vector::from(lengths)));
+    result
+}
+
+/// Pattern Matching
+///
+/// Tests whether each string contains a match of a regular expression.
+/// Missing strings never match.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// grepl(pattern, x, ignore.case = false, fixed = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `pattern`: A regular expression.
+/// `x`: A vector of strings.
+/// `ignore.case`: Whether to match regardless of case.
+/// `fixed`: Whether to match `pattern` literally.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// grepl("^a", c("apple", "banana", NA))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// grepl(".", c("a.b", "ab"), fixed = true)
+/// ```
+///
+#[doc(alias = "grepl")]
+#[builtin(sym = "grepl")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveGrepl;
+
+impl Callable for PrimitiveGrepl {
+    fn formals(&self) -> ExprList {
+        pattern_formals(&["x"])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let re = pattern_arg(&mut args, stack)?;
+        let x = characters(args.try_get_named("x")?.force(stack)?, "x")?;
+
+        let result: Vec<Logical> = x
+            .iter()
+            .map(|xi| match (&re, xi) {
+                (None, _) => OptionNA::NA,
+                (Some(re), OptionNA::Some(s)) => OptionNA::Some(re.is_match(s)),
+                (Some(_), OptionNA::NA) => OptionNA::Some(false),
+            })
+            .collect();
+
+        Ok(Obj::Vector(Vector::from(result)))
+    }
+}
+
+/// Replace the first or every match of a pattern in each string
+fn substitute(args: List, stack: &mut CallStack, all: bool) -> EvalResult {
+    let mut args = Obj::List(args);
+    let re = pattern_arg(&mut args, stack)?;
+    let fixed: bool = args.try_get_named("fixed")?.force(stack)?.try_into()?;
+    let x = args.try_get_named("x")?.force(stack)?;
+    let values = characters(x.clone(), "x")?;
+    let with = match characters(
+        args.try_get_named("replacement")?.force(stack)?,
+        "replacement",
+    )?
+    .into_iter()
+    .next()
+    {
+        Some(OptionNA::Some(with)) if fixed => Some(with),
+        Some(OptionNA::Some(with)) => Some(replacement(&with)),
+        Some(OptionNA::NA) => None,
+        None => return Error::Other("invalid 'replacement' argument".into()).into(),
+    };
+
+    let result: Vec<Character> = values
+        .into_iter()
+        .map(|xi| {
+            let (Some(re), OptionNA::Some(s)) = (&re, xi) else {
+                return OptionNA::NA;
+            };
+
+            let limit = if all { 0 } else { 1 };
+            match &with {
+                _ if !re.is_match(&s) => OptionNA::Some(s),
+                None => OptionNA::NA,
+                Some(with) if fixed => {
+                    OptionNA::Some(re.replacen(&s, limit, NoExpand(with)).into_owned())
+                }
+                Some(with) => OptionNA::Some(re.replacen(&s, limit, with.as_str()).into_owned()),
+            }
+        })
+        .collect();
+
+    Ok(with_names(Vector::from(result), &x))
+}
+
+/// Replace the First Match of a Pattern
+///
+/// Replaces the first match of a regular expression in each string.
+/// Replacements may refer to capture groups as `\\1` through `\\9`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sub(pattern, replacement, x, ignore.case = false, fixed = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `pattern`: A regular expression.
+/// `replacement`: The string with which to replace a match.
+/// `x`: A vector of strings.
+/// `ignore.case`: Whether to match regardless of case.
+/// `fixed`: Whether to match `pattern` and use `replacement` literally.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sub("o", "0", "foo boo")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// sub("(\\w+) (\\w+)", "\\2 \\1", "hello world")
+/// ```
+///
+#[doc(alias = "sub")]
+#[builtin(sym = "sub")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSub;
+
+impl Callable for PrimitiveSub {
+    fn formals(&self) -> ExprList {
+        pattern_formals(&["replacement", "x"])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        substitute(args, stack, false)
+    }
+}
+
+/// Replace Every Match of a Pattern
+///
+/// Replaces every match of a regular expression in each string.
+/// Replacements may refer to capture groups as `\\1` through `\\9`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// gsub(pattern, replacement, x, ignore.case = false, fixed = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `pattern`: A regular expression.
+/// `replacement`: The string with which to replace each match.
+/// `x`: A vector of strings.
+/// `ignore.case`: Whether to match regardless of case.
+/// `fixed`: Whether to match `pattern` and use `replacement` literally.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// gsub("o", "0", "foo boo")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// gsub("(\\d+)", "<\\1>", "a1b22c333")
+/// ```
+///
+#[doc(alias = "gsub")]
+#[builtin(sym = "gsub")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveGsub;
+
+impl Callable for PrimitiveGsub {
+    fn formals(&self) -> ExprList {
+        pattern_formals(&["replacement", "x"])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        substitute(args, stack, true)
+    }
+}
+
+/// Find the First Match of a Pattern
+///
+/// Finds the character position of the first match of a regular expression
+/// in each string, or `-1` where there is none. The lengths of matches are
+/// given by the `match.length` attribute.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// regexpr(pattern, text, ignore.case = false, fixed = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `pattern`: A regular expression.
+/// `text`: A vector of strings.
+/// `ignore.case`: Whether to match regardless of case.
+/// `fixed`: Whether to match `pattern` literally.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// regexpr("[0-9]+", c("ab12c", "none", "7"))
+/// ```
+///
+#[doc(alias = "regexpr")]
+#[builtin(sym = "regexpr")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRegexpr;
+
+impl Callable for PrimitiveRegexpr {
+    fn formals(&self) -> ExprList {
+        pattern_formals(&["text"])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let re = pattern_arg(&mut args, stack)?;
+        let text = characters(args.try_get_named("text")?.force(stack)?, "text")?;
+
+        let (positions, lengths): (Vec<Integer>, Vec<Integer>) = text
+            .iter()
+            .map(|xi| match (&re, xi) {
+                (Some(re), OptionNA::Some(s)) => match re.find(s) {
+                    Some(m) => (
+                        OptionNA::Some(char_position(s, m.start())),
+                        OptionNA::Some(m.as_str().chars().count() as i32),
+                    ),
+                    None => (OptionNA::Some(-1), OptionNA::Some(-1)),
+                },
+                _ => (OptionNA::NA, OptionNA::NA),
+            })
+            .unzip();
+
+        Ok(Obj::Vector(with_match_length(positions, lengths)))
+    }
+}
+
+/// Find Every Match of a Pattern
+///
+/// Finds the character positions of every match of a regular expression in
+/// each string, producing a list of positions with the lengths of matches
+/// given by their `match.length` attribute. Strings without a match have a
+/// single position of `-1`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// gregexpr(pattern, text, ignore.case = false, fixed = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `pattern`: A regular expression.
+/// `text`: A vector of strings.
+/// `ignore.case`: Whether to match regardless of case.
+/// `fixed`: Whether to match `pattern` literally.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// gregexpr("[0-9]+", "a1b22c333")
+/// ```
+///
+#[doc(alias = "gregexpr")]
+#[builtin(sym = "gregexpr")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveGregexpr;
+
+impl Callable for PrimitiveGregexpr {
+    fn formals(&self) -> ExprList {
+        pattern_formals(&["text"])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let re = pattern_arg(&mut args, stack)?;
+        let text = characters(args.try_get_named("text")?.force(stack)?, "text")?;
+
+        let result = text
+            .iter()
+            .map(|xi| {
+                let (positions, lengths) = match (&re, xi) {
+                    (Some(re), OptionNA::Some(s)) => {
+                        let (positions, lengths): (Vec<_>, Vec<_>) = re
+                            .find_iter(s)
+                            .map(|m| {
+                                (
+                                    OptionNA::Some(char_position(s, m.start())),
+                                    OptionNA::Some(m.as_str().chars().count() as i32),
+                                )
+                            })
+                            .unzip();
+
+                        match positions.is_empty() {
+                            true => (vec![OptionNA::Some(-1)], vec![OptionNA::Some(-1)]),
+                            false => (positions, lengths),
+                        }
+                    }
+                    _ => (vec![OptionNA::NA], vec![OptionNA::NA]),
+                };
+
+                (None, Obj::Vector(with_match_length(positions, lengths)))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Obj::List(List::from(result)))
+    }
+}
+
+/// The integer values of a match, or of its `match.length` attribute
+fn match_values(m: &Obj, attr: Option<&str>) -> Result<Vec<Integer>, Signal> {
+    let values = match attr {
+        Some(name) => m.get_attr(name),
+        None => Some(m.clone()),
+    };
+
+    match values.map(|v| v.as_integer()) {
+        Some(Ok(Obj::Vector(Vector::Integer(v)))) => Ok(v.iter().collect()),
+        _ => Error::Other("'m' must be the result of 'regexpr()' or 'gregexpr()'".into()).into(),
+    }
+}
+
+/// The substrings of a string at match positions with lengths
+fn matched(x: &Character, positions: &[Integer], lengths: &[Integer]) -> Vec<Character> {
+    let OptionNA::Some(s) = x else {
+        return vec![];
+    };
+
+    positions
+        .iter()
+        .zip(lengths.iter())
+        .filter_map(|(start, length)| match (start, length) {
+            (OptionNA::Some(start), OptionNA::Some(length)) if *start > 0 => {
+                let piece = s.chars().skip(*start as usize - 1).take(*length as usize);
+                Some(OptionNA::Some(piece.collect()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Extract Matched Substrings
+///
+/// Extracts the substrings found by `regexpr()` or `gregexpr()`. Matches
+/// found by `regexpr()` produce a vector of matched substrings, omitting
+/// strings without a match, while those found by `gregexpr()` produce a
+/// list of the matched substrings of each string.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// regmatches(x, m)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: The vector of strings which was matched.
+/// `m`: The matches found by `regexpr()` or `gregexpr()`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- c("ab12c", "none", "7")
+/// regmatches(x, regexpr("[0-9]+", x))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// x <- "a1b22c333"
+/// regmatches(x, gregexpr("[0-9]+", x))
+/// ```
+///
+#[doc(alias = "regmatches")]
+#[builtin(sym = "regmatches")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRegmatches;
+
+impl Callable for PrimitiveRegmatches {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("m".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = characters(args.try_get_named("x")?.force(stack)?, "x")?;
+        let m = args.try_get_named("m")?.force(stack)?;
+
+        if m.len() != Some(x.len()) {
+            return Error::Other("'x' and 'm' must have the same length".into()).into();
+        }
+
+        match m {
+            Obj::List(l) => {
+                let result = x
+                    .iter()
+                    .zip(l.materialize().values.borrow().iter())
+                    .map(|(xi, (_, mi))| {
+                        let positions = match_values(mi, None)?;
+                        let lengths = match_values(mi, Some("match.length"))?;
+                        let pieces = matched(xi, &positions, &lengths);
+                        Ok((None, Obj::Vector(Vector::from(pieces))))
+                    })
+                    .collect::<Result<Vec<_>, Signal>>()?;
+
+                Ok(Obj::List(List::from(result)))
+            }
+            m => {
+                let positions = match_values(&m, None)?;
+                let lengths = match_values(&m, Some("match.length"))?;
+                let result: Vec<Character> = x
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, xi)| {
+                        matched(xi, &positions[i..=i], lengths.get(i..=i).unwrap_or(&[]))
+                    })
+                    .collect();
+
+                Ok(Obj::Vector(Vector::from(result)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn grepl_matches() {
+        assert_eq!(
+            r! { grepl("^a", c("apple", "banana", NA)) },
+            r! { c(true, false, false) }
+        );
+        assert_eq!(
+            r! { grepl("A", c("apple", "Avocado"), ignore.case = true) },
+            r! { c(true, true) }
+        );
+        assert_eq!(
+            r! { grepl(".", c("a.b", "ab"), fixed = true) },
+            r! { c(true, false) }
+        );
+        assert_eq!(r! { grepl(NA, c("a", "b")) }, r! { c(NA, NA) })
+    }
+
+    #[test]
+    fn grepl_character_classes() {
+        assert_eq!(
+            r! {{ r#"grepl("^\\d+$", c("123", "12a"))"# }},
+            r! { c(true, false) }
+        );
+        assert_eq!(
+            r! { grepl("[[:space:]]", c("a b", "ab")) },
+            r! { c(true, false) }
+        )
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(r! { grepl("(", "a") }.is_err())
+    }
+
+    #[test]
+    fn sub_first_match() {
+        assert_eq!(
+            r! { sub("o", "0", c("foo", NA, "bar")) },
+            r! { c("f0o", NA, "bar") }
+        )
+    }
+
+    #[test]
+    fn gsub_every_match() {
+        assert_eq!(r! { gsub("o", "0", "foo boo") }, r! { "f00 b00" });
+        assert_eq!(
+            r! { gsub("O", "0", "foo", ignore.case = true) },
+            r! { "f00" }
+        )
+    }
+
+    #[test]
+    fn substitution_backreferences() {
+        assert_eq!(
+            r! {{ r#"sub("(\\w+) (\\w+)", "\\2 \\1", "hello world")"# }},
+            r! { "world hello" }
+        );
+        assert_eq!(
+            r! {{ r#"gsub("(\\d+)", "<\\1>", "a1b22")"# }},
+            r! { "a<1>b<22>" }
+        )
+    }
+
+    #[test]
+    fn substitution_literals() {
+        assert_eq!(r! { gsub("a", "$1", "banana") }, r! { "b$1n$1n$1" });
+        assert_eq!(
+            r! {{ r#"gsub(".", "\\1", "a.b", fixed = true)"# }},
+            r! {{ r#""a\\1b""# }}
+        )
+    }
+
+    #[test]
+    fn substitution_keeps_names() {
+        assert_eq!(
+            r! { sub("a", "A", c(x = "a", y = "b")) },
+            r! { c(x = "A", y = "b") }
+        )
+    }
+
+    #[test]
+    fn regexpr_positions() {
+        assert_eq!(
+            r! { regexpr("[0-9]+", c("ab12c", "none", NA)) },
+            r! { structure(c(3L, -1L, NA), match.length = c(2L, -1L, NA)) }
+        );
+        assert_eq!(
+            r! { regexpr("b", "éb") },
+            r! { structure(2L, match.length = 1L) }
+        )
+    }
+
+    #[test]
+    fn gregexpr_positions() {
+        assert_eq!(
+            r! { gregexpr("[0-9]+", c("a1b22", "none")) },
+            r! {
+                list(
+                    structure(c(2L, 4L), match.length = c(1L, 2L)),
+                    structure(-1L, match.length = -1L)
+                )
+            }
+        )
+    }
+
+    #[test]
+    fn regmatches_extracts() {
+        assert_eq!(
+            r! { x <- c("ab12c", "none", "7"); regmatches(x, regexpr("[0-9]+", x)) },
+            r! { c("12", "7") }
+        );
+        assert_eq!(
+            r! { x <- c("a1b22", "none"); regmatches(x, gregexpr("[0-9]+", x)) },
+            r! { list(c("1", "22"), c("a")[0]) }
+        )
+    }
+}
@@ -42,10 +42,41 @@ impl Callable for PrimitivePrint {
         FORMALS.clone()
     }
 
-    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
         let mut args = Obj::List(args);
         let x = args.try_get_named("x")?.force(stack)?;
-        writeln!(stack.session.output, "{x}").ok();
-        Ok(x)
+        print_value(x, ellipsis.values.iter().collect(), stack)
+    }
+}
+
+/// Print a value, dispatching to the `print` method of its class
+///
+/// Additional arguments are passed on to the method. This is used both by
+/// `print()` and to print the values of top-level expressions.
+///
+pub fn print_value(x: Obj, args: Vec<(Option<String>, Obj)>, stack: &mut CallStack) -> EvalResult {
+    let mut dispatch_args = vec![(None, x.clone())];
+    dispatch_args.extend(args);
+    if let Some(result) = stack.dispatch("print", &x, dispatch_args) {
+        return result;
+    }
+
+    writeln!(stack.session.output, "{x}").ok();
+    Ok(x)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::r;
+
+    #[test]
+    fn dispatch_forwards_arguments() {
+        assert_eq!(
+            r! {{r#"
+                print.money <- function(x, digits = 2, ...) digits
+                print(structure(5, class = "money"), digits = 3)
+            "#}},
+            r! { 3 }
+        )
     }
 }
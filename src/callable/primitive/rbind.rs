@@ -0,0 +1,162 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+use super::c::combine;
+use super::cbind::bind;
+
+/// Combine Objects by Rows
+///
+/// Combines vectors and matrices as the rows of a matrix, recycling
+/// vectors to the number of columns. When any argument is a data frame,
+/// the rows of each argument are appended to those of the first data
+/// frame instead, matching columns by name.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// rbind(...)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Vectors, matrices or data frames to combine.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// rbind(1:3, 4:6)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// rbind(data.frame(x = 1, y = "a"), data.frame(y = "b", x = 2))
+/// ```
+///
+#[doc(alias = "rbind")]
+#[builtin(sym = "rbind")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRbind;
+
+impl Callable for PrimitiveRbind {
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let Obj::List(vals) = stack.eval_list_eager(args)? else {
+            unreachable!()
+        };
+
+        let vals: Vec<Obj> = vals.values.iter().map(|(_, v)| v).collect();
+        let first = vals.iter().find_map(|v| match v {
+            Obj::List(l) if l.is_data_frame() => Some(l.clone()),
+            _ => None,
+        });
+
+        let Some(first) = first else {
+            return bind(vals, true, stack);
+        };
+
+        let names: Vec<String> = first.columns().into_iter().map(|(k, _)| k).collect();
+        let mismatched = || Error::Other("numbers of columns of arguments do not match".into());
+
+        // gather the pieces of each column, in the order of the first data frame
+        let mut pieces: Vec<Vec<Obj>> = vec![vec![]; names.len()];
+        for value in vals {
+            match value {
+                Obj::List(l) if l.is_data_frame() => {
+                    let columns = l.columns();
+                    if columns.len() != names.len() {
+                        return mismatched().into();
+                    }
+
+                    for (name, piece) in names.iter().zip(pieces.iter_mut()) {
+                        let Some((_, v)) = columns.iter().find(|(k, _)| k == name) else {
+                            return Error::Other("names do not match previous names".into()).into();
+                        };
+                        piece.push(Obj::Vector(v.clone()));
+                    }
+                }
+                Obj::Vector(v) => {
+                    if v.len() != names.len() {
+                        return mismatched().into();
+                    }
+
+                    for (j, piece) in pieces.iter_mut().enumerate() {
+                        piece.push(Obj::Vector(v.subset(Subset::Range(j..j + 1)).materialize()));
+                    }
+                }
+                Obj::Null => (),
+                _ => return Error::Other("cannot bind objects of this type".into()).into(),
+            }
+        }
+
+        let mut columns: Vec<(String, Vector)> = vec![];
+        for (name, piece) in names.into_iter().zip(pieces) {
+            let Obj::Vector(v) = combine(piece, stack)? else {
+                return Error::Other("cannot bind objects of this type".into()).into();
+            };
+
+            v.attributes().set("names", Obj::Null);
+            columns.push((name, v));
+        }
+
+        Ok(Obj::List(List::data_frame(columns)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn vectors_as_rows() {
+        r_expect! {{r#"
+            m <- rbind(1:3, 4:6)
+            nrow(m) == 2 && ncol(m) == 3 && m[2, 1] == 4 && m[1, 3] == 3
+        "#}}
+    }
+
+    #[test]
+    fn matrices_and_vectors() {
+        r_expect! {{r#"
+            m <- rbind(matrix(1:4, nrow = 2), 5:6)
+            nrow(m) == 3 && ncol(m) == 2 && m[3, 1] == 5 && m[1, 2] == 3
+        "#}}
+    }
+
+    #[test]
+    fn mismatched_columns() {
+        assert_eq!(
+            r! { rbind(matrix(1:4, nrow = 2), matrix(1:6, nrow = 2)) },
+            Error::Other("number of columns of matrices must match (see arg 2)".into()).into()
+        )
+    }
+
+    #[test]
+    fn data_frames_matched_by_name() {
+        assert_eq!(
+            r! { rbind(data.frame(x = 1, y = "a"), data.frame(y = "b", x = 2))$y },
+            r! { c("a", "b") }
+        )
+    }
+
+    #[test]
+    fn data_frame_and_vector() {
+        assert_eq!(
+            r! { rbind(data.frame(x = 1:2, y = c(3, 4)), c(5, 6))$y },
+            r! { c(3, 4, 6) }
+        )
+    }
+
+    #[test]
+    fn data_frames_with_different_columns() {
+        assert_eq!(
+            r! { rbind(data.frame(x = 1), data.frame(x = 1, y = 2)) },
+            Error::Other("numbers of columns of arguments do not match".into()).into()
+        )
+    }
+}
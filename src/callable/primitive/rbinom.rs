@@ -0,0 +1,138 @@
+use r_derive::builtin;
+use rand_distr::{Binomial, Distribution};
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+use crate::rng::{draw, SessionRng};
+
+/// Binomially Distributed Random Number Generation
+///
+/// Create a vector of binomially distributed random integers. When the
+/// expected number of the less likely outcome is below 30, values are
+/// drawn by inversion and match those of GNU R for the same seed.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// rbinom(n = 1, size, prob)
+/// ```
+///
+/// ## Arguments
+///
+/// * `n`: The number of values to generate
+/// * `size`,`prob`: The number of trials and the probability of success of
+///   each trial, recycled over the values generated.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// rbinom(5, size = 10, prob = 0.5)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// rbinom(3, size = 1, prob = c(0.1, 0.5, 0.9))
+/// ```
+///
+#[doc(alias = "rbinom")]
+#[builtin(sym = "rbinom")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRbinom;
+impl Callable for PrimitiveRbinom {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some(String::from("n")), Expr::Number(1.0)),
+            (Some(String::from("size")), Expr::Missing),
+            (Some(String::from("prob")), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let n: i32 = args.try_get_named("n")?.force(stack)?.try_into()?;
+        let n = usize::try_from(n).map_err(|_| Error::Other("invalid arguments".into()))?;
+        let size: Vec<f64> = args.try_get_named("size")?.force(stack)?.try_into()?;
+        let prob: Vec<f64> = args.try_get_named("prob")?.force(stack)?.try_into()?;
+
+        let rng = stack.session.rng.clone();
+        let (any_na, values) = draw(n, &[size, prob], |p| match (p[0], p[1]) {
+            (size, _) if !size.is_finite() || size < 0.0 || size.fract() != 0.0 => None,
+            (_, prob) if !(0.0..=1.0).contains(&prob) => None,
+            (size, prob) if size == 0.0 || prob == 0.0 => Some(0.0),
+            (size, 1.0) => Some(size),
+            (size, prob) => Some(rbinom(size, prob, &rng)),
+        });
+
+        if any_na {
+            stack.warn("NAs produced".to_string())?;
+        }
+
+        Ok(Obj::Vector(Vector::from(values).as_integer()))
+    }
+}
+
+/// Draw a binomially distributed value
+///
+/// Values are drawn for the less likely outcome, by inversion when its
+/// expected number is small.
+///
+fn rbinom(size: f64, prob: f64, rng: &SessionRng) -> f64 {
+    let p = prob.min(1.0 - prob);
+    let q = 1.0 - p;
+
+    let ix = if size * p < 30.0 {
+        let qn = q.powi(size as i32);
+        let r = p / q;
+        let g = r * (size + 1.0);
+
+        'draw: loop {
+            let mut ix = 0.0;
+            let mut f = qn;
+            let mut u = rng.unif_rand();
+            while ix <= 110.0 {
+                if u < f {
+                    break 'draw ix;
+                }
+
+                u -= f;
+                ix += 1.0;
+                f *= g / ix - r;
+            }
+        }
+    } else {
+        match Binomial::new(size as u64, p) {
+            Ok(d) => rng.with_rng(|r| d.sample(r)) as f64,
+            Err(_) => return f64::NAN,
+        }
+    };
+
+    if prob > 0.5 {
+        size - ix
+    } else {
+        ix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn matches_r() {
+        assert_eq!(
+            r! {{"
+                set.seed(1)
+                rbinom(5, 10, 0.5)
+            "}},
+            r! { c(4L, 4L, 5L, 7L, 4L) }
+        )
+    }
+
+    #[test]
+    fn certain_outcomes() {
+        assert_eq!(r! { rbinom(3, 5, c(0, 1)) }, r! { c(0L, 5L, 0L) })
+    }
+}
@@ -0,0 +1,305 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::{Character, Logical};
+use crate::object::*;
+
+/// Read a CSV File
+///
+/// Reads a file of comma-separated values into a data frame. The type of
+/// each column is inferred from its values, preferring `logical`, then
+/// `integer`, then `double` and finally `character` columns.
+///
+/// Fields may be quoted with `"`, in which case they can contain separators,
+/// line breaks and doubled (`""`) quotes. Empty fields are read as `NA` in
+/// all but `character` columns.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// read.csv(file, header = true, sep = ",", na.strings = "NA", colClasses = NA)
+/// ```
+///
+/// ## Arguments
+///
+/// `file`: The path of the file to read.
+/// `header`: Whether the first line contains the names of the columns. When
+///   `false`, columns are named `V1`, `V2`, and so on.
+/// `sep`: The character separating fields.
+/// `na.strings`: Values to read as `NA`.
+/// `colClasses`: The classes of the columns, recycled across columns. Any
+///   of `"logical"`, `"integer"`, `"numeric"` or `"character"`. Columns
+///   with an `NA` class are inferred from their values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// df <- read.csv("data.csv")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// df <- read.csv("data.tsv", sep = "\t", colClasses = c("character", NA))
+/// ```
+///
+#[doc(alias = "read.csv")]
+#[builtin(sym = "read.csv")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveReadCsv;
+
+impl Callable for PrimitiveReadCsv {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("file".to_string()), Expr::Missing),
+            (Some("header".to_string()), Expr::Bool(true)),
+            (Some("sep".to_string()), Expr::String(",".to_string())),
+            (
+                Some("na.strings".to_string()),
+                Expr::String("NA".to_string()),
+            ),
+            (Some("colClasses".to_string()), Expr::NA),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let file = match args.try_get_named("file")?.force(stack)? {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => String::from(v),
+            _ => return Error::Other("'file' must be a character string".into()).into(),
+        };
+
+        let header: bool = args.try_get_named("header")?.force(stack)?.try_into()?;
+
+        let sep = match args.try_get_named("sep")?.force(stack)? {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => String::from(v),
+            _ => return Error::Other("'sep' must be a character string".into()).into(),
+        };
+
+        let mut chars = sep.chars();
+        let (Some(sep), None) = (chars.next(), chars.next()) else {
+            return Error::Other("invalid 'sep' value: must be one character".into()).into();
+        };
+
+        let na_strings: Vec<String> = match args.try_get_named("na.strings")?.force(stack)? {
+            Obj::Vector(v) => v.into(),
+            _ => vec![],
+        };
+
+        let col_classes: Vec<Option<String>> =
+            match args.try_get_named("colClasses")?.force(stack)? {
+                Obj::Vector(Vector::Character(v)) => v
+                    .inner()
+                    .borrow()
+                    .iter()
+                    .map(|class| match class {
+                        OptionNA::Some(class) => Some(class.clone()),
+                        OptionNA::NA => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+
+        let text = std::fs::read_to_string(&file)
+            .map_err(|e| Error::Other(format!("cannot open file '{file}': {e}")))?;
+
+        let mut records = parse_records(&text, sep)?;
+        let ncol = records.first().map_or(0, |r| r.len());
+        let names = match header && !records.is_empty() {
+            true => records.remove(0),
+            false => (1..=ncol).map(|j| format!("V{j}")).collect(),
+        };
+
+        // the header line is line 1 when present
+        let skip = if header { 2 } else { 1 };
+        if let Some(i) = records.iter().position(|r| r.len() != ncol) {
+            let msg = format!("line {} did not have {ncol} elements", i + skip);
+            return Error::Other(msg).into();
+        }
+
+        let mut columns = vec![];
+        for (j, name) in names.into_iter().enumerate() {
+            let values: Vec<Character> = records
+                .iter()
+                .map(|r| match na_strings.contains(&r[j]) {
+                    true => OptionNA::NA,
+                    false => OptionNA::Some(r[j].clone()),
+                })
+                .collect();
+
+            let class = match col_classes.len() {
+                0 => None,
+                n => col_classes[j % n].as_deref(),
+            };
+
+            let column = parse_column(values, class).ok_or_else(|| {
+                let class = class.unwrap_or_default();
+                Error::Other(format!("column '{name}' cannot be read as '{class}'"))
+            })?;
+
+            columns.push((name, column));
+        }
+
+        Ok(Obj::List(List::data_frame(columns)?))
+    }
+}
+
+/// Split text into records of fields
+///
+/// Blank lines are skipped. Quoted fields may contain separators, line
+/// breaks and doubled (`""`) quotes.
+///
+fn parse_records(text: &str, sep: char) -> Result<Vec<Vec<String>>, Signal> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            c if quoted => field.push(c),
+            c if c == sep => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => (),
+            '\n' => {
+                if !record.is_empty() || !field.is_empty() {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+            }
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Error::Other("unterminated quoted field".into()).into();
+    }
+
+    if !record.is_empty() || !field.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Parse the values of a column as a given class, or infer its class
+///
+/// Returns `None` when values cannot be read as the requested class.
+///
+fn parse_column(values: Vec<Character>, class: Option<&str>) -> Option<Vector> {
+    // empty fields are missing from all but character columns
+    let blank_as_na: Vec<Character> = values
+        .iter()
+        .map(|x| match x {
+            OptionNA::Some(x) if x.is_empty() => OptionNA::NA,
+            x => x.clone(),
+        })
+        .collect();
+
+    let as_logical = || parse_logical(&blank_as_na).map(Vector::from);
+    let as_integer = || match Vector::vec_parse::<i32>(&blank_as_na) {
+        (false, v) => Some(Vector::from(v)),
+        (true, _) => None,
+    };
+    let as_double = || match Vector::vec_parse::<f64>(&blank_as_na) {
+        (false, v) => Some(Vector::from(v)),
+        (true, _) => None,
+    };
+
+    match class {
+        None => as_logical()
+            .or_else(as_integer)
+            .or_else(as_double)
+            .or_else(|| Some(Vector::from(values))),
+        Some("logical") => as_logical(),
+        Some("integer") => as_integer(),
+        Some("numeric" | "double") => as_double(),
+        Some("character") => Some(Vector::from(values)),
+        Some(_) => None,
+    }
+}
+
+/// Parse logical values, accepting `TRUE`/`FALSE` in upper or lower case
+fn parse_logical(values: &[Character]) -> Option<Vec<Logical>> {
+    values
+        .iter()
+        .map(|x| match x {
+            OptionNA::Some(x) => match x.as_str() {
+                "TRUE" | "true" => Some(OptionNA::Some(true)),
+                "FALSE" | "false" => Some(OptionNA::Some(false)),
+                _ => None,
+            },
+            OptionNA::NA => Some(OptionNA::NA),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::lang::{CallStack, EvalResult};
+    use crate::r;
+
+    fn read(name: &str, contents: &str, args: &str) -> EvalResult {
+        let path = std::env::temp_dir().join(format!("r-read-csv-{name}.csv"));
+        std::fs::write(&path, contents).unwrap();
+        let expr = format!("read.csv({:?}{args})", path.display().to_string());
+        CallStack::default().parse_and_eval(&expr)
+    }
+
+    #[test]
+    fn infers_column_types() {
+        let mut df = read("types", "a,b,c,d\n1,1.5,TRUE,x\n2,NA,FALSE,y\n", "").unwrap();
+        assert_eq!(df.try_get_named("a"), r! { c(1L, 2L) });
+        assert_eq!(df.try_get_named("b"), r! { c(1.5, NA) });
+        assert_eq!(df.try_get_named("c"), r! { c(true, false) });
+        assert_eq!(df.try_get_named("d"), r! { c("x", "y") });
+    }
+
+    #[test]
+    fn quoted_fields() {
+        let mut df = read("quoted", "a,b\n\"x, \"\"y\"\"\",1\n", "").unwrap();
+        assert_eq!(df.try_get_named("a"), r! {{ r#"c('x, "y"')"# }});
+    }
+
+    #[test]
+    fn without_header() {
+        let mut df = read("no-header", "1;2\n3;4\n", ", header = false, sep = \";\"").unwrap();
+        assert_eq!(df.try_get_named("V2"), r! { c(2L, 4L) });
+    }
+
+    #[test]
+    fn na_strings() {
+        let mut df = read("na", "a,b\n-,\n1,x\n", ", na.strings = \"-\"").unwrap();
+        assert_eq!(df.try_get_named("a"), r! { c(NA, 1L) });
+        assert_eq!(df.try_get_named("b"), r! { c("", "x") });
+    }
+
+    #[test]
+    fn column_classes() {
+        let mut df = read(
+            "classes",
+            "a,b\n1,2\n",
+            ", colClasses = c(\"character\", NA)",
+        )
+        .unwrap();
+        assert_eq!(df.try_get_named("a"), r! { c("1") });
+        assert_eq!(df.try_get_named("b"), r! { c(2L) });
+    }
+
+    #[test]
+    fn ragged_lines() {
+        assert_eq!(
+            read("ragged", "a,b\n1,2\n3\n", ""),
+            Error::Other("line 3 did not have 2 elements".into()).into()
+        )
+    }
+}
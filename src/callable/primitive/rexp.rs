@@ -0,0 +1,87 @@
+use r_derive::builtin;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+use crate::rng::draw;
+
+/// Exponentially Distributed Random Number Generation
+///
+/// Create a vector of exponentially distributed random numbers.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// rexp(n = 1, rate = 1)
+/// ```
+///
+/// ## Arguments
+///
+/// * `n`: The number of values to generate
+/// * `rate`: The rate of the exponential distribution, recycled over the
+///   values generated.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// rexp(3)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// rexp(3, rate = c(1, 10, 100))
+/// ```
+///
+#[doc(alias = "rexp")]
+#[builtin(sym = "rexp")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRexp;
+impl Callable for PrimitiveRexp {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some(String::from("n")), Expr::Number(1.0)),
+            (Some(String::from("rate")), Expr::Number(1.0)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let n: i32 = args.try_get_named("n")?.force(stack)?.try_into()?;
+        let n = usize::try_from(n).map_err(|_| Error::Other("invalid arguments".into()))?;
+        let rate: Vec<f64> = args.try_get_named("rate")?.force(stack)?.try_into()?;
+
+        let rng = stack.session.rng.clone();
+        let (any_na, values) = draw(n, &[rate], |p| match 1.0 / p[0] {
+            0.0 => Some(0.0),
+            scale if !scale.is_finite() || scale < 0.0 => None,
+            scale => Some(scale * rng.exp_rand()),
+        });
+
+        if any_na {
+            stack.warn("NAs produced".to_string())?;
+        }
+
+        Ok(Obj::Vector(Vector::from(values)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{r, r_expect};
+
+    #[test]
+    fn matches_r() {
+        r_expect! {{"
+            set.seed(1)
+            x <- rexp(2)
+            x[1] > 0.7551817 && x[1] < 0.7551819 && x[2] > 1.1816427 && x[2] < 1.1816429
+        "}}
+    }
+
+    #[test]
+    fn invalid_rate() {
+        assert_eq!(r! { rexp(1, rate = -1) }, r! { c(NA, 1)[1] })
+    }
+}
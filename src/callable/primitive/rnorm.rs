@@ -1,10 +1,10 @@
 use r_derive::builtin;
-use rand_distr::{Distribution, Normal};
 
 use crate::callable::core::*;
 use crate::error::Error;
 use crate::lang::*;
 use crate::object::*;
+use crate::rng::draw;
 
 /// Normally Distributed Random Number Generation
 ///
@@ -50,49 +50,24 @@ impl Callable for PrimitiveRnorm {
         ])
     }
 
-    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
-        use Error::ArgumentInvalid;
-        let (vals, _) = self.match_arg_exprs(args, stack)?;
-        let vals = force_promises(vals, stack)?;
-        let mut vals = Obj::List(List::from(vals));
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let n: i32 = args.try_get_named("n")?.force(stack)?.try_into()?;
+        let n = usize::try_from(n).map_err(|_| Error::Other("invalid arguments".into()))?;
+        let mean: Vec<f64> = args.try_get_named("mean")?.force(stack)?.try_into()?;
+        let std: Vec<f64> = args.try_get_named("std")?.force(stack)?.try_into()?;
 
-        let n: i32 = vals.try_get_named("n")?.try_into()?;
-        let mean: Vec<f64> = vals.try_get_named("mean")?.try_into()?;
-        let std: Vec<f64> = vals.try_get_named("std")?.try_into()?;
-        let len = std::cmp::max(mean.len(), std.len());
-        let mut rng = rand::thread_rng();
+        let rng = stack.session.rng.clone();
+        let (any_na, values) = draw(n, &[mean, std], |p| match (p[0], p[1]) {
+            (mean, std) if mean.is_nan() || !std.is_finite() || std < 0.0 => None,
+            (mean, std) if std == 0.0 || !mean.is_finite() => Some(mean),
+            (mean, std) => Some(mean + std * rng.norm_rand()),
+        });
 
-        // TODO: perhaps these branches can be unified by creating a
-        // run-length-encoding of the iterator?
-
-        // special case when both min and max are length 1, sampling
-        // all random numbers at once from the same distribution
-        if len == 1 {
-            let mean = mean
-                .first()
-                .map_or(ArgumentInvalid(String::from("mean")).into(), Ok)?;
-            let std = std
-                .first()
-                .map_or(ArgumentInvalid(String::from("std")).into(), Ok)?;
-
-            let normal = Normal::new(*mean, *std).unwrap();
-
-            Ok(Obj::Vector(Vector::from(
-                (1..=n)
-                    .map(|_| normal.sample(&mut rng))
-                    .collect::<Vec<f64>>(),
-            )))
-
-        // otherwise we need to zip through mins and maxes to get distributions
-        } else {
-            Ok(Obj::Vector(Vector::from(
-                mean.into_iter()
-                    .cycle()
-                    .zip(std.into_iter().cycle())
-                    .take(len)
-                    .map(|(mean, std)| Normal::new(mean, std).unwrap().sample(&mut rng))
-                    .collect::<Vec<f64>>(),
-            )))
+        if any_na {
+            stack.warn("NAs produced".to_string())?;
         }
+
+        Ok(Obj::Vector(Vector::from(values)))
     }
 }
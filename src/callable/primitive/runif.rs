@@ -1,11 +1,10 @@
 use r_derive::builtin;
-use rand::distributions::{Distribution, Uniform};
-use rand::Rng;
 
 use crate::callable::core::*;
 use crate::error::Error;
 use crate::lang::*;
 use crate::object::*;
+use crate::rng::draw;
 
 /// Uniform Random Number Generation
 ///
@@ -51,49 +50,24 @@ impl Callable for PrimitiveRunif {
         ])
     }
 
-    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
-        use Error::ArgumentInvalid;
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let n: i32 = args.try_get_named("n")?.force(stack)?.try_into()?;
+        let n = usize::try_from(n).map_err(|_| Error::Other("invalid arguments".into()))?;
+        let min: Vec<f64> = args.try_get_named("min")?.force(stack)?.try_into()?;
+        let max: Vec<f64> = args.try_get_named("max")?.force(stack)?.try_into()?;
 
-        let (vals, _) = self.match_arg_exprs(args, stack)?;
-        let vals = force_promises(vals, stack)?;
-        let mut vals = Obj::List(List::from(vals));
+        let rng = stack.session.rng.clone();
+        let (any_na, values) = draw(n, &[min, max], |p| match (p[0], p[1]) {
+            (a, b) if !a.is_finite() || !b.is_finite() || b < a => None,
+            (a, b) if a == b => Some(a),
+            (a, b) => Some(a + (b - a) * rng.unif_rand()),
+        });
 
-        let n: i32 = vals.try_get_named("n")?.try_into()?;
-        let min: Vec<f64> = vals.try_get_named("min")?.try_into()?;
-        let max: Vec<f64> = vals.try_get_named("max")?.try_into()?;
-        let len = std::cmp::max(min.len(), max.len());
-        let mut rng = rand::thread_rng();
-
-        // TODO: perhaps these branches can be unified by creating a
-        // run-length-encoding of the iterator?
-
-        // special case when both min and max are length 1, sampling
-        // all random numbers at once from the same distribution
-        if len == 1 {
-            let min = min
-                .first()
-                .map_or(ArgumentInvalid(String::from("min")).into(), Ok)?;
-            let max = max
-                .first()
-                .map_or(ArgumentInvalid(String::from("max")).into(), Ok)?;
-            let between = Uniform::from(*min..=*max);
-
-            Ok(Obj::Vector(Vector::from(
-                (1..=n)
-                    .map(|_| between.sample(&mut rng))
-                    .collect::<Vec<f64>>(),
-            )))
-
-        // otherwise we need to zip through mins and maxes to get distributions
-        } else {
-            Ok(Obj::Vector(Vector::from(
-                min.into_iter()
-                    .cycle()
-                    .zip(max.into_iter().cycle())
-                    .take(len)
-                    .map(|(min, max)| rng.gen_range(min..=max))
-                    .collect::<Vec<f64>>(),
-            )))
+        if any_na {
+            stack.warn("NAs produced".to_string())?;
         }
+
+        Ok(Obj::Vector(Vector::from(values)))
     }
 }
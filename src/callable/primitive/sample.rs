@@ -0,0 +1,300 @@
+use r_derive::builtin;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+use crate::rng::SessionRng;
+
+/// Random Samples
+///
+/// Draw a random sample of the elements of a vector, with or without
+/// replacement. Samples are drawn from the session's random number
+/// generator, such that they match those of GNU R for the same seed.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sample(x, size = NULL, replace = false, prob = NULL)
+/// ```
+///
+/// ## Arguments
+///
+/// * `x`: The vector from which to sample elements. When a single number
+///   of at least `1`, elements are sampled from `1:x`.
+/// * `size`: The number of elements to sample, by default the number of
+///   elements of `x`.
+/// * `replace`: Whether elements may be sampled more than once.
+/// * `prob`: Weights with which to sample each element.
+///
+/// ## Examples
+///
+/// Shuffle a vector:
+///
+/// ```custom,{class=r-repl}
+/// sample(10)
+/// ```
+///
+/// Sample with replacement, preferring some elements:
+///
+/// ```custom,{class=r-repl}
+/// sample(c("a", "b", "c"), 5, replace = true, prob = c(0.6, 0.3, 0.1))
+/// ```
+///
+#[doc(alias = "sample")]
+#[builtin(sym = "sample")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSample;
+impl Callable for PrimitiveSample {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some(String::from("x")), Expr::Missing),
+            (Some(String::from("size")), Expr::Null),
+            (Some(String::from("replace")), Expr::Bool(false)),
+            (Some(String::from("prob")), Expr::Null),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+
+        // a single number is shorthand for sampling from 1:x
+        let population = match &x {
+            Obj::Vector(v @ (Vector::Double(_) | Vector::Integer(_))) if v.len() == 1 => {
+                let n: Vec<f64> = x.clone().try_into()?;
+                match n[0] {
+                    n if n >= 1.0 => Some(n.floor() as usize),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        let n = match population {
+            Some(n) => n,
+            None => x.len().unwrap_or(0),
+        };
+
+        let size = match args.try_get_named("size")?.force(stack)? {
+            Obj::Null => n,
+            size => {
+                let size: i32 = size.try_into()?;
+                usize::try_from(size).map_err(|_| Error::Other("invalid 'size' argument".into()))?
+            }
+        };
+
+        let replace: bool = args.try_get_named("replace")?.force(stack)?.try_into()?;
+        if !replace && size > n {
+            let msg = "cannot take a sample larger than the population when 'replace = FALSE'";
+            return Error::Other(msg.into()).into();
+        }
+
+        let rng = &stack.session.rng.clone();
+        let indices = match args.try_get_named("prob")?.force(stack)? {
+            Obj::Null if replace || size < 2 => (0..size).map(|_| rng.unif_index(n)).collect(),
+            Obj::Null => sample_without_replacement(n, size, rng),
+            prob => {
+                let prob: Vec<f64> = prob.try_into()?;
+                if prob.len() != n {
+                    return Error::Other("incorrect number of probabilities".into()).into();
+                }
+
+                let prob = normalize_probabilities(prob, size, replace)?;
+                match replace {
+                    true => prob_sample_replace(prob, size, rng),
+                    false => prob_sample_without_replacement(prob, size, rng),
+                }
+            }
+        };
+
+        let indices: Vec<i32> = indices.into_iter().map(|i| i as i32 + 1).collect();
+        match population {
+            Some(_) => Ok(Obj::Vector(Vector::from(indices))),
+            None => x.try_get(Obj::Vector(Vector::from(indices))),
+        }
+    }
+}
+
+/// Sample indices uniformly, removing each from the population once drawn
+fn sample_without_replacement(n: usize, size: usize, rng: &SessionRng) -> Vec<usize> {
+    let mut population: Vec<usize> = (0..n).collect();
+    let mut n = n;
+    (0..size)
+        .map(|_| {
+            let j = rng.unif_index(n);
+            let index = population[j];
+            n -= 1;
+            population[j] = population[n];
+            index
+        })
+        .collect()
+}
+
+/// Validate sampling weights and scale them to sum to one
+fn normalize_probabilities(prob: Vec<f64>, size: usize, replace: bool) -> Result<Vec<f64>, Signal> {
+    if prob.iter().any(|p| !p.is_finite()) {
+        return Error::Other("NA in probability vector".into()).into();
+    }
+
+    if prob.iter().any(|p| *p < 0.0) {
+        return Error::Other("negative probability".into()).into();
+    }
+
+    let npos = prob.iter().filter(|p| **p > 0.0).count();
+    if npos == 0 || (!replace && size > npos) {
+        return Error::Other("too few positive probabilities".into()).into();
+    }
+
+    let total: f64 = prob.iter().sum();
+    Ok(prob.into_iter().map(|p| p / total).collect())
+}
+
+/// Sort probabilities into descending order, alongside their indices
+///
+/// A heap sort, ordering ties as GNU R does so that samples are
+/// reproduced for the same seed.
+///
+fn revsort(prob: Vec<f64>) -> (Vec<f64>, Vec<usize>) {
+    let n = prob.len();
+
+    // one-based, as the heap is indexed from 1
+    let mut a: Vec<f64> = std::iter::once(0.0).chain(prob).collect();
+    let mut ib: Vec<usize> = (0..=n).map(|i| i.saturating_sub(1)).collect();
+
+    if n > 1 {
+        let mut l = (n >> 1) + 1;
+        let mut ir = n;
+        loop {
+            let (ra, ii);
+            if l > 1 {
+                l -= 1;
+                ra = a[l];
+                ii = ib[l];
+            } else {
+                ra = a[ir];
+                ii = ib[ir];
+                a[ir] = a[1];
+                ib[ir] = ib[1];
+                ir -= 1;
+                if ir == 1 {
+                    a[1] = ra;
+                    ib[1] = ii;
+                    break;
+                }
+            }
+
+            let mut i = l;
+            let mut j = l << 1;
+            while j <= ir {
+                if j < ir && a[j] > a[j + 1] {
+                    j += 1;
+                }
+
+                if ra > a[j] {
+                    a[i] = a[j];
+                    ib[i] = ib[j];
+                    i = j;
+                    j += j;
+                } else {
+                    j = ir + 1;
+                }
+            }
+
+            a[i] = ra;
+            ib[i] = ii;
+        }
+    }
+
+    (a[1..].to_vec(), ib[1..].to_vec())
+}
+
+/// Sample indices with replacement, weighted by probabilities
+fn prob_sample_replace(prob: Vec<f64>, size: usize, rng: &SessionRng) -> Vec<usize> {
+    let (mut p, perm) = revsort(prob);
+    for i in 1..p.len() {
+        p[i] += p[i - 1];
+    }
+
+    (0..size)
+        .map(|_| {
+            let u = rng.unif_rand();
+            let j = p[..p.len() - 1].iter().position(|&p| u <= p);
+            perm[j.unwrap_or(p.len() - 1)]
+        })
+        .collect()
+}
+
+/// Sample indices without replacement, weighted by probabilities
+fn prob_sample_without_replacement(prob: Vec<f64>, size: usize, rng: &SessionRng) -> Vec<usize> {
+    let (mut p, mut perm) = revsort(prob);
+    let mut total = 1.0;
+
+    (0..size)
+        .map(|_| {
+            let u = total * rng.unif_rand();
+            let last = p.len() - 1;
+
+            let mut mass = 0.0;
+            let mut j = 0;
+            while j < last {
+                mass += p[j];
+                if u <= mass {
+                    break;
+                }
+                j += 1;
+            }
+
+            total -= p.remove(j);
+            perm.remove(j)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn matches_r() {
+        assert_eq!(
+            r! {{"
+                set.seed(1)
+                sample(10)
+            "}},
+            r! { c(9L, 4L, 7L, 1L, 2L, 5L, 3L, 10L, 6L, 8L) }
+        )
+    }
+
+    #[test]
+    fn samples_elements() {
+        r_expect! {{r#"
+            x <- sample(c("a", "b", "c"), 10, replace = true)
+            length(x) == 10 && length(x[x != "a" & x != "b" & x != "c"]) == 0
+        "#}}
+    }
+
+    #[test]
+    fn weighted_samples() {
+        assert_eq!(
+            r! { sample(c(10, 20, 30), 5, replace = true, prob = c(0, 1, 0)) },
+            r! { c(20, 20, 20, 20, 20) }
+        );
+
+        assert_eq!(r! { sample(3, prob = c(0, 1, 0), size = 1) }, r! { 2L });
+    }
+
+    #[test]
+    fn larger_than_population() {
+        assert_eq!(
+            r! { sample(3, 4) },
+            Error::Other(
+                "cannot take a sample larger than the population when 'replace = FALSE'".into()
+            )
+            .into()
+        )
+    }
+}
@@ -0,0 +1,123 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+use crate::rng::RngKind;
+
+/// Set the Random Number Generator Seed
+///
+/// Seeds the session's random number generator, such that subsequent
+/// random values are reproducible. The `"Mersenne-Twister"` generator
+/// produces the same uniform values as GNU R for the same seed.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// set.seed(seed, kind = NULL)
+/// ```
+///
+/// ## Arguments
+///
+/// `seed`: An integer seed.
+/// `kind`: The kind of generator to use, either `"Mersenne-Twister"` or
+///   `"ChaCha"`. When `NULL`, the current kind of generator is kept.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// set.seed(1)
+/// runif(3)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// set.seed(42, kind = "ChaCha")
+/// rnorm(3)
+/// ```
+///
+#[doc(alias = "set.seed")]
+#[builtin(sym = "set.seed")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSetSeed;
+
+impl Callable for PrimitiveSetSeed {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("seed".to_string()), Expr::Missing),
+            (Some("kind".to_string()), Expr::Null),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let seed: i32 = args.try_get_named("seed")?.force(stack)?.try_into()?;
+
+        let kind = match args.try_get_named("kind")?.force(stack)? {
+            Obj::Null => None,
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => {
+                let kind = String::from(v);
+                match kind.parse::<RngKind>() {
+                    Ok(kind) => Some(kind),
+                    Err(_) => {
+                        let msg = format!("'{kind}' is not a valid generator kind");
+                        return Error::Other(msg).into();
+                    }
+                }
+            }
+            _ => return Error::Other("'kind' must be a character string".into()).into(),
+        };
+
+        stack.session.rng.set_seed(seed, kind);
+        Ok(Obj::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn reproducible() {
+        assert_eq!(
+            r! {{"
+                set.seed(10)
+                runif(5)
+            "}},
+            r! {{"
+                set.seed(10)
+                runif(5)
+            "}}
+        )
+    }
+
+    #[test]
+    fn matches_r() {
+        r_expect! {{"
+            set.seed(1)
+            x <- runif(3)
+            x[1] > 0.2655086 && x[1] < 0.2655088 && x[3] > 0.5728533 && x[3] < 0.5728535
+        "}}
+    }
+
+    #[test]
+    fn generator_kinds() {
+        r_expect! {{r#"
+            set.seed(1, kind = "ChaCha")
+            x <- runif(1)
+            set.seed(1, kind = "Mersenne-Twister")
+            x != runif(1)
+        "#}}
+    }
+
+    #[test]
+    fn invalid_kind() {
+        assert_eq!(
+            r! { set.seed(1, kind = "Unknown") },
+            Error::Other("'Unknown' is not a valid generator kind".into()).into()
+        )
+    }
+}
@@ -0,0 +1,880 @@
+use hashbrown::HashMap;
+use r_derive::*;
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// Values of atomic vectors, which can be ordered and matched
+trait Element: PartialOrd + Clone {
+    type Key: Hash + Eq;
+
+    /// Whether a value is ordered as though it were missing
+    fn is_nan(&self) -> bool {
+        false
+    }
+
+    /// A key, equal for values which match one another
+    fn key(&self) -> Self::Key;
+}
+
+impl Element for bool {
+    type Key = bool;
+    fn key(&self) -> bool {
+        *self
+    }
+}
+
+impl Element for i32 {
+    type Key = i32;
+    fn key(&self) -> i32 {
+        *self
+    }
+}
+
+impl Element for f64 {
+    type Key = u64;
+
+    fn is_nan(&self) -> bool {
+        f64::is_nan(*self)
+    }
+
+    fn key(&self) -> u64 {
+        // all `NaN`s match one another, as do `0` and `-0`
+        match *self {
+            x if x.is_nan() => f64::NAN.to_bits(),
+            0.0 => 0,
+            x => x.to_bits(),
+        }
+    }
+}
+
+impl Element for String {
+    type Key = String;
+    fn key(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Where missing values are placed when ordering
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NaLast {
+    First,
+    Last,
+    Remove,
+    Keep,
+}
+
+/// Parse an `na.last` argument
+///
+/// `TRUE` and `FALSE` place missing values last or first, while `NA`
+/// removes them. Where `keep` is allowed, `"keep"` retains missing values
+/// in place.
+///
+fn na_last(x: Obj, keep: bool) -> Result<NaLast, Signal> {
+    match x {
+        Obj::Vector(Vector::Logical(v)) if v.len() == 1 => match v.iter().next() {
+            Some(OptionNA::Some(true)) => Ok(NaLast::Last),
+            Some(OptionNA::Some(false)) => Ok(NaLast::First),
+            _ => Ok(NaLast::Remove),
+        },
+        Obj::Vector(v @ Vector::Character(_)) if keep && v.len() == 1 => {
+            match String::from(v).as_str() {
+                "keep" => Ok(NaLast::Keep),
+                _ => Error::Other("invalid 'na.last' value".into()).into(),
+            }
+        }
+        _ => Error::Other("invalid 'na.last' value".into()).into(),
+    }
+}
+
+/// Convert an argument to an atomic vector, treating `NULL` as empty
+fn atomic(x: Obj, name: &str) -> Result<Vector, Signal> {
+    match x {
+        Obj::Vector(v) => Ok(v),
+        Obj::Null => Ok(Vector::from(Vec::<Logical>::new())),
+        _ => Error::Other(format!("'{name}' must be an atomic vector")).into(),
+    }
+}
+
+/// Select (0-indexed) elements of a vector, alongside their names
+fn select(x: &Vector, indices: Vec<usize>) -> Vector {
+    x.subset(Subset::from(indices)).materialize()
+}
+
+/// Rank values densely, such that equal values share a rank
+///
+/// Missing values, including `NaN`s, are not ranked.
+///
+fn dense_ranks_of<T: Element>(x: &[OptionNA<T>]) -> Vec<Option<usize>> {
+    let mut present: Vec<(usize, &T)> = x
+        .iter()
+        .enumerate()
+        .filter_map(|(i, xi)| match xi {
+            OptionNA::Some(xi) if !xi.is_nan() => Some((i, xi)),
+            _ => None,
+        })
+        .collect();
+
+    present.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let mut ranks = vec![None; x.len()];
+    let mut rank = 0;
+    for (k, &(i, xi)) in present.iter().enumerate() {
+        if k > 0 && present[k - 1].1 < xi {
+            rank += 1;
+        }
+        ranks[i] = Some(rank);
+    }
+
+    ranks
+}
+
+fn dense_ranks(x: &Vector) -> Vec<Option<usize>> {
+    match x {
+        Vector::Double(v) => dense_ranks_of(&v.iter().collect::<Vec<_>>()),
+        Vector::Integer(v) => dense_ranks_of(&v.iter().collect::<Vec<_>>()),
+        Vector::Logical(v) => dense_ranks_of(&v.iter().collect::<Vec<_>>()),
+        Vector::Character(v) => dense_ranks_of(&v.iter().collect::<Vec<_>>()),
+    }
+}
+
+/// Order (0-indexed) positions by one or more keys of dense ranks
+///
+/// Ties of one key are broken by the next, and the order of any remaining
+/// ties is kept, such that ordering is stable.
+///
+fn ordering(keys: &[Vec<Option<usize>>], decreasing: bool, na_last: NaLast) -> Vec<usize> {
+    let n = keys.first().map_or(0, |key| key.len());
+    let mut indices: Vec<usize> = (0..n).collect();
+
+    if na_last == NaLast::Remove {
+        indices.retain(|&i| keys.iter().all(|key| key[i].is_some()));
+    }
+
+    let missing = match na_last {
+        NaLast::First => Ordering::Less,
+        _ => Ordering::Greater,
+    };
+
+    indices.sort_by(|&i, &j| {
+        for key in keys {
+            let ordering = match (key[i], key[j]) {
+                (Some(a), Some(b)) if decreasing => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => missing,
+                (Some(_), None) => missing.reverse(),
+            };
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    indices
+}
+
+/// Find the (0-indexed) position of the first match of values in a table
+fn positions_of<T: Element>(x: &[OptionNA<T>], table: &[OptionNA<T>]) -> Vec<Option<usize>> {
+    let key = |x: &OptionNA<T>| match x {
+        OptionNA::Some(x) => Some(x.key()),
+        OptionNA::NA => None,
+    };
+
+    let mut first: HashMap<Option<T::Key>, usize> = HashMap::new();
+    for (i, t) in table.iter().enumerate() {
+        first.entry(key(t)).or_insert(i);
+    }
+
+    x.iter().map(|xi| first.get(&key(xi)).copied()).collect()
+}
+
+/// Find the positions of values in a table, after coercing both to a
+/// common type
+fn positions(x: Vector, table: Vector) -> Vec<Option<usize>> {
+    use Vector::*;
+    let (x, table) = match (&x, &table) {
+        (Character(_), _) | (_, Character(_)) => (x.as_character(), table.as_character()),
+        (Double(_), _) | (_, Double(_)) => (x.as_double(), table.as_double()),
+        (Integer(_), _) | (_, Integer(_)) => (x.as_integer(), table.as_integer()),
+        _ => (x, table),
+    };
+
+    match (x, table) {
+        (Double(x), Double(t)) => {
+            positions_of(&x.iter().collect::<Vec<_>>(), &t.iter().collect::<Vec<_>>())
+        }
+        (Integer(x), Integer(t)) => {
+            positions_of(&x.iter().collect::<Vec<_>>(), &t.iter().collect::<Vec<_>>())
+        }
+        (Logical(x), Logical(t)) => {
+            positions_of(&x.iter().collect::<Vec<_>>(), &t.iter().collect::<Vec<_>>())
+        }
+        (Character(x), Character(t)) => {
+            positions_of(&x.iter().collect::<Vec<_>>(), &t.iter().collect::<Vec<_>>())
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Whether each value matches an earlier value
+fn duplicates(x: &Vector) -> Vec<bool> {
+    positions(x.clone(), x.clone())
+        .into_iter()
+        .enumerate()
+        .map(|(i, first)| first != Some(i))
+        .collect()
+}
+
+/// Sort a Vector
+///
+/// Sorts the values of an atomic vector into ascending or descending order.
+/// Names are sorted alongside their values.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sort(x, decreasing = false, na.last = NA)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector.
+/// `decreasing`: Whether to sort into descending order.
+/// `na.last`: Where to place missing values. When `true` or `false`,
+///   missing values are placed last or first, while `NA` removes them.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sort(c(3, 1, NA, 2))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// sort(c("b", "c", "a"), decreasing = true)
+/// ```
+///
+#[doc(alias = "sort")]
+#[builtin(sym = "sort")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSort;
+
+impl Callable for PrimitiveSort {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("decreasing".to_string()), Expr::Bool(false)),
+            (Some("na.last".to_string()), Expr::NA),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let decreasing: bool = args.try_get_named("decreasing")?.force(stack)?.try_into()?;
+        let na_last = na_last(args.try_get_named("na.last")?.force(stack)?, false)?;
+
+        match x {
+            Obj::Null => Ok(Obj::Null),
+            x => {
+                let x = atomic(x, "x")?;
+                let indices = ordering(&[dense_ranks(&x)], decreasing, na_last);
+                Ok(Obj::Vector(select(&x, indices)))
+            }
+        }
+    }
+}
+
+/// Order Values
+///
+/// Finds the permutation of positions which sorts one or more vectors.
+/// Ties in one vector are broken by the next, and any remaining ties are
+/// kept in their original order.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// order(..., na.last = true, decreasing = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Atomic vectors of equal length by which to order.
+/// `na.last`: Where to place missing values. When `true` or `false`,
+///   missing values are placed last or first, while `NA` removes them.
+/// `decreasing`: Whether to order into descending order.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// order(c(3, 1, 2))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// x <- c(1, 2, 1, 2)
+/// y <- c(4, 3, 2, 1)
+/// order(x, y)
+/// ```
+///
+#[doc(alias = "order")]
+#[builtin(sym = "order")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveOrder;
+
+impl Callable for PrimitiveOrder {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (None, Expr::Ellipsis(None)),
+            (Some("na.last".to_string()), Expr::Bool(true)),
+            (Some("decreasing".to_string()), Expr::Bool(false)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let na_last = na_last(args.try_get_named("na.last")?.force(stack)?, false)?;
+        let decreasing: bool = args.try_get_named("decreasing")?.force(stack)?.try_into()?;
+
+        let keys = force_promises(ellipsis, stack)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, (_, x))| Ok(dense_ranks(&atomic(x, &format!("..{}", i + 1))?)))
+            .collect::<Result<Vec<_>, Signal>>()?;
+
+        if keys.windows(2).any(|w| w[0].len() != w[1].len()) {
+            return Error::Other("argument lengths differ".into()).into();
+        }
+
+        let indices: Vec<i32> = ordering(&keys, decreasing, na_last)
+            .into_iter()
+            .map(|i| i as i32 + 1)
+            .collect();
+
+        Ok(Obj::Vector(Vector::from(indices)))
+    }
+}
+
+/// Rank Values
+///
+/// Finds the rank of each value of an atomic vector among all of its
+/// values. Ties are resolved by one of several methods.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// rank(x, na.last = true, ties.method = "average")
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector.
+/// `na.last`: Where to rank missing values. When `true` or `false`, they
+///   are ranked last or first, `NA` removes them and `"keep"` gives them
+///   a rank of `NA`.
+/// `ties.method`: How to rank tied values, one of `"average"`, `"first"`,
+///   `"last"`, `"min"` or `"max"`. Ranks are `integer`s, except for
+///   `"average"` ranks.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// rank(c(10, 20, 10, 30))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// rank(c(10, 20, 10, 30), ties.method = "min")
+/// ```
+///
+#[doc(alias = "rank")]
+#[builtin(sym = "rank")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRank;
+
+impl Callable for PrimitiveRank {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("na.last".to_string()), Expr::Bool(true)),
+            (
+                Some("ties.method".to_string()),
+                Expr::String("average".to_string()),
+            ),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = atomic(args.try_get_named("x")?.force(stack)?, "x")?;
+        let na_last = na_last(args.try_get_named("na.last")?.force(stack)?, true)?;
+        let method = match args.try_get_named("ties.method")?.force(stack)? {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => String::from(v),
+            _ => return Error::Other("'ties.method' must be a character string".into()).into(),
+        };
+
+        let ranks = dense_ranks(&x);
+        let order = match na_last {
+            NaLast::Keep => ordering(std::slice::from_ref(&ranks), false, NaLast::Last),
+            na_last => ordering(std::slice::from_ref(&ranks), false, na_last),
+        };
+
+        // walk runs of tied values in order, where missing values are
+        // never tied with one another
+        let mut result: Vec<Option<f64>> = vec![None; x.len()];
+        let mut start = 0;
+        while start < order.len() {
+            let rank = ranks[order[start]];
+            let mut end = start + 1;
+            while end < order.len() && rank.is_some() && ranks[order[end]] == rank {
+                end += 1;
+            }
+
+            for (offset, &i) in order[start..end].iter().enumerate() {
+                let (first, last) = ((start + 1) as f64, end as f64);
+                result[i] = match method.as_str() {
+                    "average" => Some((first + last) / 2.0),
+                    "first" => Some(first + offset as f64),
+                    "last" => Some(last - offset as f64),
+                    "min" => Some(first),
+                    "max" => Some(last),
+                    _ => return Error::Other("invalid 'ties.method' value".into()).into(),
+                };
+            }
+
+            start = end;
+        }
+
+        if na_last == NaLast::Keep {
+            for (i, rank) in ranks.iter().enumerate() {
+                if rank.is_none() {
+                    result[i] = None;
+                }
+            }
+        }
+
+        let result = match method.as_str() {
+            "average" => Vector::from(
+                result
+                    .into_iter()
+                    .map(|r| r.map_or(OptionNA::NA, OptionNA::Some))
+                    .collect::<Vec<Double>>(),
+            ),
+            _ => Vector::from(
+                result
+                    .into_iter()
+                    .map(|r| r.map_or(OptionNA::NA, |r| OptionNA::Some(r as i32)))
+                    .collect::<Vec<Integer>>(),
+            ),
+        };
+
+        if let Some(names) = x.attributes().get("names") {
+            result.attributes().set("names", names);
+        }
+
+        match na_last {
+            NaLast::Remove => {
+                let present = (0..x.len()).filter(|&i| ranks[i].is_some()).collect();
+                Ok(Obj::Vector(select(&result, present)))
+            }
+            _ => Ok(Obj::Vector(result)),
+        }
+    }
+}
+
+/// Reverse a Vector
+///
+/// Reverses the elements of a vector or list, alongside their names.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// rev(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector or list.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// rev(1:5)
+/// ```
+///
+#[doc(alias = "rev")]
+#[builtin(sym = "rev")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRev;
+
+impl Callable for PrimitiveRev {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let indices = |n: usize| Subset::from((0..n).rev().collect::<Vec<_>>());
+
+        match x {
+            Obj::Vector(v) => Ok(Obj::Vector(v.subset(indices(v.len())).materialize())),
+            Obj::List(l) => Ok(Obj::List(l.subset(indices(l.len())).materialize())),
+            Obj::Null => Ok(Obj::Null),
+            _ => Error::Other("cannot reverse this object".into()).into(),
+        }
+    }
+}
+
+/// Unique Values
+///
+/// Removes values which match an earlier value of an atomic vector.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// unique(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// unique(c(1, 2, 1, NA, 3, NA))
+/// ```
+///
+#[doc(alias = "unique")]
+#[builtin(sym = "unique")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveUnique;
+
+impl Callable for PrimitiveUnique {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        match args.try_get_named("x")?.force(stack)? {
+            Obj::Null => Ok(Obj::Null),
+            x => {
+                let x = atomic(x, "x")?;
+                let indices = duplicates(&x)
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, duplicate)| (!duplicate).then_some(i))
+                    .collect();
+
+                let unique = select(&x, indices).with_attributes(Attributes::default());
+                Ok(Obj::Vector(unique))
+            }
+        }
+    }
+}
+
+/// Find Duplicated Values
+///
+/// Tests whether each value of an atomic vector matches an earlier value.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// duplicated(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// duplicated(c("a", "b", "a"))
+/// ```
+///
+#[doc(alias = "duplicated")]
+#[builtin(sym = "duplicated")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveDuplicated;
+
+impl Callable for PrimitiveDuplicated {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = atomic(args.try_get_named("x")?.force(stack)?, "x")?;
+        Ok(Obj::Vector(Vector::from(duplicates(&x))))
+    }
+}
+
+/// Match Values
+///
+/// Finds the position of the first match of each value within a table of
+/// values. Values are first coerced to a common type, and missing values
+/// match one another.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// match(x, table, nomatch = NA)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector of values to find.
+/// `table`: An atomic vector of values in which to find them.
+/// `nomatch`: The position given to values which are not found.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// match(c("b", "z"), c("a", "b", "c"))
+/// ```
+///
+#[doc(alias = "match")]
+#[builtin(sym = "match")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMatch;
+
+impl Callable for PrimitiveMatch {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("table".to_string()), Expr::Missing),
+            (Some("nomatch".to_string()), Expr::NA),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = atomic(args.try_get_named("x")?.force(stack)?, "x")?;
+        let table = atomic(args.try_get_named("table")?.force(stack)?, "table")?;
+        let nomatch = match args.try_get_named("nomatch")?.force(stack)?.as_integer()? {
+            Obj::Vector(Vector::Integer(v)) if v.len() == 1 => v.iter().next().unwrap_or_default(),
+            _ => return Error::Other("'nomatch' must be of length 1".into()).into(),
+        };
+
+        let positions: Vec<Integer> = positions(x, table)
+            .into_iter()
+            .map(|i| i.map_or(nomatch.clone(), |i| OptionNA::Some(i as i32 + 1)))
+            .collect();
+
+        Ok(Obj::Vector(Vector::from(positions)))
+    }
+}
+
+/// Value Matching
+///
+/// Tests whether each value is found within a table of values. Values are
+/// first coerced to a common type, and missing values match one another.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// x %in% table
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector of values to find.
+/// `table`: An atomic vector of values in which to find them.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// c(1, 5, NA) %in% c(1, 2, NA)
+/// ```
+///
+#[doc(alias = "%in%")]
+#[builtin(sym = "%in%")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIn;
+
+impl Callable for PrimitiveIn {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("table".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = atomic(args.try_get_named("x")?.force(stack)?, "x")?;
+        let table = atomic(args.try_get_named("table")?.force(stack)?, "table")?;
+        let found: Vec<bool> = positions(x, table)
+            .into_iter()
+            .map(|i| i.is_some())
+            .collect();
+
+        Ok(Obj::Vector(Vector::from(found)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn sort_removes_missing() {
+        assert_eq!(r! { sort(c(3, 1, NA, 2)) }, r! { c(1, 2, 3) })
+    }
+
+    #[test]
+    fn sort_decreasing_missing_first() {
+        assert_eq!(
+            r! { sort(c(3L, NA, 1L, 2L), decreasing = true, na.last = false) },
+            r! { c(NA, 3L, 2L, 1L) }
+        )
+    }
+
+    #[test]
+    fn sort_characters_and_logicals() {
+        assert_eq!(r! { sort(c("b", "c", "a")) }, r! { c("a", "b", "c") });
+        assert_eq!(
+            r! { sort(c(true, false, true)) },
+            r! { c(false, true, true) }
+        )
+    }
+
+    #[test]
+    fn sort_keeps_names() {
+        assert_eq!(
+            r! { names(sort(structure(c(2, 3, 1), names = c("b", "c", "a")))) },
+            r! { c("a", "b", "c") }
+        )
+    }
+
+    #[test]
+    fn order_is_stable() {
+        assert_eq!(r! { order(c(2, 1, 2, 1)) }, r! { c(2L, 4L, 1L, 3L) });
+        assert_eq!(
+            r! { order(c(2, 1, 2, 1), decreasing = true) },
+            r! { c(1L, 3L, 2L, 4L) }
+        )
+    }
+
+    #[test]
+    fn order_multiple_keys() {
+        assert_eq!(
+            r! { order(c(1, 2, 1, 2), c(4, 3, 2, 1)) },
+            r! { c(3L, 1L, 4L, 2L) }
+        )
+    }
+
+    #[test]
+    fn order_missing() {
+        assert_eq!(r! { order(c(2, NA, 1)) }, r! { c(3L, 1L, 2L) });
+        assert_eq!(r! { order(c(2, NA, 1), na.last = NA) }, r! { c(3L, 1L) })
+    }
+
+    #[test]
+    fn order_subsets() {
+        assert_eq!(r! { x <- c(3, 1, 2); x[order(x)] }, r! { c(1, 2, 3) })
+    }
+
+    #[test]
+    fn order_differing_lengths() {
+        assert!(r! { order(1:3, 1:2) }.is_err())
+    }
+
+    #[test]
+    fn rank_ties() {
+        assert_eq!(r! { rank(c(10, 20, 10, 30)) }, r! { c(1.5, 3, 1.5, 4) });
+        assert_eq!(
+            r! { rank(c(10, 20, 10, 30), ties.method = "first") },
+            r! { c(1L, 3L, 2L, 4L) }
+        );
+        assert_eq!(
+            r! { rank(c(10, 20, 10, 30), ties.method = "last") },
+            r! { c(2L, 3L, 1L, 4L) }
+        );
+        assert_eq!(
+            r! { rank(c(10, 20, 10, 30), ties.method = "min") },
+            r! { c(1L, 3L, 1L, 4L) }
+        );
+        assert_eq!(
+            r! { rank(c(10, 20, 10, 30), ties.method = "max") },
+            r! { c(2L, 3L, 2L, 4L) }
+        )
+    }
+
+    #[test]
+    fn rank_missing() {
+        assert_eq!(r! { rank(c(2, NA, 1, NA)) }, r! { c(2, 3, 1, 4) });
+        assert_eq!(
+            r! { rank(c(2, NA, 1), na.last = "keep") },
+            r! { c(2, NA, 1) }
+        );
+        assert_eq!(r! { rank(c(2, NA, 1), na.last = NA) }, r! { c(2, 1) })
+    }
+
+    #[test]
+    fn rev_vectors_and_lists() {
+        assert_eq!(r! { rev(1:3) }, r! { c(3L, 2L, 1L) });
+        assert_eq!(r! { rev(list(1, "a")) }, r! { list("a", 1) })
+    }
+
+    #[test]
+    fn unique_values() {
+        assert_eq!(r! { unique(c(1, 2, 1, NA, 3, NA)) }, r! { c(1, 2, NA, 3) });
+        assert_eq!(r! { unique(c("a", "b", "a")) }, r! { c("a", "b") })
+    }
+
+    #[test]
+    fn duplicated_values() {
+        assert_eq!(
+            r! { duplicated(c(true, false, true, NA, NA)) },
+            r! { c(false, false, true, false, true) }
+        )
+    }
+
+    #[test]
+    fn match_values() {
+        assert_eq!(
+            r! { match(c("b", "z", NA), c("a", "b", "b", NA)) },
+            r! { c(2L, NA, 4L) }
+        );
+        assert_eq!(r! { match(c(2, 5), 1:3, nomatch = 0) }, r! { c(2L, 0L) })
+    }
+
+    #[test]
+    fn match_coerces() {
+        assert_eq!(r! { match(2L, c("1", "2")) }, r! { 2L })
+    }
+
+    #[test]
+    fn in_values() {
+        assert_eq!(
+            r! { c(1, 5, NA) %in% c(1, 2, NA) },
+            r! { c(true, false, true) }
+        )
+    }
+}
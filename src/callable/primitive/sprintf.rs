@@ -0,0 +1,544 @@
+use r_derive::*;
+
+use super::strings::{characters, recycled_len};
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// A conversion specification, like `%-8.3f`
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    space: bool,
+    zero: bool,
+    alternate: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+    conversion: char,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Piece {
+    Literal(String),
+    Spec(Spec),
+}
+
+/// Parse a format string into literal text and conversion specifications
+fn parse(format: &str) -> Result<Vec<Piece>, Signal> {
+    let invalid = || {
+        Error::Other(format!(
+            "invalid format '{format}'; unrecognised conversion"
+        ))
+    };
+
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            literal.push('%');
+            continue;
+        }
+
+        let mut spec = Spec::default();
+        while let Some(&flag) = chars.peek() {
+            match flag {
+                '-' => spec.left = true,
+                '+' => spec.plus = true,
+                ' ' => spec.space = true,
+                '0' => spec.zero = true,
+                '#' => spec.alternate = true,
+                _ => break,
+            }
+            chars.next();
+        }
+
+        let number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+            let mut digits = String::new();
+            while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                digits.push(*d);
+                chars.next();
+            }
+            digits.parse::<usize>().ok()
+        };
+
+        spec.width = number(&mut chars);
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            spec.precision = Some(number(&mut chars).unwrap_or(0));
+        }
+
+        spec.conversion = match chars.next() {
+            Some(c @ ('d' | 'i' | 'f' | 'e' | 'E' | 'g' | 'G' | 's' | 'x' | 'X' | 'o')) => c,
+            _ => return invalid().into(),
+        };
+
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+        }
+
+        pieces.push(Piece::Spec(spec));
+    }
+
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+
+    Ok(pieces)
+}
+
+/// Format a double in C-style scientific notation, like `1.500000e+03`
+fn scientific(x: f64, precision: usize, upper: bool) -> String {
+    let formatted = format!("{x:.precision$e}");
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let sign = if exponent < 0 { '-' } else { '+' };
+    let e = if upper { 'E' } else { 'e' };
+    format!("{mantissa}{e}{sign}{:02}", exponent.abs())
+}
+
+/// Remove trailing zeros after a decimal point, and the point itself
+fn strip_zeros(x: &str) -> String {
+    match x.contains('.') {
+        true => x.trim_end_matches('0').trim_end_matches('.').to_string(),
+        false => x.to_string(),
+    }
+}
+
+/// Format a double in C-style general notation
+///
+/// Scientific notation is used for exponents less than `-4` or not less
+/// than the precision, and fixed notation otherwise. Trailing zeros are
+/// removed unless `alternate` is set.
+///
+fn general(x: f64, precision: usize, upper: bool, alternate: bool) -> String {
+    let p = precision.max(1);
+    let exponent = match x {
+        0.0 => 0,
+        _ => {
+            let e = scientific(x, p - 1, false);
+            let (_, exponent) = e.split_once('e').unwrap_or((&e, "0"));
+            exponent.parse::<i32>().unwrap_or(0)
+        }
+    };
+
+    if exponent < -4 || exponent >= p as i32 {
+        let formatted = scientific(x, p - 1, upper);
+        match alternate {
+            true => formatted,
+            false => {
+                let (mantissa, exponent) =
+                    formatted.split_at(formatted.find(['e', 'E']).unwrap_or(0));
+                format!("{}{exponent}", strip_zeros(mantissa))
+            }
+        }
+    } else {
+        let decimals = (p as i32 - 1 - exponent).max(0) as usize;
+        let formatted = format!("{x:.decimals$}");
+        match alternate {
+            true => formatted,
+            false => strip_zeros(&formatted),
+        }
+    }
+}
+
+/// Pad a formatted value to the width of a specification
+///
+/// Zero padding is inserted after any sign, and only applies to numbers.
+///
+fn pad(body: String, spec: &Spec, numeric: bool) -> String {
+    let width = spec.width.unwrap_or(0);
+    let n = body.chars().count();
+    if n >= width {
+        return body;
+    }
+
+    let fill = width - n;
+    if spec.left {
+        format!("{body}{}", " ".repeat(fill))
+    } else if spec.zero && numeric {
+        let split = body.find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+        let (sign, digits) = body.split_at(split);
+        format!("{sign}{}{digits}", "0".repeat(fill))
+    } else {
+        format!("{}{body}", " ".repeat(fill))
+    }
+}
+
+/// Prefix a non-negative number with a sign, as requested
+fn signed(body: String, negative: bool, spec: &Spec) -> String {
+    match (negative, spec.plus, spec.space) {
+        (true, ..) => format!("-{body}"),
+        (false, true, _) => format!("+{body}"),
+        (false, false, true) => format!(" {body}"),
+        _ => body,
+    }
+}
+
+/// Format an integer by a specification
+fn format_integer(x: i64, spec: &Spec) -> String {
+    let magnitude = x.unsigned_abs();
+    let body = match spec.conversion {
+        'x' if spec.alternate && x != 0 => format!("0x{magnitude:x}"),
+        'X' if spec.alternate && x != 0 => format!("0X{magnitude:X}"),
+        'o' if spec.alternate && x != 0 => format!("0{magnitude:o}"),
+        'x' => format!("{magnitude:x}"),
+        'X' => format!("{magnitude:X}"),
+        'o' => format!("{magnitude:o}"),
+        _ => magnitude.to_string(),
+    };
+
+    let body = match spec.precision {
+        Some(p) if body.len() < p => format!("{}{body}", "0".repeat(p - body.len())),
+        _ => body,
+    };
+
+    pad(signed(body, x < 0, spec), spec, true)
+}
+
+/// Format a double by a specification
+fn format_double(x: f64, spec: &Spec) -> String {
+    if !x.is_finite() {
+        let body = match x {
+            x if x.is_nan() => "NaN".to_string(),
+            x => signed("Inf".to_string(), x < 0.0, spec),
+        };
+        return pad(body, spec, false);
+    }
+
+    let precision = spec.precision.unwrap_or(6);
+    let magnitude = x.abs();
+    let body = match spec.conversion {
+        'e' | 'E' => scientific(magnitude, precision, spec.conversion == 'E'),
+        'g' | 'G' => general(magnitude, precision, spec.conversion == 'G', spec.alternate),
+        _ => format!("{magnitude:.precision$}"),
+    };
+
+    pad(
+        signed(body, x.is_sign_negative() && x != 0.0, spec),
+        spec,
+        true,
+    )
+}
+
+/// Format a missing value by a specification
+fn format_na(spec: &Spec) -> String {
+    let na = match spec.precision {
+        Some(p) if spec.conversion == 's' => "NA".chars().take(p).collect(),
+        _ => "NA".to_string(),
+    };
+
+    pad(
+        na,
+        &Spec {
+            zero: false,
+            ..spec.clone()
+        },
+        false,
+    )
+}
+
+/// The values of an argument, prepared for a conversion
+enum Values {
+    Integer(Vec<Integer>),
+    Double(Vec<Double>),
+    Character(Vec<Character>),
+}
+
+impl Values {
+    fn len(&self) -> usize {
+        match self {
+            Values::Integer(v) => v.len(),
+            Values::Double(v) => v.len(),
+            Values::Character(v) => v.len(),
+        }
+    }
+
+    /// Prepare the values of an argument for a conversion
+    ///
+    /// `double`s with whole values may be formatted as integers, while
+    /// `integer`s and `logical`s may be formatted as doubles. Any value may
+    /// be formatted as a string.
+    ///
+    fn prepare(x: &Obj, conversion: char) -> Result<Values, Signal> {
+        let bad = |ty: &str| {
+            let msg = format!("invalid format '%{conversion}'; use format {ty}");
+            Error::Other(msg).into()
+        };
+
+        match (conversion, x) {
+            ('s', x) => Ok(Values::Character(characters(x.clone(), "...")?)),
+            (_, Obj::Vector(Vector::Character(_))) => bad("%s for character objects"),
+            ('d' | 'i' | 'x' | 'X' | 'o', Obj::Vector(Vector::Double(v))) => {
+                let values: Vec<Double> = v.iter().collect();
+                let whole = values.iter().all(|x| match x {
+                    OptionNA::Some(x) => x.is_finite() && x.fract() == 0.0,
+                    OptionNA::NA => true,
+                });
+
+                match whole {
+                    true => Ok(Values::Double(values)),
+                    false => bad("%f, %e or %g for numeric objects"),
+                }
+            }
+            ('d' | 'i' | 'x' | 'X' | 'o', Obj::Vector(v)) => match v.clone().as_integer() {
+                Vector::Integer(v) => Ok(Values::Integer(v.iter().collect())),
+                _ => unreachable!(),
+            },
+            (_, Obj::Vector(v)) => match v.clone().as_double() {
+                Vector::Double(v) => Ok(Values::Double(v.iter().collect())),
+                _ => unreachable!(),
+            },
+            _ => Error::Other("unsupported type".into()).into(),
+        }
+    }
+
+    fn format(&self, i: usize, spec: &Spec) -> String {
+        let is_integer = matches!(spec.conversion, 'd' | 'i' | 'x' | 'X' | 'o');
+        match self {
+            Values::Character(v) => match &v[i % v.len()] {
+                OptionNA::Some(s) => {
+                    let s = match spec.precision {
+                        Some(p) => s.chars().take(p).collect(),
+                        None => s.clone(),
+                    };
+                    pad(s, spec, false)
+                }
+                OptionNA::NA => format_na(spec),
+            },
+            Values::Integer(v) => match v[i % v.len()] {
+                OptionNA::Some(x) if is_integer => format_integer(x as i64, spec),
+                OptionNA::Some(x) => format_double(x as f64, spec),
+                OptionNA::NA => format_na(spec),
+            },
+            Values::Double(v) => match v[i % v.len()] {
+                OptionNA::Some(x) if is_integer => format_integer(x as i64, spec),
+                OptionNA::Some(x) => format_double(x, spec),
+                OptionNA::NA => format_na(spec),
+            },
+        }
+    }
+}
+
+/// Format Strings
+///
+/// Formats values into strings using C-style conversion specifications.
+/// Each specification consumes the next argument, and all arguments,
+/// including the format, are recycled to the length of the longest.
+///
+/// Specifications take the form `%[flags][width][.precision]conversion`,
+/// where conversions are `d` or `i` (integers), `f` (fixed decimals),
+/// `e` or `E` (scientific), `g` or `G` (general), `s` (strings) and `x`,
+/// `X` or `o` (hexadecimal and octal integers). Flags are `-` (justify to
+/// the left), `+` (always sign), a space (pad positive numbers with a
+/// space), `0` (pad with zeros) and `#` (alternate form). A literal `%` is
+/// written as `%%`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sprintf(fmt, ...)
+/// ```
+///
+/// ## Arguments
+///
+/// `fmt`: A `character` vector of formats.
+/// `...`: Values to format.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sprintf("%s has %d items costing %.2f", "cart", 3L, 9.5)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// sprintf("%5.1f%%", c(12.345, 6.7))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// sprintf("%-6s|%06.2f|%e", "ab", 3.14159, 12345.678)
+/// ```
+///
+#[doc(alias = "sprintf")]
+#[builtin(sym = "sprintf")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSprintf;
+
+impl Callable for PrimitiveSprintf {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("fmt".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let fmt = match args.try_get_named("fmt")?.force(stack)? {
+            Obj::Vector(Vector::Character(v)) => v.iter().collect::<Vec<Character>>(),
+            _ => return Error::Other("'fmt' is not a character vector".into()).into(),
+        };
+        let values: Vec<Obj> = force_promises(ellipsis, stack)?
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+
+        // each distinct format is parsed once, and its arguments prepared
+        let mut formats: Vec<Option<(Vec<Piece>, Vec<Values>)>> = vec![];
+        let mut max_used = 0;
+        for f in &fmt {
+            let OptionNA::Some(f) = f else {
+                formats.push(None);
+                continue;
+            };
+
+            let pieces = parse(f)?;
+            let specs: Vec<&Spec> = pieces
+                .iter()
+                .filter_map(|p| match p {
+                    Piece::Spec(spec) => Some(spec),
+                    Piece::Literal(_) => None,
+                })
+                .collect();
+
+            if specs.len() > values.len() {
+                return Error::Other("too few arguments".into()).into();
+            }
+
+            max_used = max_used.max(specs.len());
+            let prepared = specs
+                .iter()
+                .zip(values.iter())
+                .map(|(spec, value)| Values::prepare(value, spec.conversion))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            formats.push(Some((pieces, prepared)));
+        }
+
+        if max_used < values.len() && !fmt.is_empty() {
+            let unused = values.len() - max_used;
+            stack.warn(format!("{unused} arguments not used by format"))?;
+        }
+
+        let lengths: Vec<usize> = std::iter::once(fmt.len())
+            .chain(values.iter().map(|v| v.len().unwrap_or(0)))
+            .collect();
+        let n = recycled_len(&lengths);
+
+        let result: Vec<Character> = (0..n)
+            .map(|i| match &formats[i % formats.len()] {
+                None => OptionNA::NA,
+                Some((pieces, prepared)) => {
+                    let mut prepared = prepared.iter();
+                    let mut s = String::new();
+                    for piece in pieces {
+                        match piece {
+                            Piece::Literal(text) => s.push_str(text),
+                            Piece::Spec(spec) => match prepared.next() {
+                                Some(values) if values.len() > 0 => {
+                                    s.push_str(&values.format(i, spec))
+                                }
+                                _ => (),
+                            },
+                        }
+                    }
+                    OptionNA::Some(s)
+                }
+            })
+            .collect();
+
+        Ok(Obj::Vector(Vector::from(result)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn integers_and_strings() {
+        assert_eq!(
+            r! { sprintf("%s has %d items", "cart", 3L) },
+            r! { "cart has 3 items" }
+        );
+        assert_eq!(r! { sprintf("%d", 3) }, r! { "3" });
+        assert_eq!(
+            r! { sprintf("%5d|%-5d|%05d", 42L, 42L, -42L) },
+            r! { "   42|42   |-0042" }
+        );
+        assert_eq!(r! { sprintf("%+d % d", 5L, 5L) }, r! { "+5  5" })
+    }
+
+    #[test]
+    fn doubles() {
+        assert_eq!(r! { sprintf("%.2f", 3.14159) }, r! { "3.14" });
+        assert_eq!(r! { sprintf("%8.3f", -2.5) }, r! { "  -2.500" });
+        assert_eq!(r! { sprintf("%f", 1L) }, r! { "1.000000" });
+        assert_eq!(r! { sprintf("%e", 12345.678) }, r! { "1.234568e+04" });
+        assert_eq!(r! { sprintf("%.2E", 0.000123) }, r! { "1.23E-04" })
+    }
+
+    #[test]
+    fn general() {
+        assert_eq!(r! { sprintf("%g", 1234567) }, r! { "1.23457e+06" });
+        assert_eq!(r! { sprintf("%g", 0.0001) }, r! { "0.0001" });
+        assert_eq!(r! { sprintf("%g", 0.00001234) }, r! { "1.234e-05" });
+        assert_eq!(r! { sprintf("%g", 100) }, r! { "100" });
+        assert_eq!(r! { sprintf("%.3g", 3.14159) }, r! { "3.14" })
+    }
+
+    #[test]
+    fn percent_and_widths() {
+        assert_eq!(r! { sprintf("%5.1f%%", 12.345) }, r! { " 12.3%" });
+        assert_eq!(r! { sprintf("%-4s|%4s", "ab", "cd") }, r! { "ab  |  cd" });
+        assert_eq!(r! { sprintf("%.2s", "abc") }, r! { "ab" })
+    }
+
+    #[test]
+    fn hexadecimal() {
+        assert_eq!(
+            r! { sprintf("%x %X %o %#x", 255L, 255L, 8L, 255L) },
+            r! { "ff FF 10 0xff" }
+        )
+    }
+
+    #[test]
+    fn vectorized() {
+        assert_eq!(
+            r! { sprintf("%s-%d", c("a", "b", "c"), 1:3) },
+            r! { c("a-1", "b-2", "c-3") }
+        );
+        assert_eq!(r! { sprintf(c("%d!", "%d?"), 1L) }, r! { c("1!", "1?") });
+        assert_eq!(r! { sprintf("%d", c(1L)[0]) }, r! { c("a")[0] })
+    }
+
+    #[test]
+    fn missing_values() {
+        assert_eq!(
+            r! { sprintf("%d|%4.1f|%s", NA, NA, NA) },
+            r! { "NA|  NA|NA" }
+        );
+        assert_eq!(r! { sprintf("%f", 0 / 0) }, r! { "NaN" });
+        assert_eq!(r! { sprintf("%5.1f", -1 / 0) }, r! { " -Inf" })
+    }
+
+    #[test]
+    fn invalid_formats() {
+        assert!(r! { sprintf("%d", 1.5) }.is_err());
+        assert!(r! { sprintf("%d", "a") }.is_err());
+        assert!(r! { sprintf("%d %d", 1L) }.is_err());
+        assert!(r! { sprintf("%q", 1L) }.is_err())
+    }
+}
@@ -0,0 +1,88 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Concatenate arguments into a single condition message, as is used by
+/// `stop()`, `warning()` and `message()`.
+pub fn condition_message(ellipsis: List, stack: &mut CallStack) -> Result<String, Signal> {
+    force_promises(ellipsis, stack)?
+        .into_iter()
+        .map(|(_, v)| match v.as_character()? {
+            Obj::Vector(v) => Ok(Into::<Vec<String>>::into(v).concat()),
+            _ => Ok(String::new()),
+        })
+        .collect()
+}
+
+/// Raise an Error
+///
+/// Stops evaluation, raising an error which propagates until it is handled
+/// by `tryCatch()` or reported at the top level.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// stop(...)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects which are coerced to `character` and concatenated to
+///   produce the error message.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- fn(x) if (x < 0) stop("x must be positive, not ", x) else x
+/// f(-1)
+/// ```
+///
+#[doc(alias = "stop")]
+#[builtin(sym = "stop")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveStop;
+impl Callable for PrimitiveStop {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(None, Expr::Ellipsis(None))])
+    }
+
+    fn call_matched(&self, _args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let message = condition_message(ellipsis, stack)?;
+        Error::Other(message).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::r;
+
+    #[test]
+    fn raises_error() {
+        assert_eq!(
+            r! { stop("oh no") },
+            Error::Other("oh no".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn concatenates_message() {
+        assert_eq!(
+            r! { stop("value ", 1, " is ", c("not", "ok")) },
+            Error::Other("value 1 is notok".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn stops_evaluation() {
+        assert_eq!(
+            r! { f <- fn() { stop("!"); 2 }; f() },
+            Error::Other("!".to_string()).into()
+        )
+    }
+}
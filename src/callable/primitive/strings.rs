@@ -0,0 +1,871 @@
+use r_derive::*;
+use regex::Regex;
+
+use super::pattern::compile;
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// The values of an argument, coerced to `character`
+///
+/// `NULL` is treated as an empty vector.
+///
+pub fn characters(x: Obj, name: &str) -> Result<Vec<Character>, Signal> {
+    match x.as_character() {
+        Ok(Obj::Vector(Vector::Character(v))) => Ok(v.iter().collect()),
+        _ => Error::Other(format!("'{name}' must be a character vector")).into(),
+    }
+}
+
+/// The values of an argument, coerced to `integer`
+fn integers(x: Obj, name: &str) -> Result<Vec<Integer>, Signal> {
+    match x.as_integer() {
+        Ok(Obj::Vector(Vector::Integer(v))) => Ok(v.iter().collect()),
+        _ => Error::Other(format!("'{name}' must be an integer vector")).into(),
+    }
+}
+
+/// The values of a `character` argument, without coercion
+fn strict_characters(x: Obj) -> Result<Vec<Character>, Signal> {
+    match x {
+        Obj::Vector(Vector::Character(v)) => Ok(v.iter().collect()),
+        _ => Error::Other("non-character object(s)".into()).into(),
+    }
+}
+
+/// A single string argument
+fn string(x: Obj, name: &str) -> Result<String, Signal> {
+    match x {
+        Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => Ok(String::from(v)),
+        _ => Error::Other(format!("'{name}' must be a character string")).into(),
+    }
+}
+
+/// Keep the `names` of `x` on a result of the same length
+pub fn with_names(result: Vector, x: &Obj) -> Obj {
+    if let Some(names) = x.get_attr("names") {
+        result.attributes().set("names", names);
+    }
+
+    Obj::Vector(result)
+}
+
+/// The length of the result of recycling arguments of some lengths
+///
+/// Arguments are recycled to the longest length, unless any is empty.
+///
+pub fn recycled_len(lengths: &[usize]) -> usize {
+    match lengths.iter().min() {
+        None | Some(0) => 0,
+        Some(_) => lengths.iter().copied().max().unwrap_or(0),
+    }
+}
+
+/// A substring of characters, by 1-indexed inclusive positions
+fn substring_of(x: &str, start: i32, stop: i32) -> String {
+    let start = start.max(1) as usize;
+    let stop = stop.max(0) as usize;
+    if start > stop {
+        return String::new();
+    }
+
+    x.chars().skip(start - 1).take(stop - start + 1).collect()
+}
+
+/// Number of Characters
+///
+/// Counts the characters of each string. Values are first coerced to
+/// `character`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// nchar(x, type = "chars")
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector of strings.
+/// `type`: What to count, either `"chars"` or `"bytes"`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// nchar(c("apple", "fig", NA))
+/// ```
+///
+#[doc(alias = "nchar")]
+#[builtin(sym = "nchar")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveNchar;
+
+impl Callable for PrimitiveNchar {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("type".to_string()), Expr::String("chars".to_string())),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let count: fn(&str) -> usize =
+            match string(args.try_get_named("type")?.force(stack)?, "type")?.as_str() {
+                "chars" => |s| s.chars().count(),
+                "bytes" => str::len,
+                _ => return Error::Other("invalid 'type' argument".into()).into(),
+            };
+
+        let result: Vec<Integer> = characters(x.clone(), "x")?
+            .into_iter()
+            .map(|xi| xi.map(|s| count(&s) as i32))
+            .collect();
+
+        Ok(with_names(Vector::from(result), &x))
+    }
+}
+
+/// Substrings
+///
+/// Extracts the characters of each string between a start and a stop
+/// position, counted from `1`. Start and stop positions are recycled
+/// along `x`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// substr(x, start, stop)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector of strings.
+/// `start`: The position of the first character to extract.
+/// `stop`: The position of the last character to extract.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// substr("abcdef", 2, 4)
+/// ```
+///
+#[doc(alias = "substr")]
+#[builtin(sym = "substr")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSubstr;
+
+impl Callable for PrimitiveSubstr {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("start".to_string()), Expr::Missing),
+            (Some("stop".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let start = integers(args.try_get_named("start")?.force(stack)?, "start")?;
+        let stop = integers(args.try_get_named("stop")?.force(stack)?, "stop")?;
+        let values = characters(x.clone(), "x")?;
+
+        if !values.is_empty() && (start.is_empty() || stop.is_empty()) {
+            return Error::Other("invalid substring arguments".into()).into();
+        }
+
+        let result: Vec<Character> = values
+            .into_iter()
+            .enumerate()
+            .map(
+                |(i, xi)| match (xi, &start[i % start.len()], &stop[i % stop.len()]) {
+                    (OptionNA::Some(s), OptionNA::Some(a), OptionNA::Some(b)) => {
+                        OptionNA::Some(substring_of(&s, *a, *b))
+                    }
+                    _ => OptionNA::NA,
+                },
+            )
+            .collect();
+
+        Ok(with_names(Vector::from(result), &x))
+    }
+}
+
+/// Substrings of Recycled Strings
+///
+/// Extracts the characters of strings between start and stop positions,
+/// counted from `1`. Unlike `substr()`, all arguments are recycled to the
+/// length of the longest.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// substring(text, first, last = 1000000L)
+/// ```
+///
+/// ## Arguments
+///
+/// `text`: A vector of strings.
+/// `first`: The positions of the first characters to extract.
+/// `last`: The positions of the last characters to extract.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// substring("abcdef", 1:3, 3:5)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// substring("abcdef", 3)
+/// ```
+///
+#[doc(alias = "substring")]
+#[builtin(sym = "substring")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSubstring;
+
+impl Callable for PrimitiveSubstring {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("text".to_string()), Expr::Missing),
+            (Some("first".to_string()), Expr::Missing),
+            (Some("last".to_string()), Expr::Integer(1_000_000)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let text = characters(args.try_get_named("text")?.force(stack)?, "text")?;
+        let first = integers(args.try_get_named("first")?.force(stack)?, "first")?;
+        let last = integers(args.try_get_named("last")?.force(stack)?, "last")?;
+
+        let n = recycled_len(&[text.len(), first.len(), last.len()]);
+        let result: Vec<Character> = (0..n)
+            .map(|i| {
+                match (
+                    &text[i % text.len()],
+                    &first[i % first.len()],
+                    &last[i % last.len()],
+                ) {
+                    (OptionNA::Some(s), OptionNA::Some(a), OptionNA::Some(b)) => {
+                        OptionNA::Some(substring_of(s, *a, *b))
+                    }
+                    _ => OptionNA::NA,
+                }
+            })
+            .collect();
+
+        Ok(Obj::Vector(Vector::from(result)))
+    }
+}
+
+/// Convert Strings to Upper Case
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// toupper(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector of strings.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// toupper(c("abc", "Def"))
+/// ```
+///
+#[doc(alias = "toupper")]
+#[builtin(sym = "toupper")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveToupper;
+
+impl Callable for PrimitiveToupper {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let result: Vec<Character> = characters(x.clone(), "x")?
+            .into_iter()
+            .map(|xi| xi.map(|s| s.to_uppercase()))
+            .collect();
+
+        Ok(with_names(Vector::from(result), &x))
+    }
+}
+
+/// Convert Strings to Lower Case
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// tolower(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector of strings.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// tolower(c("ABC", "Def"))
+/// ```
+///
+#[doc(alias = "tolower")]
+#[builtin(sym = "tolower")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTolower;
+
+impl Callable for PrimitiveTolower {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let result: Vec<Character> = characters(x.clone(), "x")?
+            .into_iter()
+            .map(|xi| xi.map(|s| s.to_lowercase()))
+            .collect();
+
+        Ok(with_names(Vector::from(result), &x))
+    }
+}
+
+/// Trim Whitespace
+///
+/// Removes leading and trailing spaces, tabs and line breaks from strings.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// trimws(x, which = "both")
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector of strings.
+/// `which`: Which whitespace to remove, one of `"both"`, `"left"` or
+///   `"right"`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// trimws("  padded\t")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// trimws("  padded  ", which = "left")
+/// ```
+///
+#[doc(alias = "trimws")]
+#[builtin(sym = "trimws")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTrimws;
+
+impl Callable for PrimitiveTrimws {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("which".to_string()), Expr::String("both".to_string())),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let which = string(args.try_get_named("which")?.force(stack)?, "which")?;
+
+        let is_space = |c: char| matches!(c, ' ' | '\t' | '\r' | '\n');
+        let trim: Box<dyn Fn(&str) -> &str> = match which.as_str() {
+            "both" => Box::new(move |s| s.trim_matches(is_space)),
+            "left" => Box::new(move |s| s.trim_start_matches(is_space)),
+            "right" => Box::new(move |s| s.trim_end_matches(is_space)),
+            _ => return Error::Other("invalid 'which' argument".into()).into(),
+        };
+
+        let result: Vec<Character> = characters(x.clone(), "x")?
+            .into_iter()
+            .map(|xi| xi.map(|s| trim(&s).to_string()))
+            .collect();
+
+        Ok(with_names(Vector::from(result), &x))
+    }
+}
+
+/// Split a string at each match of a separator
+///
+/// A trailing empty piece is dropped, and an empty separator splits a
+/// string into its characters.
+///
+fn split_by(x: &str, split: &Regex) -> Vec<String> {
+    if split.as_str().is_empty() {
+        return x.chars().map(String::from).collect();
+    }
+
+    let mut pieces: Vec<String> = split.split(x).map(String::from).collect();
+    if pieces.last().is_some_and(|last| last.is_empty()) {
+        pieces.pop();
+    }
+
+    pieces
+}
+
+/// Split Strings
+///
+/// Splits each string at every match of a separator, producing a list of
+/// `character` vectors. Separators are regular expressions, unless `fixed`,
+/// and are recycled along `x`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// strsplit(x, split, fixed = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A `character` vector of strings to split.
+/// `split`: A `character` vector of separators. An empty separator splits
+///   strings into their individual characters.
+/// `fixed`: Whether to match separators literally.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// strsplit(c("a,b,c", "d,e"), ",")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// strsplit("abc", "")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// strsplit("a1b22c", "[0-9]+")
+/// ```
+///
+#[doc(alias = "strsplit")]
+#[builtin(sym = "strsplit")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveStrsplit;
+
+impl Callable for PrimitiveStrsplit {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("split".to_string()), Expr::Missing),
+            (Some("fixed".to_string()), Expr::Bool(false)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let split = strict_characters(args.try_get_named("split")?.force(stack)?)?;
+        let fixed: bool = args.try_get_named("fixed")?.force(stack)?.try_into()?;
+        let values = strict_characters(x.clone())?;
+
+        let split = match split.is_empty() {
+            true => vec![OptionNA::Some(String::new())],
+            false => split,
+        };
+
+        let split = split
+            .iter()
+            .map(|sep| match sep {
+                OptionNA::Some(sep) => Ok(Some(compile(sep, fixed, false)?)),
+                OptionNA::NA => Ok(None),
+            })
+            .collect::<Result<Vec<_>, Signal>>()?;
+
+        let pieces = values
+            .into_iter()
+            .enumerate()
+            .map(|(i, xi)| {
+                let pieces: Vec<Character> = match (xi, &split[i % split.len()]) {
+                    (OptionNA::Some(s), Some(sep)) => {
+                        split_by(&s, sep).into_iter().map(OptionNA::Some).collect()
+                    }
+                    _ => vec![OptionNA::NA],
+                };
+
+                (None, Obj::Vector(Vector::from(pieces)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = List::from(pieces);
+        if let Some(names) = x.get_attr("names") {
+            result.set_names(characters(names, "names")?);
+        }
+
+        Ok(Obj::List(result))
+    }
+}
+
+/// Test whether strings start or end with an affix, recycling both
+fn affixed(x: Obj, affix: Obj, test: fn(&str, &str) -> bool) -> EvalResult {
+    let x = strict_characters(x)?;
+    let affix = strict_characters(affix)?;
+
+    let n = recycled_len(&[x.len(), affix.len()]);
+    let result: Vec<Logical> = (0..n)
+        .map(|i| match (&x[i % x.len()], &affix[i % affix.len()]) {
+            (OptionNA::Some(s), OptionNA::Some(a)) => OptionNA::Some(test(s, a)),
+            _ => OptionNA::NA,
+        })
+        .collect();
+
+    Ok(Obj::Vector(Vector::from(result)))
+}
+
+/// Test for a String Prefix
+///
+/// Tests whether strings start with a prefix. Strings and prefixes are
+/// recycled to a common length.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// startsWith(x, prefix)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A `character` vector of strings.
+/// `prefix`: A `character` vector of prefixes.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// startsWith(c("apple", "banana"), "a")
+/// ```
+///
+#[doc(alias = "startsWith")]
+#[builtin(sym = "startsWith")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveStartsWith;
+
+impl Callable for PrimitiveStartsWith {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("prefix".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let prefix = args.try_get_named("prefix")?.force(stack)?;
+        affixed(x, prefix, |s, a| s.starts_with(a))
+    }
+}
+
+/// Test for a String Suffix
+///
+/// Tests whether strings end with a suffix. Strings and suffixes are
+/// recycled to a common length.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// endsWith(x, suffix)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A `character` vector of strings.
+/// `suffix`: A `character` vector of suffixes.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// endsWith(c("data.csv", "notes.txt"), ".csv")
+/// ```
+///
+#[doc(alias = "endsWith")]
+#[builtin(sym = "endsWith")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveEndsWith;
+
+impl Callable for PrimitiveEndsWith {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("suffix".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let suffix = args.try_get_named("suffix")?.force(stack)?;
+        affixed(x, suffix, |s, a| s.ends_with(a))
+    }
+}
+
+/// Pad a string to a width, justified to the left, right or centre
+fn pad(x: &str, width: usize, how: &str) -> String {
+    let pad = width.saturating_sub(x.chars().count());
+    match how {
+        "right" => format!("{}{x}", " ".repeat(pad)),
+        "centre" => format!("{}{x}{}", " ".repeat(pad / 2), " ".repeat(pad - pad / 2)),
+        _ => format!("{x}{}", " ".repeat(pad)),
+    }
+}
+
+/// Format a double with at least some number of decimal places
+fn with_decimals(x: f64, nsmall: usize) -> String {
+    let s = x.to_string();
+    if !x.is_finite() || s.contains('e') {
+        return s;
+    }
+
+    let decimals = s.split_once('.').map_or(0, |(_, d)| d.len());
+    match decimals < nsmall {
+        true => format!("{x:.nsmall$}"),
+        false => s,
+    }
+}
+
+/// Format Values as Strings
+///
+/// Formats the values of an atomic vector as strings of a common width.
+/// Numbers are justified to the right, while strings are justified as
+/// requested. Names and dimensions are kept.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// format(x, nsmall = 0L, width = 0L, justify = "left")
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An atomic vector.
+/// `nsmall`: The minimum number of decimal places of `double`s.
+/// `width`: The minimum width of formatted values.
+/// `justify`: How to justify strings, one of `"left"`, `"right"` or
+///   `"centre"`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// format(c(1, 10, 100))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// format(3.14159, nsmall = 2)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// format("a", width = 5, justify = "right")
+/// ```
+///
+#[doc(alias = "format")]
+#[builtin(sym = "format")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveFormat;
+
+impl Callable for PrimitiveFormat {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("nsmall".to_string()), Expr::Integer(0)),
+            (Some("width".to_string()), Expr::Integer(0)),
+            (
+                Some("justify".to_string()),
+                Expr::String("left".to_string()),
+            ),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let nsmall = args
+            .try_get_named("nsmall")?
+            .force(stack)?
+            .into_usize()
+            .unwrap_or(0);
+        let width = args
+            .try_get_named("width")?
+            .force(stack)?
+            .into_usize()
+            .unwrap_or(0);
+        let justify = string(args.try_get_named("justify")?.force(stack)?, "justify")?;
+
+        let (values, how): (Vec<String>, &str) = match &x {
+            Obj::Vector(Vector::Double(v)) => (
+                v.iter()
+                    .map(|xi| match xi {
+                        OptionNA::Some(xi) => with_decimals(xi, nsmall),
+                        OptionNA::NA => "NA".to_string(),
+                    })
+                    .collect(),
+                "right",
+            ),
+            Obj::Vector(Vector::Character(v)) => (
+                v.iter().map(|xi| xi.to_string()).collect(),
+                justify.as_str(),
+            ),
+            Obj::Vector(v) => (
+                characters(Obj::Vector(v.clone()), "x")?
+                    .into_iter()
+                    .map(|xi| xi.to_string())
+                    .collect(),
+                "right",
+            ),
+            Obj::Null => return Ok(Obj::Vector(Vector::from(Vec::<Character>::new()))),
+            _ => return Error::Other("cannot format this object".into()).into(),
+        };
+
+        let width = values
+            .iter()
+            .map(|v| v.chars().count())
+            .fold(width, std::cmp::max);
+
+        let result: Vec<String> = values.iter().map(|v| pad(v, width, how)).collect();
+        let result = Vector::from(result);
+        for name in ["names", "dim"] {
+            if let Some(value) = x.get_attr(name) {
+                result.attributes().set(name, value);
+            }
+        }
+
+        Ok(Obj::Vector(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn nchar_counts() {
+        assert_eq!(
+            r! { nchar(c("apple", "", NA, "héllo")) },
+            r! { c(5L, 0L, NA, 5L) }
+        );
+        assert_eq!(r! { nchar("héllo", type = "bytes") }, r! { 6L });
+        assert_eq!(r! { nchar(123) }, r! { 3L })
+    }
+
+    #[test]
+    fn substr_positions() {
+        assert_eq!(r! { substr("abcdef", 2, 4) }, r! { "bcd" });
+        assert_eq!(
+            r! { substr(c("abc", "defg", NA), 0, c(2, 10)) },
+            r! { c("ab", "defg", NA) }
+        );
+        assert_eq!(r! { substr("abc", 3, 2) }, r! { "" })
+    }
+
+    #[test]
+    fn substring_recycles() {
+        assert_eq!(
+            r! { substring("abcdef", 1:3, 3:5) },
+            r! { c("abc", "bcd", "cde") }
+        );
+        assert_eq!(r! { substring("abcdef", 4) }, r! { "def" })
+    }
+
+    #[test]
+    fn case_conversion() {
+        assert_eq!(
+            r! { toupper(c(a = "abc", b = NA)) },
+            r! { c(a = "ABC", b = NA) }
+        );
+        assert_eq!(r! { tolower("AbC") }, r! { "abc" })
+    }
+
+    #[test]
+    fn trimws_sides() {
+        assert_eq!(r! {{ "trimws(\" \t x \n\")" }}, r! { "x" });
+        assert_eq!(r! { trimws("  x  ", which = "left") }, r! { "x  " });
+        assert_eq!(r! { trimws("  x  ", which = "right") }, r! { "  x" })
+    }
+
+    #[test]
+    fn trimws_escaped_whitespace() {
+        assert_eq!(r! {{ r#"trimws("  padded\t")"# }}, r! { "padded" })
+    }
+
+    #[test]
+    fn strsplit_pieces() {
+        assert_eq!(
+            r! { strsplit(c("a,b,,c,", NA, ""), ",") },
+            r! { list(c("a", "b", "", "c"), c("x", NA)[2], c("x")[0]) }
+        );
+        assert_eq!(r! { strsplit("abc", "") }, r! { list(c("a", "b", "c")) })
+    }
+
+    #[test]
+    fn strsplit_patterns() {
+        assert_eq!(
+            r! { strsplit("a1b22c", "[0-9]+") },
+            r! { list(c("a", "b", "c")) }
+        );
+        assert_eq!(
+            r! { strsplit("a.b.c", ".", fixed = true) },
+            r! { list(c("a", "b", "c")) }
+        )
+    }
+
+    #[test]
+    fn strsplit_requires_characters() {
+        assert!(r! { strsplit(1, "") }.is_err())
+    }
+
+    #[test]
+    fn affixes() {
+        assert_eq!(
+            r! { startsWith(c("apple", "banana", NA), "a") },
+            r! { c(true, false, NA) }
+        );
+        assert_eq!(
+            r! { endsWith("data.csv", c(".csv", ".txt")) },
+            r! { c(true, false) }
+        )
+    }
+
+    #[test]
+    fn format_widths() {
+        assert_eq!(r! { format(c(1, 10, 100)) }, r! { c("  1", " 10", "100") });
+        assert_eq!(r! { format(3.14159, nsmall = 2) }, r! { "3.14159" });
+        assert_eq!(r! { format(2, nsmall = 2) }, r! { "2.00" });
+        assert_eq!(r! { format(c("a", "bbb")) }, r! { c("a  ", "bbb") });
+        assert_eq!(
+            r! { format("a", width = 3, justify = "right") },
+            r! { "  a" }
+        )
+    }
+}
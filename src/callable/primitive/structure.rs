@@ -0,0 +1,87 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Set Attributes of an Object
+///
+/// Produce an object with attributes set by name.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// structure(.Data, ...)
+/// ```
+///
+/// ## Arguments
+///
+/// `.Data`: An object whose attributes to set.
+/// `...`: Named attribute values. `NULL` values remove an attribute.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// structure(1:3, names = c("a", "b", "c"))
+/// ```
+///
+#[doc(alias = "structure")]
+#[builtin(sym = "structure")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveStructure;
+
+impl Callable for PrimitiveStructure {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some(".Data".to_string()), Expr::Missing),
+            (None, Expr::Ellipsis(None)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut x = Obj::List(args).try_get_named(".Data")?.force(stack)?;
+
+        for (name, value) in force_promises(ellipsis, stack)? {
+            let Some(name) = name else {
+                return Error::Other("attributes must be named".into()).into();
+            };
+
+            x = x.set_attr(&name, value)?;
+        }
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn set_names() {
+        assert_eq!(
+            r! { names(structure(1:2, names = c("a", "b"))) },
+            r! { c("a", "b") }
+        )
+    }
+
+    #[test]
+    fn set_attributes() {
+        r_expect! {{r#"
+            x <- structure(1:2, units = "cm", scale = 10)
+            attr(x, "units") == "cm" & attr(x, "scale") == 10
+        "#}}
+    }
+
+    #[test]
+    fn unnamed_attributes() {
+        assert_eq!(
+            r! { structure(1:2, "cm") },
+            Error::Other("attributes must be named".to_string()).into()
+        )
+    }
+}
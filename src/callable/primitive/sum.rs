@@ -1,8 +1,7 @@
 use r_derive::*;
 
+use super::summary::{gather, Numbers};
 use crate::callable::core::*;
-use crate::error::*;
-use crate::internal_err;
 use crate::lang::*;
 use crate::object::reptype::RepType;
 use crate::object::*;
@@ -14,12 +13,13 @@ use crate::object::*;
 /// ## Usage
 ///
 /// ```custom,{class=r}
-/// sum(...)
+/// sum(..., na.rm = false)
 /// ```
 ///
 /// ## Arguments
 ///
 /// `...`: Objects that can be coerced into numerics.
+/// `na.rm`: Whether to remove missing values.
 ///
 /// ## Examples
 ///
@@ -34,128 +34,51 @@ pub struct PrimitiveSum;
 
 impl Callable for PrimitiveSum {
     fn formals(&self) -> ExprList {
-        ExprList::from(vec![(None, Expr::Ellipsis(None))])
+        ExprList::from(vec![
+            (None, Expr::Ellipsis(None)),
+            (Some("na.rm".to_string()), Expr::Bool(false)),
+        ])
     }
 
-    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
-        let (_, ellipsis) = self.match_arg_exprs(args, stack)?;
-
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
         if ellipsis.is_empty() {
             return EvalResult::Ok(Obj::Vector(Vector::from(RepType::from(vec![0.0]))));
         }
 
+        let na_rm: bool = Obj::List(args)
+            .try_get_named("na.rm")?
+            .force(stack)?
+            .try_into()?;
         let objects: Vec<Obj> = force_promises(ellipsis, stack)?
             .into_iter()
             .map(|(_, value)| value)
             .collect();
 
-        let mut any_double: bool = false;
-
-        for obj in &objects {
-            match obj {
-                Obj::Vector(Vector::Double(..)) => {
-                    any_double = true;
-                    break;
-                }
-                Obj::Vector(Vector::Logical(..)) | Obj::Vector(Vector::Integer(..)) => {
-                    continue;
-                }
-                _ => {
-                    return EvalResult::Err(Signal::Error(Error::Other(String::from(
-                        "All inputs must be of type numeric, integer or logical.",
-                    ))))
-                }
+        match gather(objects, na_rm)? {
+            Numbers::Double(Some(x)) => {
+                let sum: f64 = x.iter().sum();
+                EvalResult::Ok(Obj::Vector(Vector::from(RepType::from(vec![sum]))))
             }
-        }
-
-        if any_double {
-            let mut sum: f64 = 0.0;
-
-            for obj in objects {
-                match obj {
-                    Obj::Vector(vect) => {
-                        match vect {
-                            Vector::Logical(repr) => {
-                                for x in repr.inner().borrow().iter() {
-                                    match *x {
-                                        OptionNA::NA => {
-                                            let rep: RepType<OptionNA<f64>> =
-                                                RepType::from(vec![OptionNA::NA]);
-                                            return EvalResult::Ok(Obj::Vector(Vector::from(rep)));
-                                        }
-                                        OptionNA::Some(x) => sum += x as i32 as f64,
-                                    }
-                                }
-                            }
-                            Vector::Integer(repr) => {
-                                for x in repr.inner().borrow().iter() {
-                                    match *x {
-                                        OptionNA::NA => {
-                                            let rep: RepType<OptionNA<f64>> =
-                                                RepType::from(vec![OptionNA::NA]);
-                                            return EvalResult::Ok(Obj::Vector(Vector::from(rep)));
-                                        }
-                                        OptionNA::Some(x) => sum += x as f64,
-                                    }
-                                }
-                            }
-                            Vector::Double(repr) => {
-                                for x in repr.inner().borrow().iter() {
-                                    match *x {
-                                        OptionNA::NA => {
-                                            let rep: RepType<OptionNA<f64>> =
-                                                RepType::from(vec![OptionNA::NA]);
-                                            return EvalResult::Ok(Obj::Vector(Vector::from(rep)));
-                                        }
-                                        OptionNA::Some(x) => sum += x,
-                                    }
-                                }
-                            }
-                            _ => return internal_err!(),
-                        };
-                    }
-                    _ => return internal_err!(),
-                }
+            Numbers::Double(None) => {
+                let rep: RepType<OptionNA<f64>> = RepType::from(vec![OptionNA::NA]);
+                EvalResult::Ok(Obj::Vector(Vector::from(rep)))
             }
-            EvalResult::Ok(Obj::Vector(Vector::from(RepType::from(vec![sum]))))
-        } else {
-            let mut sum: i32 = 0;
-
-            for obj in objects {
-                match obj {
-                    Obj::Vector(vect) => {
-                        match vect {
-                            Vector::Logical(repr) => {
-                                for x in repr.inner().borrow().iter() {
-                                    match *x {
-                                        OptionNA::NA => {
-                                            let rep: RepType<OptionNA<i32>> =
-                                                RepType::from(vec![OptionNA::NA]);
-                                            return EvalResult::Ok(Obj::Vector(Vector::from(rep)));
-                                        }
-                                        OptionNA::Some(x) => sum += x as i32,
-                                    }
-                                }
-                            }
-                            Vector::Integer(repr) => {
-                                for x in repr.inner().borrow().iter() {
-                                    match *x {
-                                        OptionNA::NA => {
-                                            let rep: RepType<OptionNA<i32>> =
-                                                RepType::from(vec![OptionNA::NA]);
-                                            return EvalResult::Ok(Obj::Vector(Vector::from(rep)));
-                                        }
-                                        OptionNA::Some(x) => sum += x,
-                                    }
-                                }
-                            }
-                            _ => return internal_err!(),
-                        };
+            Numbers::Integer(Some(x)) => {
+                let sum = x.iter().try_fold(0i32, |sum, &xi| sum.checked_add(xi));
+                let sum = match sum {
+                    Some(sum) => OptionNA::Some(sum),
+                    None => {
+                        stack.warn("integer overflow - use sum(as.numeric(.))".to_string())?;
+                        OptionNA::NA
                     }
-                    _ => return internal_err!(),
-                }
+                };
+
+                EvalResult::Ok(Obj::Vector(Vector::from(RepType::from(vec![sum]))))
+            }
+            Numbers::Integer(None) => {
+                let rep: RepType<OptionNA<i32>> = RepType::from(vec![OptionNA::NA]);
+                EvalResult::Ok(Obj::Vector(Vector::from(rep)))
             }
-            EvalResult::Ok(Obj::Vector(Vector::from(RepType::from(vec![sum]))))
         }
     }
 }
@@ -194,6 +117,12 @@ mod tests {
         assert!((r! {sum(null)}).is_err());
     }
 
+    #[test]
+    fn sum_na_rm() {
+        assert_eq!(r! {sum(c(1, NA, 2), na.rm = true)}, r! {3});
+        assert_eq!(r! {sum(c(1L, NA), 2L, na.rm = true)}, r! {3L});
+    }
+
     #[test]
     fn sum_double() {
         assert_eq!(r! {{"sum(c(1, 2), c(3, 4))"}}, r! {{"10"}})
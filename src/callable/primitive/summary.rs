@@ -0,0 +1,967 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::error::*;
+use crate::internal_err;
+use crate::lang::*;
+use crate::object::types::*;
+use crate::object::*;
+
+/// The numeric values of the arguments of a summary function
+///
+/// Values are `None` when any value is missing and missing values are not
+/// removed.
+///
+pub enum Numbers {
+    Integer(Option<Vec<i32>>),
+    Double(Option<Vec<f64>>),
+}
+
+/// Gather the values of numeric arguments
+///
+/// Values are gathered as `integer`s, counting `logical`s as `integer`s,
+/// unless any argument is a `double`. With `na_rm`, missing values
+/// (including `NaN`s) are removed.
+///
+pub fn gather(objects: Vec<Obj>, na_rm: bool) -> Result<Numbers, Signal> {
+    let mut any_double: bool = false;
+
+    for obj in &objects {
+        match obj {
+            Obj::Vector(Vector::Double(..)) => any_double = true,
+            Obj::Vector(Vector::Logical(..)) | Obj::Vector(Vector::Integer(..)) => continue,
+            _ => {
+                return Error::Other(
+                    "All inputs must be of type numeric, integer or logical.".into(),
+                )
+                .into()
+            }
+        }
+    }
+
+    if any_double {
+        let mut values: Vec<f64> = vec![];
+        for obj in objects {
+            let Obj::Vector(Vector::Double(v)) = obj.as_double()? else {
+                return internal_err!();
+            };
+
+            for x in v.iter() {
+                match x {
+                    OptionNA::Some(x) if na_rm && x.is_nan() => (),
+                    OptionNA::Some(x) => values.push(x),
+                    OptionNA::NA if na_rm => (),
+                    OptionNA::NA => return Ok(Numbers::Double(None)),
+                }
+            }
+        }
+
+        Ok(Numbers::Double(Some(values)))
+    } else {
+        let mut values: Vec<i32> = vec![];
+        for obj in objects {
+            let Obj::Vector(Vector::Integer(v)) = obj.as_integer()? else {
+                return internal_err!();
+            };
+
+            for x in v.iter() {
+                match x {
+                    OptionNA::Some(x) => values.push(x),
+                    OptionNA::NA if na_rm => (),
+                    OptionNA::NA => return Ok(Numbers::Integer(None)),
+                }
+            }
+        }
+
+        Ok(Numbers::Integer(Some(values)))
+    }
+}
+
+/// Force the arguments and `na.rm` flag of a summary function of `...`
+fn summary_args(args: List, ellipsis: List, stack: &mut CallStack) -> Result<Numbers, Signal> {
+    let na_rm: bool = Obj::List(args)
+        .try_get_named("na.rm")?
+        .force(stack)?
+        .try_into()?;
+    let objects = force_promises(ellipsis, stack)?;
+    gather(objects.into_iter().map(|(_, v)| v).collect(), na_rm)
+}
+
+/// Force the `x` argument and `na.rm` flag of a summary function of `x`
+fn summary_arg(args: List, stack: &mut CallStack) -> Result<Numbers, Signal> {
+    let mut args = Obj::List(args);
+    let x = args.try_get_named("x")?.force(stack)?;
+    let na_rm: bool = args.try_get_named("na.rm")?.force(stack)?.try_into()?;
+    gather(vec![x], na_rm)
+}
+
+fn summary_formals(first: Option<&str>) -> ExprList {
+    let first = match first {
+        Some(name) => (Some(name.to_string()), Expr::Missing),
+        None => (None, Expr::Ellipsis(None)),
+    };
+
+    ExprList::from(vec![first, (Some("na.rm".to_string()), Expr::Bool(false))])
+}
+
+fn double(x: Vec<Double>) -> EvalResult {
+    Ok(Obj::Vector(Vector::from(x)))
+}
+
+fn integer(x: Vec<Integer>) -> EvalResult {
+    Ok(Obj::Vector(Vector::from(x)))
+}
+
+/// The mean of values, refined by a second pass over their deviations
+fn mean_of(x: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let mean = x.iter().sum::<f64>() / n;
+    mean + x.iter().map(|xi| xi - mean).sum::<f64>() / n
+}
+
+/// The sample variance of values
+fn var_of(x: &[f64]) -> Double {
+    match x.len() {
+        0 | 1 => OptionNA::NA,
+        n => {
+            let mean = mean_of(x);
+            let ss: f64 = x.iter().map(|xi| (xi - mean).powi(2)).sum();
+            OptionNA::Some(ss / (n - 1) as f64)
+        }
+    }
+}
+
+/// The extreme of values, preferring `NaN`s over any other value
+fn extreme_of(x: &[f64], max: bool) -> f64 {
+    x.iter().fold(
+        if max {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        },
+        |acc, &xi| match (acc, xi) {
+            (acc, _) if acc.is_nan() => acc,
+            (_, xi) if xi.is_nan() => xi,
+            (acc, xi) if max => acc.max(xi),
+            (acc, xi) => acc.min(xi),
+        },
+    )
+}
+
+/// The minimum or maximum of the values of a summary function
+///
+/// Without any values, warns and produces `Inf` or `-Inf`.
+///
+fn extreme(numbers: Numbers, max: bool, stack: &mut CallStack) -> Result<Vector, Signal> {
+    let x = match numbers {
+        Numbers::Integer(None) => return Ok(Vector::from(vec![OptionNA::<i32>::NA])),
+        Numbers::Double(None) => return Ok(Vector::from(vec![OptionNA::<f64>::NA])),
+        Numbers::Integer(Some(x)) if !x.is_empty() => {
+            let value = if max { x.iter().max() } else { x.iter().min() };
+            return Ok(Vector::from(
+                value.into_iter().copied().collect::<Vec<i32>>(),
+            ));
+        }
+        Numbers::Integer(Some(_)) => vec![],
+        Numbers::Double(Some(x)) => x,
+    };
+
+    if x.is_empty() {
+        let (name, value) = if max { ("max", "-Inf") } else { ("min", "Inf") };
+        stack.warn(format!(
+            "no non-missing arguments to {name}; returning {value}"
+        ))?;
+    }
+
+    Ok(Vector::from(vec![extreme_of(&x, max)]))
+}
+
+/// Sort values, which must not be `NaN`
+fn sorted(mut x: Vec<f64>) -> Vec<f64> {
+    x.sort_by(|a, b| a.total_cmp(b));
+    x
+}
+
+/// The type 7 quantile of sorted values, interpolating between the values
+/// closest to a probability
+fn quantile_of(x: &[f64], p: f64) -> f64 {
+    let h = (x.len() - 1) as f64 * p;
+    let (lo, hi) = (h.floor() as usize, h.ceil() as usize);
+    x[lo] + (h - h.floor()) * (x[hi] - x[lo])
+}
+
+/// Accumulate the values of a numeric vector
+///
+/// `integer` and `logical` vectors are accumulated as `integer`s when given
+/// an `integer` operation, producing `NA` on overflow. Missing values
+/// propagate to all later values and only the names of the vector are kept.
+///
+fn cumulate<I, D>(x: Obj, name: &str, stack: &mut CallStack, int: Option<I>, dbl: D) -> EvalResult
+where
+    I: Fn(i32, i32) -> Option<i32>,
+    D: Fn(f64, f64) -> f64,
+{
+    let Obj::Vector(v) = x else {
+        return Error::Other("All inputs must be of type numeric, integer or logical.".into())
+            .into();
+    };
+
+    let names = v.attributes().get("names");
+    let result = match (v, int) {
+        (v @ (Vector::Integer(_) | Vector::Logical(_)), Some(f)) => {
+            let Vector::Integer(v) = v.as_integer() else {
+                return internal_err!();
+            };
+
+            let mut overflow = false;
+            let mut acc: Option<Integer> = None;
+            let values: Vec<Integer> = v
+                .iter()
+                .map(|x| {
+                    let next = match (acc.clone(), x) {
+                        (Some(OptionNA::NA), _) | (_, OptionNA::NA) => OptionNA::NA,
+                        (None, x) => x,
+                        (Some(OptionNA::Some(a)), OptionNA::Some(x)) => match f(a, x) {
+                            Some(y) => OptionNA::Some(y),
+                            None => {
+                                overflow = true;
+                                OptionNA::NA
+                            }
+                        },
+                    };
+
+                    acc = Some(next.clone());
+                    next
+                })
+                .collect();
+
+            if overflow {
+                stack.warn(format!(
+                    "integer overflow in '{name}'; use '{name}(as.numeric(.))'"
+                ))?;
+            }
+
+            Vector::from(values)
+        }
+        (v @ (Vector::Double(_) | Vector::Integer(_) | Vector::Logical(_)), _) => {
+            let Vector::Double(v) = v.as_double() else {
+                return internal_err!();
+            };
+
+            let mut acc: Option<Double> = None;
+            let values: Vec<Double> = v
+                .iter()
+                .map(|x| {
+                    let next = match (acc.clone(), x) {
+                        (Some(OptionNA::NA), _) | (_, OptionNA::NA) => OptionNA::NA,
+                        (None, x) => x,
+                        (Some(OptionNA::Some(a)), OptionNA::Some(x)) => OptionNA::Some(dbl(a, x)),
+                    };
+
+                    acc = Some(next.clone());
+                    next
+                })
+                .collect();
+
+            Vector::from(values)
+        }
+        _ => {
+            return Error::Other("All inputs must be of type numeric, integer or logical.".into())
+                .into()
+        }
+    };
+
+    if let Some(names) = names {
+        result.attributes().set("names", names);
+    }
+
+    Ok(Obj::Vector(result))
+}
+
+/// Product of Elements
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// prod(..., na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects that can be coerced into numerics.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// prod(1:5)
+/// ```
+///
+#[doc(alias = "prod")]
+#[builtin(sym = "prod")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveProd;
+
+impl Callable for PrimitiveProd {
+    fn formals(&self) -> ExprList {
+        summary_formals(None)
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        match summary_args(args, ellipsis, stack)? {
+            Numbers::Integer(Some(x)) => {
+                double(vec![OptionNA::Some(x.iter().map(|&i| i as f64).product())])
+            }
+            Numbers::Double(Some(x)) => double(vec![OptionNA::Some(x.iter().product())]),
+            _ => double(vec![OptionNA::NA]),
+        }
+    }
+}
+
+/// Maximum of Elements
+///
+/// The largest value among all arguments. The result is an `integer` when
+/// all arguments are `integer` or `logical`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// max(..., na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects that can be coerced into numerics.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// max(c(3, 1, 2), 5)
+/// ```
+///
+#[doc(alias = "max")]
+#[builtin(sym = "max")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMax;
+
+impl Callable for PrimitiveMax {
+    fn formals(&self) -> ExprList {
+        summary_formals(None)
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let numbers = summary_args(args, ellipsis, stack)?;
+        Ok(Obj::Vector(extreme(numbers, true, stack)?))
+    }
+}
+
+/// Minimum of Elements
+///
+/// The smallest value among all arguments. The result is an `integer` when
+/// all arguments are `integer` or `logical`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// min(..., na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects that can be coerced into numerics.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// min(c(3, 1, 2), 5)
+/// ```
+///
+#[doc(alias = "min")]
+#[builtin(sym = "min")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMin;
+
+impl Callable for PrimitiveMin {
+    fn formals(&self) -> ExprList {
+        summary_formals(None)
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let numbers = summary_args(args, ellipsis, stack)?;
+        Ok(Obj::Vector(extreme(numbers, false, stack)?))
+    }
+}
+
+/// Range of Elements
+///
+/// The smallest and largest values among all arguments.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// range(..., na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects that can be coerced into numerics.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// range(c(3, 1, 2), 5)
+/// ```
+///
+#[doc(alias = "range")]
+#[builtin(sym = "range")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRange;
+
+impl Callable for PrimitiveRange {
+    fn formals(&self) -> ExprList {
+        summary_formals(None)
+    }
+
+    fn call_matched(&self, args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let (min, max) = match summary_args(args, ellipsis, stack)? {
+            Numbers::Integer(x) => (Numbers::Integer(x.clone()), Numbers::Integer(x)),
+            Numbers::Double(x) => (Numbers::Double(x.clone()), Numbers::Double(x)),
+        };
+
+        let min = Obj::Vector(extreme(min, false, stack)?);
+        let max = Obj::Vector(extreme(max, true, stack)?);
+        super::c::combine(vec![min, max], stack)
+    }
+}
+
+/// Arithmetic Mean
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// mean(x, na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// mean(c(1, 2, 6))
+/// ```
+///
+#[doc(alias = "mean")]
+#[builtin(sym = "mean")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMean;
+
+impl Callable for PrimitiveMean {
+    fn formals(&self) -> ExprList {
+        summary_formals(Some("x"))
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        match summary_arg(args, stack)? {
+            Numbers::Integer(Some(x)) => {
+                let x: Vec<f64> = x.into_iter().map(|i| i as f64).collect();
+                double(vec![OptionNA::Some(mean_of(&x))])
+            }
+            Numbers::Double(Some(x)) => double(vec![OptionNA::Some(mean_of(&x))]),
+            _ => double(vec![OptionNA::NA]),
+        }
+    }
+}
+
+/// Variance
+///
+/// The sample variance of a numeric vector, `NA` for fewer than two values.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// var(x, na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// var(c(1, 2, 3, 4))
+/// ```
+///
+#[doc(alias = "var")]
+#[builtin(sym = "var")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveVar;
+
+impl Callable for PrimitiveVar {
+    fn formals(&self) -> ExprList {
+        summary_formals(Some("x"))
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        match summary_arg(args, stack)? {
+            Numbers::Integer(Some(x)) => {
+                let x: Vec<f64> = x.into_iter().map(|i| i as f64).collect();
+                double(vec![var_of(&x)])
+            }
+            Numbers::Double(Some(x)) => double(vec![var_of(&x)]),
+            _ => double(vec![OptionNA::NA]),
+        }
+    }
+}
+
+/// Standard Deviation
+///
+/// The sample standard deviation of a numeric vector, `NA` for fewer than
+/// two values.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// sd(x, na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// sd(c(2, 4, 4, 4, 5, 5, 7, 9))
+/// ```
+///
+#[doc(alias = "sd")]
+#[builtin(sym = "sd")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveSd;
+
+impl Callable for PrimitiveSd {
+    fn formals(&self) -> ExprList {
+        summary_formals(Some("x"))
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let var = match summary_arg(args, stack)? {
+            Numbers::Integer(Some(x)) => {
+                let x: Vec<f64> = x.into_iter().map(|i| i as f64).collect();
+                var_of(&x)
+            }
+            Numbers::Double(Some(x)) => var_of(&x),
+            _ => OptionNA::NA,
+        };
+
+        double(vec![var.map(f64::sqrt)])
+    }
+}
+
+/// Median Value
+///
+/// The middle value of a numeric vector, or the mean of the two middle
+/// values for vectors of even length. The median of an odd number of
+/// `integer`s is an `integer`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// median(x, na.rm = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `na.rm`: Whether to remove missing values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// median(c(5, 3, 1, 4))
+/// ```
+///
+#[doc(alias = "median")]
+#[builtin(sym = "median")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMedian;
+
+impl Callable for PrimitiveMedian {
+    fn formals(&self) -> ExprList {
+        summary_formals(Some("x"))
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        match summary_arg(args, stack)? {
+            Numbers::Integer(None) => integer(vec![OptionNA::NA]),
+            Numbers::Integer(Some(mut x)) if x.len() % 2 == 1 => {
+                x.sort();
+                integer(vec![OptionNA::Some(x[x.len() / 2])])
+            }
+            Numbers::Integer(Some(x)) => {
+                let x: Vec<f64> = x.into_iter().map(|i| i as f64).collect();
+                double(vec![median_of(x)])
+            }
+            Numbers::Double(Some(x)) => double(vec![median_of(x)]),
+            Numbers::Double(None) => double(vec![OptionNA::NA]),
+        }
+    }
+}
+
+/// The median of values, `NA` for no values
+fn median_of(x: Vec<f64>) -> Double {
+    if x.is_empty() {
+        return OptionNA::NA;
+    }
+
+    if x.iter().any(|xi| xi.is_nan()) {
+        return OptionNA::Some(f64::NAN);
+    }
+
+    OptionNA::Some(quantile_of(&sorted(x), 0.5))
+}
+
+/// Sample Quantiles
+///
+/// Quantiles of a numeric vector, interpolating linearly between the
+/// closest values (R's type 7 quantiles).
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// quantile(x, probs = c(0, 0.25, 0.5, 0.75, 1), na.rm = false, names = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector.
+/// `probs`: Probabilities in `[0, 1]` for which to find quantiles.
+/// `na.rm`: Whether to remove missing values. Otherwise, missing values
+///   are an error.
+/// `names`: Whether to name quantiles by their probabilities.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// quantile(1:10)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// quantile(c(1, 5, 2, 8), probs = 0.9)
+/// ```
+///
+#[doc(alias = "quantile")]
+#[builtin(sym = "quantile")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveQuantile;
+
+impl Callable for PrimitiveQuantile {
+    fn formals(&self) -> ExprList {
+        let probs = [0.0, 0.25, 0.5, 0.75, 1.0].map(|p| (None, Expr::Number(p)));
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (
+                Some("probs".to_string()),
+                Expr::Call(
+                    Box::new(Expr::Symbol("c".to_string())),
+                    ExprList::from_iter(probs),
+                ),
+            ),
+            (Some("na.rm".to_string()), Expr::Bool(false)),
+            (Some("names".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let probs: Vec<f64> = args.try_get_named("probs")?.force(stack)?.try_into()?;
+        let na_rm: bool = args.try_get_named("na.rm")?.force(stack)?.try_into()?;
+        let names: bool = args.try_get_named("names")?.force(stack)?.try_into()?;
+
+        if probs.iter().any(|p| !(0.0..=1.0).contains(p)) {
+            return Error::Other("'probs' outside [0,1]".into()).into();
+        }
+
+        let x = match gather(vec![x], na_rm)? {
+            Numbers::Integer(Some(x)) => x.into_iter().map(|i| i as f64).collect(),
+            Numbers::Double(Some(x)) if !x.iter().any(|xi| xi.is_nan()) => x,
+            _ => {
+                let msg = "missing values and NaN's not allowed if 'na.rm' is FALSE";
+                return Error::Other(msg.into()).into();
+            }
+        };
+
+        let x = sorted(x);
+        let values: Vec<Double> = probs
+            .iter()
+            .map(|&p| match x.is_empty() {
+                true => OptionNA::NA,
+                false => OptionNA::Some(quantile_of(&x, p)),
+            })
+            .collect();
+
+        let result = Vector::from(values);
+        if names {
+            let names: Vec<String> = probs
+                .iter()
+                .map(|p| format!("{}%", (p * 100.0 * 1e7).round() / 1e7))
+                .collect();
+            result
+                .attributes()
+                .set("names", Obj::Vector(Vector::from(names)));
+        }
+
+        Ok(Obj::Vector(result))
+    }
+}
+
+/// Cumulative Sums
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// cumsum(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector. Once a value is missing, all later sums are
+///   missing.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// cumsum(1:5)
+/// ```
+///
+#[doc(alias = "cumsum")]
+#[builtin(sym = "cumsum")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCumsum;
+
+impl Callable for PrimitiveCumsum {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        cumulate(x, "cumsum", stack, Some(i32::checked_add), |a, b| a + b)
+    }
+}
+
+/// Cumulative Products
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// cumprod(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector. Once a value is missing, all later products are
+///   missing.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// cumprod(1:5)
+/// ```
+///
+#[doc(alias = "cumprod")]
+#[builtin(sym = "cumprod")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCumprod;
+
+impl Callable for PrimitiveCumprod {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let int: Option<fn(i32, i32) -> Option<i32>> = None;
+        cumulate(x, "cumprod", stack, int, |a, b| a * b)
+    }
+}
+
+/// Cumulative Maxima
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// cummax(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector. Once a value is missing, all later maxima are
+///   missing.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// cummax(c(1, 3, 2, 5, 4))
+/// ```
+///
+#[doc(alias = "cummax")]
+#[builtin(sym = "cummax")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCummax;
+
+impl Callable for PrimitiveCummax {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let max = |a: i32, b: i32| Some(a.max(b));
+        cumulate(x, "cummax", stack, Some(max), |a, b| {
+            extreme_of(&[a, b], true)
+        })
+    }
+}
+
+/// Cumulative Minima
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// cummin(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A numeric vector. Once a value is missing, all later minima are
+///   missing.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// cummin(c(5, 3, 4, 1, 2))
+/// ```
+///
+#[doc(alias = "cummin")]
+#[builtin(sym = "cummin")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveCummin;
+
+impl Callable for PrimitiveCummin {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let min = |a: i32, b: i32| Some(a.min(b));
+        cumulate(x, "cummin", stack, Some(min), |a, b| {
+            extreme_of(&[a, b], false)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn extremes() {
+        assert_eq!(r! { max(c(3L, 1L), 2L) }, r! { 3L });
+        assert_eq!(r! { min(c(3, 1), 2L) }, r! { 1 });
+        assert_eq!(r! { range(c(3, NA, 1), na.rm = true) }, r! { c(1, 3) });
+        assert_eq!(r! { max(c(1, NA)) }, r! { c(1, NA)[2] });
+    }
+
+    #[test]
+    fn empty_extremes() {
+        r_expect! { min(c(NA, 1L)[-2], na.rm = true) > 1000000 }
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(r! { prod(1:5) }, r! { 120 });
+        assert_eq!(r! { prod(c(2, NA, 3), na.rm = true) }, r! { 6 });
+    }
+
+    #[test]
+    fn moments() {
+        assert_eq!(r! { mean(c(1, 2, 6)) }, r! { 3 });
+        assert_eq!(r! { mean(c(1L, NA, 3L), na.rm = true) }, r! { 2 });
+        assert_eq!(r! { var(c(1, 2, 3, 4)) }, r! { 5 / 3 });
+        assert_eq!(r! { sd(c(2, 4, 4, 4, 5, 5, 7, 9)) }, r! { sqrt(32 / 7) });
+        assert_eq!(r! { var(1) }, r! { c(1, NA)[2] });
+    }
+
+    #[test]
+    fn medians() {
+        assert_eq!(r! { median(c(3L, 1L, 2L)) }, r! { 2L });
+        assert_eq!(r! { median(c(5, 3, 1, 4)) }, r! { 3.5 });
+        assert_eq!(r! { median(c(5, NA, 1), na.rm = true) }, r! { 3 });
+    }
+
+    #[test]
+    fn quantiles() {
+        assert_eq!(
+            r! { quantile(1:9) },
+            r! { structure(c(1, 3, 5, 7, 9), names = c("0%", "25%", "50%", "75%", "100%")) }
+        );
+        assert_eq!(
+            r! { quantile(c(1, 5, 2, 8), probs = c(0.5, 1), names = false) },
+            r! { c(3.5, 8) }
+        );
+        assert_eq!(
+            r! { quantile(c(1, NA)) },
+            Error::Other("missing values and NaN's not allowed if 'na.rm' is FALSE".into()).into()
+        );
+    }
+
+    #[test]
+    fn cumulative() {
+        assert_eq!(r! { cumsum(1:4) }, r! { c(1L, 3L, 6L, 10L) });
+        assert_eq!(r! { cumprod(1:4) }, r! { c(1, 2, 6, 24) });
+        assert_eq!(r! { cummax(c(1, 3, NA, 5)) }, r! { c(1, 3, NA, NA) });
+        assert_eq!(
+            r! { cummin(c(a = 5L, b = 3L, c = 4L)) },
+            r! { c(a = 5L, b = 3L, c = 3L) }
+        );
+    }
+}
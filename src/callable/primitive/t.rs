@@ -0,0 +1,84 @@
+use r_derive::*;
+
+use super::matrix::reshape;
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Transpose a Matrix
+///
+/// Swaps the rows and columns of a matrix. Vectors are treated as a matrix
+/// with a single column, such that their transpose is a single row.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// t(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A matrix or vector.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// t(matrix(1:6, nrow = 2))
+/// ```
+///
+#[doc(alias = "t")]
+#[builtin(sym = "t")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveT;
+
+impl Callable for PrimitiveT {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let Obj::Vector(x) = Obj::List(args).try_get_named("x")?.force(stack)? else {
+            return Error::Other("argument is not a matrix".into()).into();
+        };
+
+        let (nrow, ncol) = match x.attributes().dim().as_deref() {
+            Some(&[nrow, ncol]) => (nrow, ncol),
+            None => (x.len(), 1),
+            Some(_) => return Error::Other("argument is not a matrix".into()).into(),
+        };
+
+        // row i, column j of the result is row j, column i of x
+        let indices = (0..nrow * ncol).map(|k| (k % ncol) * nrow + k / ncol);
+        Ok(Obj::Vector(reshape(x, indices, &[ncol, nrow])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn transpose_matrix() {
+        r_expect! {{r#"
+            m <- t(matrix(1:6, nrow = 2))
+            nrow(m) == 3 && ncol(m) == 2 && m[1, 2] == 2 && m[3, 1] == 5
+        "#}}
+    }
+
+    #[test]
+    fn transpose_vector() {
+        assert_eq!(r! { dim(t(1:3)) }, r! { c(1L, 3L) })
+    }
+
+    #[test]
+    fn transpose_array() {
+        assert_eq!(
+            r! { t(array(1:8, c(2, 2, 2))) },
+            Error::Other("argument is not a matrix".to_string()).into()
+        )
+    }
+}
@@ -0,0 +1,226 @@
+use r_derive::*;
+
+use super::{condition, PrimitiveMessage, PrimitiveStop, PrimitiveWarning};
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Whether a call is to a builtin which raises conditions on behalf of
+/// its caller
+fn is_condition_call(call: &Expr) -> bool {
+    let Expr::Call(what, _) = call else {
+        return false;
+    };
+
+    match &**what {
+        Expr::Symbol(name) | Expr::String(name) => {
+            matches!(name.as_str(), "stop" | "warning" | "message")
+        }
+        what => [
+            Expr::as_primitive(PrimitiveStop),
+            Expr::as_primitive(PrimitiveWarning),
+            Expr::as_primitive(PrimitiveMessage),
+        ]
+        .contains(what),
+    }
+}
+
+/// Find the call which raised a condition
+///
+/// Frames are left on the stack as a condition propagates, so the most
+/// recent frame is that of the call which raised it. Conditions raised by
+/// `stop()`, `warning()` and `message()` are instead attributed to the
+/// function call from which they were called. Calls outside of `tryCatch()`,
+/// at frames below `depth`, are not reported.
+///
+fn condition_call(stack: &CallStack, depth: usize) -> Obj {
+    let Some(frame) = stack.frames.last() else {
+        return Obj::Null;
+    };
+
+    let i = match is_condition_call(&frame.call) {
+        true => stack.origin_frame(),
+        false => stack.frames.len() - 1,
+    };
+
+    match i {
+        i if i >= depth => Obj::Expr(stack.frames[i].call.clone()),
+        _ => Obj::Null,
+    }
+}
+
+/// Evaluate an Expression, Handling Conditions
+///
+/// Evaluates an expression, establishing handlers for conditions that are
+/// raised during its evaluation. When a condition is raised for which a
+/// handler was provided, evaluation of `expr` is abandoned and the result
+/// of the handler is returned instead.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// tryCatch(expr, ..., finally)
+/// ```
+///
+/// ## Arguments
+///
+/// `expr`: An expression to evaluate.
+/// `...`: Named condition handlers. Each is a function accepting a single
+///   condition object, a `list` with `message` and `call` elements, classed
+///   as `c("simpleError", "error", "condition")` or the equivalent for
+///   warnings and messages. Handlers may be
+///   provided for `error`, `warning` and `message` conditions, or for any
+///   `condition`.
+/// `finally`: An expression which is evaluated before returning, regardless
+///   of whether a condition was raised.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// tryCatch(stop("oh no"), error = fn(e) paste("caught:", e$message))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// tryCatch(
+///   { warning("careful"); "unreachable" },
+///   warning = fn(w) "handled",
+///   finally = print("done")
+/// )
+/// ```
+///
+#[doc(alias = "tryCatch")]
+#[builtin(sym = "tryCatch")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTryCatch;
+impl Callable for PrimitiveTryCatch {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some(String::from("expr")), Expr::Null),
+            (None, Expr::Ellipsis(None)),
+            (Some(String::from("finally")), Expr::Null),
+        ])
+    }
+
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let (args, ellipsis) = self.match_arg_exprs(args, stack)?;
+        let mut args = Obj::List(args);
+
+        // establish handlers in this call's frame for the duration of `expr`
+        let handlers = force_promises(ellipsis, stack)?;
+        let depth = stack.frames.len();
+        stack.frames[depth - 1].handlers = handlers.iter().filter_map(|(k, _)| k.clone()).collect();
+
+        let result = match args.try_get_named("expr")? {
+            Obj::Promise(_, Expr::Missing, _) => Ok(Obj::Null),
+            expr => expr.force(stack),
+        };
+
+        stack.frames[depth - 1].handlers.clear();
+
+        let raised = match &result {
+            Err(Signal::Error(Error::WithCallStack(e, _))) => Some(("error", e.as_str())),
+            Err(Signal::Error(e)) => Some(("error", e.as_str())),
+            Err(Signal::Condition(Cond::Warning(msg))) => Some(("warning", msg.clone())),
+            Err(Signal::Condition(Cond::Message(msg))) => Some(("message", msg.clone())),
+            _ => None,
+        };
+
+        let handler = raised.and_then(|(class, msg)| {
+            handlers
+                .into_iter()
+                .find(|(k, _)| matches!(k.as_deref(), Some(k) if k == class || k == "condition"))
+                .map(|(_, handler)| (handler, class, msg))
+        });
+
+        let result = match handler {
+            Some((handler, class, msg)) => {
+                let call = condition_call(stack, depth);
+                stack.unwind(depth)?;
+                let condition = condition(class, msg, call)?;
+                stack.call_with(handler, vec![(None, condition)])
+            }
+            None => result,
+        };
+
+        if let Ok(finally) = args.try_get_named("finally") {
+            finally.force(stack)?;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn no_condition() {
+        assert_eq!(r! { tryCatch(1 + 2, error = fn(e) 0) }, r! { 3 })
+    }
+
+    #[test]
+    fn catch_error() {
+        assert_eq!(
+            r! { tryCatch(stop("oh no"), error = fn(e) paste("caught:", e$message)) },
+            r! { "caught: oh no" }
+        )
+    }
+
+    #[test]
+    fn catch_internal_error() {
+        assert_eq!(
+            r! { tryCatch(undefined_var, error = fn(e) e$message) },
+            r! { "object 'undefined_var' not found" }
+        )
+    }
+
+    #[test]
+    fn catch_warning() {
+        r_expect! {{"
+            f <- fn() { warning('careful'); 'unreachable' }
+            tryCatch(f(), warning = fn(w) w$message) == 'careful'
+        "}}
+    }
+
+    #[test]
+    fn catch_builtin_warning() {
+        assert_eq!(r! { tryCatch(log(-1), warning = fn(w) "w") }, r! { "w" })
+    }
+
+    #[test]
+    fn catch_condition() {
+        r_expect! {{"
+            tryCatch(message('hi'), condition = fn(c) c$message) == 'hi'
+        "}}
+    }
+
+    #[test]
+    fn uncaught_error_propagates() {
+        assert_eq!(
+            r! { tryCatch(stop("oh no"), warning = fn(w) 0) },
+            Error::Other("oh no".to_string()).into()
+        )
+    }
+
+    #[test]
+    fn finally_is_evaluated() {
+        r_expect! {{"
+            x <- 1
+            f <- fn() { e <- environment(); tryCatch(stop('!'), error = fn(e) 2, finally = x <- 3); x }
+            f() == 3
+        "}}
+    }
+
+    #[test]
+    fn nested_handlers() {
+        r_expect! {{"
+            inner <- fn() tryCatch(stop('!'), warning = fn(w) 'inner')
+            tryCatch(inner(), error = fn(e) 'outer') == 'outer'
+        "}}
+    }
+}
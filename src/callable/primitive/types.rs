@@ -0,0 +1,586 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// The internal type of an object
+fn type_of(x: &Obj) -> &'static str {
+    match x {
+        Obj::Null => "NULL",
+        Obj::Vector(Vector::Double(_)) => "double",
+        Obj::Vector(Vector::Integer(_)) => "integer",
+        Obj::Vector(Vector::Logical(_)) => "logical",
+        Obj::Vector(Vector::Character(_)) => "character",
+        Obj::List(_) => "list",
+        Obj::Function(_, Expr::Primitive(_), _) => "builtin",
+        Obj::Function(..) => "closure",
+        Obj::Environment(_) => "environment",
+        Obj::Expr(Expr::Symbol(_)) => "symbol",
+        Obj::Expr(_) | Obj::Formula(..) => "language",
+        Obj::Promise(..) => "promise",
+    }
+}
+
+/// The storage mode of an object
+pub fn mode_of(x: &Obj) -> &'static str {
+    match x {
+        Obj::Vector(Vector::Double(_) | Vector::Integer(_)) => "numeric",
+        Obj::Function(..) => "function",
+        Obj::Expr(Expr::Symbol(_)) => "name",
+        Obj::Expr(_) | Obj::Formula(..) => "call",
+        x => type_of(x),
+    }
+}
+
+/// Whether each value of a vector is missing, including `NaN`s
+pub fn missing(v: &Vector) -> Vec<bool> {
+    match v {
+        Vector::Double(v) => v
+            .iter()
+            .map(|x| match x {
+                OptionNA::Some(x) => x.is_nan(),
+                OptionNA::NA => true,
+            })
+            .collect(),
+        Vector::Integer(v) => v.iter().map(|x| matches!(x, OptionNA::NA)).collect(),
+        Vector::Logical(v) => v.iter().map(|x| matches!(x, OptionNA::NA)).collect(),
+        Vector::Character(v) => v.iter().map(|x| matches!(x, OptionNA::NA)).collect(),
+    }
+}
+
+/// Test the type of the single `x` argument of a predicate
+fn test(args: List, stack: &mut CallStack, predicate: fn(&Obj) -> bool) -> EvalResult {
+    let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+    Ok(Obj::Vector(Vector::from(vec![predicate(&x)])))
+}
+
+fn x_formals() -> ExprList {
+    ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+}
+
+/// Get the Type of an Object
+///
+/// Returns the internal type of an object, regardless of its class.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// typeof(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose type to retrieve.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// typeof(1)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// typeof(function(x) x)
+/// ```
+///
+#[doc(alias = "typeof")]
+#[builtin(sym = "typeof")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveTypeof;
+
+impl Callable for PrimitiveTypeof {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        Ok(Obj::Vector(Vector::from(vec![type_of(&x).to_string()])))
+    }
+}
+
+/// Get the Mode of an Object
+///
+/// Returns the storage mode of an object, where both `integer` and `double`
+/// values are `"numeric"` and all functions are `"function"`.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// mode(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose mode to retrieve.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// mode(1L)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// mode(quote(f(x)))
+/// ```
+///
+#[doc(alias = "mode")]
+#[builtin(sym = "mode")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveMode;
+
+impl Callable for PrimitiveMode {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        Ok(Obj::Vector(Vector::from(vec![mode_of(&x).to_string()])))
+    }
+}
+
+/// Test for Missing Values
+///
+/// Tests whether each value of a vector is `NA`, where `NaN` is also
+/// considered missing. Elements of a list are missing when they are a single
+/// missing value. Names and dimensions are kept.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.na(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A vector or list to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.na(c(1, NA, 0 / 0))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// is.na(list(NA, c(NA, NA), "a"))
+/// ```
+///
+#[doc(alias = "is.na")]
+#[builtin(sym = "is.na")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsNa;
+
+impl Callable for PrimitiveIsNa {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let x = Obj::List(args).try_get_named("x")?.force(stack)?;
+        let result: Vec<bool> = match &x {
+            Obj::Null => vec![],
+            Obj::Vector(v) => missing(v),
+            Obj::List(l) => l
+                .materialize()
+                .values
+                .borrow()
+                .iter()
+                .map(|(_, v)| match v {
+                    Obj::Vector(v) if v.len() == 1 => missing(v)[0],
+                    _ => false,
+                })
+                .collect(),
+            x => {
+                let msg = format!(
+                    "is.na() applied to non-(list or vector) of type '{}'",
+                    type_of(x)
+                );
+                stack.warn(msg)?;
+                vec![false]
+            }
+        };
+
+        let result = Vector::from(result);
+        for name in ["names", "dim", "dimnames"] {
+            if let Some(value) = x.get_attr(name) {
+                result.attributes().set(name, value);
+            }
+        }
+
+        Ok(Obj::Vector(result))
+    }
+}
+
+/// Test for `NULL`
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.null(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.null(list())
+/// ```
+///
+#[doc(alias = "is.null")]
+#[builtin(sym = "is.null")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsNull;
+
+impl Callable for PrimitiveIsNull {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| matches!(x, Obj::Null))
+    }
+}
+
+/// Test for Functions
+///
+/// Tests whether an object is a function, either a closure or a builtin.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.function(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.function(sum)
+/// ```
+///
+#[doc(alias = "is.function")]
+#[builtin(sym = "is.function")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsFunction;
+
+impl Callable for PrimitiveIsFunction {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| matches!(x, Obj::Function(..)))
+    }
+}
+
+/// Test for Numeric Vectors
+///
+/// Tests whether an object is an `integer` or `double` vector.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.numeric(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.numeric(1L)
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// is.numeric("1")
+/// ```
+///
+#[doc(alias = "is.numeric")]
+#[builtin(sym = "is.numeric")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsNumeric;
+
+impl Callable for PrimitiveIsNumeric {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| {
+            matches!(x, Obj::Vector(Vector::Double(_) | Vector::Integer(_)))
+        })
+    }
+}
+
+/// Test for Double Vectors
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.double(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.double(1)
+/// ```
+///
+#[doc(alias = "is.double")]
+#[builtin(sym = "is.double")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsDouble;
+
+impl Callable for PrimitiveIsDouble {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| matches!(x, Obj::Vector(Vector::Double(_))))
+    }
+}
+
+/// Test for Integer Vectors
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.integer(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.integer(1:3)
+/// ```
+///
+#[doc(alias = "is.integer")]
+#[builtin(sym = "is.integer")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsInteger;
+
+impl Callable for PrimitiveIsInteger {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| {
+            matches!(x, Obj::Vector(Vector::Integer(_)))
+        })
+    }
+}
+
+/// Test for Logical Vectors
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.logical(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.logical(NA)
+/// ```
+///
+#[doc(alias = "is.logical")]
+#[builtin(sym = "is.logical")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsLogical;
+
+impl Callable for PrimitiveIsLogical {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| {
+            matches!(x, Obj::Vector(Vector::Logical(_)))
+        })
+    }
+}
+
+/// Test for Character Vectors
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.character(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.character("a")
+/// ```
+///
+#[doc(alias = "is.character")]
+#[builtin(sym = "is.character")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsCharacter;
+
+impl Callable for PrimitiveIsCharacter {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| {
+            matches!(x, Obj::Vector(Vector::Character(_)))
+        })
+    }
+}
+
+/// Test for Lists
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// is.list(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object to test.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// is.list(list(1, 2))
+/// ```
+///
+#[doc(alias = "is.list")]
+#[builtin(sym = "is.list")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveIsList;
+
+impl Callable for PrimitiveIsList {
+    fn formals(&self) -> ExprList {
+        x_formals()
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        test(args, stack, |x| matches!(x, Obj::List(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn types() {
+        assert_eq!(r! { typeof(1) }, r! { "double" });
+        assert_eq!(r! { typeof(1L) }, r! { "integer" });
+        assert_eq!(r! { typeof(list()) }, r! { "list" });
+        assert_eq!(r! { typeof(null) }, r! { "NULL" });
+        assert_eq!(r! { typeof(function(x) x) }, r! { "closure" });
+        assert_eq!(r! { typeof(sum) }, r! { "builtin" });
+        assert_eq!(r! { typeof(quote(x)) }, r! { "symbol" });
+        assert_eq!(r! { typeof(quote(f(x))) }, r! { "language" })
+    }
+
+    #[test]
+    fn types_ignore_class() {
+        assert_eq!(
+            r! { typeof(structure(1L, class = "thing")) },
+            r! { "integer" }
+        )
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(r! { mode(1L) }, r! { "numeric" });
+        assert_eq!(r! { mode("a") }, r! { "character" });
+        assert_eq!(r! { mode(sum) }, r! { "function" });
+        assert_eq!(r! { mode(quote(x)) }, r! { "name" });
+        assert_eq!(r! { mode(quote(f(x))) }, r! { "call" })
+    }
+
+    #[test]
+    fn is_na_values() {
+        assert_eq!(
+            r! { is.na(c(a = 1, b = NA, c = 0 / 0)) },
+            r! { c(a = false, b = true, c = true) }
+        );
+        assert_eq!(
+            r! { is.na(list(NA, c(NA, NA), "a")) },
+            r! { c(true, false, false) }
+        );
+        assert_eq!(r! { is.na(c("a", NA)) }, r! { c(false, true) })
+    }
+
+    #[test]
+    fn is_na_keeps_dimensions() {
+        assert_eq!(
+            r! { dim(is.na(matrix(c(1, NA, 3, 4), nrow = 2))) },
+            r! { c(2L, 2L) }
+        )
+    }
+
+    #[test]
+    fn type_predicates() {
+        assert_eq!(r! { is.null(null) }, r! { true });
+        assert_eq!(r! { is.null(list()) }, r! { false });
+        assert_eq!(r! { is.function(sum) }, r! { true });
+        assert_eq!(r! { is.function(function() 1) }, r! { true });
+        assert_eq!(r! { is.function("sum") }, r! { false });
+        assert_eq!(r! { is.numeric(1L) }, r! { true });
+        assert_eq!(r! { is.numeric("1") }, r! { false });
+        assert_eq!(r! { is.double(1L) }, r! { false });
+        assert_eq!(r! { is.integer(1:3) }, r! { true });
+        assert_eq!(r! { is.logical(NA) }, r! { true });
+        assert_eq!(r! { is.character("a") }, r! { true });
+        assert_eq!(r! { is.list(list(1)) }, r! { true })
+    }
+}
@@ -0,0 +1,56 @@
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Remove the Class of an Object
+///
+/// Returns an object with its `class` attribute removed, such that it is
+/// no longer dispatched to S3 methods for its class.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// unclass(x)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: An object whose class to remove.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// unclass(structure(1, class = "a"))
+/// ```
+///
+#[doc(alias = "unclass")]
+#[builtin(sym = "unclass")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveUnclass;
+
+impl Callable for PrimitiveUnclass {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(Some("x".to_string()), Expr::Missing)])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        match Obj::List(args).try_get_named("x")?.force(stack)? {
+            x @ (Obj::Vector(_) | Obj::List(_)) => x.set_attr("class", Obj::Null),
+            x => Ok(x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn unclass() {
+        assert_eq!(r! { unclass(structure(1, class = "a")) }, r! { 1 })
+    }
+}
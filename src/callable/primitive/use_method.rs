@@ -0,0 +1,273 @@
+use std::rc::Rc;
+
+use r_derive::*;
+
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Dispatch to an S3 Method
+///
+/// Called from within a generic function, `UseMethod()` calls the method
+/// for the class of an object. Methods are functions named
+/// `generic.class`, searched for each of the object's classes in turn,
+/// followed by `generic.default`. The method is called with the arguments
+/// of the call to the generic function, and its result is returned.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// UseMethod(generic, object)
+/// ```
+///
+/// ## Arguments
+///
+/// `generic`: The name of the generic function, as a `character` value.
+/// `object`: The object whose class determines the method. Defaults to the
+///   first argument of the enclosing function call.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// area <- function(shape, ...) UseMethod("area")
+/// area.square <- function(shape, ...) (shape$side)^2
+/// area.default <- function(shape, ...) stop("unknown shape")
+/// area(structure(list(side = 3), class = "square"))
+/// ```
+///
+#[doc(alias = "UseMethod")]
+#[builtin(sym = "UseMethod")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveUseMethod;
+
+impl Callable for PrimitiveUseMethod {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("generic".to_string()), Expr::Missing),
+            (Some("object".to_string()), Expr::Missing),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let generic = match args.try_get_named("generic")?.force(stack)? {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => String::from(v),
+            _ => return Error::Other("'generic' must be a character string".into()).into(),
+        };
+
+        let i = stack.origin_frame();
+        let frame = stack.frames[i].clone();
+        let Obj::Function(formals, ..) = frame.to.clone() else {
+            return Error::Other("UseMethod called from outside a function".into()).into();
+        };
+
+        // force the dispatched object within the generic's environment, so
+        // that it is not evaluated again by the method
+        let object = match args.try_get_named("object") {
+            Ok(object) => object.force(stack)?,
+            Err(_) => match formals.keys.first() {
+                Some(Some(name)) => stack.get(name.clone())?,
+                _ => call_args(&formals, &frame.env)
+                    .first()
+                    .map_or(Ok(Obj::Null), |(_, x)| x.clone().force(stack))?,
+            },
+        };
+
+        let mut classes = dispatch_class(&object);
+        classes.push("default".to_string());
+
+        // search the environment the generic was called from, then the
+        // environment where it was defined
+        let envs = [stack.frames[i - 1].env.clone(), frame.env.clone()];
+        let (method, classes) = match stack.find_method(&generic, &classes, &envs) {
+            Some(found) => found,
+            None => match builtin_method(&generic) {
+                Some(method) => (method, vec![]),
+                None => {
+                    let msg = format!(
+                        "no applicable method for '{generic}' applied to an object of class \"{}\"",
+                        object.class().first().cloned().unwrap_or_default()
+                    );
+                    return Error::Other(msg).into();
+                }
+            },
+        };
+
+        let dispatch = Dispatch {
+            generic,
+            classes,
+            args: call_args(&formals, &frame.env),
+        };
+
+        stack.call_method(method, dispatch)
+    }
+}
+
+/// The classes used to dispatch on an object
+///
+/// Objects without a `class` attribute dispatch on their implicit class,
+/// with arrays dispatching on their dimensionality followed by their type
+/// and numeric vectors additionally dispatching on their storage type.
+///
+fn dispatch_class(x: &Obj) -> Vec<String> {
+    if let Some(class) = x.explicit_class() {
+        return class;
+    }
+
+    let Obj::Vector(v) = x else {
+        return x.class();
+    };
+
+    let mut class = match v.attributes().dim() {
+        Some(_) => x.class(),
+        None => vec![],
+    };
+
+    let storage: &[&str] = match v {
+        Vector::Double(_) => &["double", "numeric"],
+        Vector::Integer(_) => &["integer", "numeric"],
+        Vector::Logical(_) => &["logical"],
+        Vector::Character(_) => &["character"],
+    };
+
+    class.extend(storage.iter().map(|c| c.to_string()));
+    class
+}
+
+/// Recover the arguments of a function call from its environment
+///
+/// The first argument is passed by position and all others by name, with
+/// the contents of `...` passed as they were received. Arguments which were
+/// not supplied to the call are omitted.
+///
+fn call_args(formals: &ExprList, env: &Rc<Environment>) -> Vec<(Option<String>, Obj)> {
+    let mut args = vec![];
+    for (key, value) in formals.clone().into_iter() {
+        match (key, value) {
+            (_, Expr::Ellipsis(_)) => {
+                if let Some(Obj::List(ellipsis)) = env.values.borrow().get("...") {
+                    args.extend(ellipsis.values.iter());
+                }
+            }
+            (Some(name), _) => match env.values.borrow().get(&name) {
+                None | Some(Obj::Promise(_, Expr::Missing, _)) => (),
+                // defaults are evaluated in the environment of the call
+                Some(Obj::Promise(_, _, penv)) if Rc::ptr_eq(penv, env) => (),
+                Some(value) if args.is_empty() => args.push((None, value.clone())),
+                Some(value) => args.push((Some(name), value.clone())),
+            },
+            _ => (),
+        }
+    }
+
+    args
+}
+
+/// Builtins serve as the default method of generics sharing their name
+pub fn builtin_method(generic: &str) -> Option<Obj> {
+    let builtin = builtin(generic).ok()?;
+    let env = Rc::new(Environment::default());
+    Some(Obj::Function(
+        ExprList::new(),
+        Expr::Primitive(builtin),
+        env,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::{r, r_expect};
+
+    #[test]
+    fn dispatch_on_class() {
+        r_expect! {{r#"
+            area <- function(shape, ...) UseMethod("area")
+            area.square <- function(shape, ...) (shape$side)^2
+            area(structure(list(side = 3), class = "square")) == 9
+        "#}}
+    }
+
+    #[test]
+    fn dispatch_on_class_in_order() {
+        r_expect! {{r#"
+            f <- function(x) UseMethod("f")
+            f.b <- function(x) "b"
+            f.c <- function(x) "c"
+            f(structure(1, class = c("a", "b", "c"))) == "b"
+        "#}}
+    }
+
+    #[test]
+    fn dispatch_to_default() {
+        r_expect! {{r#"
+            f <- function(x) UseMethod("f")
+            f.default <- function(x) "default"
+            f(structure(1, class = "a")) == "default"
+        "#}}
+    }
+
+    #[test]
+    fn dispatch_on_implicit_class() {
+        r_expect! {{r#"
+            f <- function(x) UseMethod("f")
+            f.numeric <- function(x) "numeric"
+            f.character <- function(x) "character"
+            f(1) == "numeric" && f("a") == "character"
+        "#}}
+    }
+
+    #[test]
+    fn passes_arguments() {
+        r_expect! {{r#"
+            f <- function(x, y = 1, ...) UseMethod("f")
+            f.a <- function(x, y = 10, ...) x + y + sum(...)
+            x <- structure(1, class = "a")
+            f(x) == 11 && f(x, 2) == 3 && f(x, y = 2, 3, 4) == 10
+        "#}}
+    }
+
+    #[test]
+    fn evaluates_object_once() {
+        r_expect! {{r#"
+            n <- 0
+            e <- environment()
+            count <- function() {
+                eval(quote(n <- n + 1), e)
+                structure(n, class = "a")
+            }
+            f <- function(x) UseMethod("f")
+            f.a <- function(x) x
+            f(count())
+            n == 1
+        "#}}
+    }
+
+    #[test]
+    fn no_applicable_method() {
+        assert_eq!(
+            r! {{r#"
+                f <- function(x) UseMethod("f")
+                f(structure(1, class = "a"))
+            "#}},
+            Error::Other(
+                "no applicable method for 'f' applied to an object of class \"a\"".to_string()
+            )
+            .into()
+        )
+    }
+
+    #[test]
+    fn dispatch_on_matrix() {
+        r_expect! {{r#"
+            f <- function(x) UseMethod("f")
+            f.numeric <- function(x) "numeric"
+            f.matrix <- function(x) "matrix"
+            f(matrix(1:4, nrow = 2)) == "matrix" && f(1) == "numeric"
+        "#}}
+    }
+}
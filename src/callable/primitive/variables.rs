@@ -0,0 +1,407 @@
+use std::rc::Rc;
+
+use r_derive::*;
+
+use super::environment::calling_env;
+use super::types::mode_of;
+use crate::callable::core::*;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// A single variable name argument
+fn name_arg(x: Obj) -> Result<String, Signal> {
+    match x {
+        Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => Ok(String::from(v)),
+        _ => Error::Other("invalid first argument".into()).into(),
+    }
+}
+
+fn env_arg(x: Obj) -> Result<Rc<Environment>, Signal> {
+    match x {
+        Obj::Environment(env) => Ok(env),
+        _ => Error::Other("invalid 'envir' argument".into()).into(),
+    }
+}
+
+/// Find the value bound to a name of some mode
+///
+/// The environment is searched first, followed by its parents when
+/// `inherits`. A `mode` of `"any"` matches values of every mode.
+///
+fn lookup(
+    name: &str,
+    env: Rc<Environment>,
+    inherits: bool,
+    mode: &str,
+    stack: &mut CallStack,
+) -> Result<Option<Obj>, Signal> {
+    let mut env = Some(env);
+    while let Some(mut e) = env {
+        if e.values.borrow().contains_key(name) {
+            let value = e.get(name.to_string())?.force(stack)?;
+            if mode == "any" || mode_of(&value) == mode {
+                return Ok(Some(value));
+            }
+        }
+
+        if !inherits {
+            break;
+        }
+
+        env = e.parent.clone();
+    }
+
+    Ok(None)
+}
+
+/// Assign a Variable
+///
+/// Binds a value to a name in an environment.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// assign(x, value, envir = environment(), inherits = false)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: The name of the variable.
+/// `value`: The value to assign.
+/// `envir`: The environment in which to assign, by default the calling
+///   environment.
+/// `inherits`: Whether to assign to an existing binding in a parent of
+///   `envir`, if `envir` doesn't bind `x` itself.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// assign("x", 3)
+/// x
+/// ```
+///
+#[doc(alias = "assign")]
+#[builtin(sym = "assign")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveAssign;
+
+impl Callable for PrimitiveAssign {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("value".to_string()), Expr::Missing),
+            (Some("envir".to_string()), calling_env()),
+            (Some("inherits".to_string()), Expr::Bool(false)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let name = name_arg(args.try_get_named("x")?.force(stack)?)?;
+        let value = args.try_get_named("value")?.force(stack)?;
+        let envir = env_arg(args.try_get_named("envir")?.force(stack)?)?;
+        let inherits: bool = args.try_get_named("inherits")?.force(stack)?.try_into()?;
+
+        let env = match inherits {
+            true => envir.binding(&name).unwrap_or(envir),
+            false => envir,
+        };
+
+        env.insert(name, value.clone());
+        Ok(value)
+    }
+}
+
+/// Get a Variable
+///
+/// Gets the value bound to a name, raising an error if there is none.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// get(x, envir = environment(), mode = "any", inherits = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: The name of the variable.
+/// `envir`: The environment in which to look, by default the calling
+///   environment.
+/// `mode`: The mode of value to find, as given by `mode()`, or `"any"`.
+/// `inherits`: Whether to look in the parents of `envir`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- 3
+/// get("x")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// sum <- 1
+/// get("sum", mode = "function")(1, 2)
+/// ```
+///
+#[doc(alias = "get")]
+#[builtin(sym = "get")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveGet;
+
+impl Callable for PrimitiveGet {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("envir".to_string()), calling_env()),
+            (Some("mode".to_string()), Expr::String("any".to_string())),
+            (Some("inherits".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let name = name_arg(args.try_get_named("x")?.force(stack)?)?;
+        let envir = env_arg(args.try_get_named("envir")?.force(stack)?)?;
+        let mode = name_arg(args.try_get_named("mode")?.force(stack)?)?;
+        let inherits: bool = args.try_get_named("inherits")?.force(stack)?.try_into()?;
+
+        match lookup(&name, envir, inherits, &mode, stack)? {
+            Some(value) => Ok(value),
+            None if mode == "any" => Error::VariableNotFound(name).into(),
+            None => Error::Other(format!("object '{name}' of mode '{mode}' was not found")).into(),
+        }
+    }
+}
+
+/// Test Whether a Variable Exists
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// exists(x, envir = environment(), mode = "any", inherits = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: The name of the variable.
+/// `envir`: The environment in which to look, by default the calling
+///   environment.
+/// `mode`: The mode of value to find, as given by `mode()`, or `"any"`.
+/// `inherits`: Whether to look in the parents of `envir`.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// exists("undefined_variable")
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// exists("sum", mode = "function")
+/// ```
+///
+#[doc(alias = "exists")]
+#[builtin(sym = "exists")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveExists;
+
+impl Callable for PrimitiveExists {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("envir".to_string()), calling_env()),
+            (Some("mode".to_string()), Expr::String("any".to_string())),
+            (Some("inherits".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let name = name_arg(args.try_get_named("x")?.force(stack)?)?;
+        let envir = env_arg(args.try_get_named("envir")?.force(stack)?)?;
+        let mode = name_arg(args.try_get_named("mode")?.force(stack)?)?;
+        let inherits: bool = args.try_get_named("inherits")?.force(stack)?.try_into()?;
+
+        let found = lookup(&name, envir, inherits, &mode, stack)?.is_some();
+        Ok(Obj::Vector(Vector::from(vec![found])))
+    }
+}
+
+/// Remove Variables
+///
+/// Removes the bindings of names from an environment, warning of any names
+/// which aren't bound.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// rm(..., list = NULL, envir = environment())
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: The variables to remove, as symbols or strings.
+/// `list`: A `character` vector of names of variables to remove.
+/// `envir`: The environment from which to remove variables, by default the
+///   calling environment.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// x <- 1
+/// rm(x)
+/// exists("x")
+/// ```
+///
+#[doc(alias = "rm")]
+#[builtin(sym = "rm")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveRm;
+
+impl Callable for PrimitiveRm {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (None, Expr::Ellipsis(None)),
+            (Some("list".to_string()), Expr::Missing),
+            (Some("envir".to_string()), calling_env()),
+        ])
+    }
+
+    fn call(&self, args: ExprList, stack: &mut CallStack) -> EvalResult {
+        let mut names: Vec<String> = vec![];
+        let mut envir = stack.env();
+
+        // variables are named by their unevaluated symbols
+        for (key, value) in args.into_iter() {
+            match (key.as_deref(), value) {
+                (Some("list"), value) => {
+                    let list = stack.eval_and_finalize(value)?.force(stack)?;
+                    match list {
+                        Obj::Null => (),
+                        Obj::Vector(v @ Vector::Character(_)) => {
+                            names.extend(Vec::<String>::from(v))
+                        }
+                        _ => return Error::Other("invalid 'list' argument".into()).into(),
+                    }
+                }
+                (Some("envir"), value) => {
+                    envir = env_arg(stack.eval_and_finalize(value)?.force(stack)?)?;
+                }
+                (None, Expr::Symbol(name) | Expr::String(name)) => names.push(name),
+                _ => {
+                    let msg = "... must contain names or character strings";
+                    return Error::Other(msg.into()).into();
+                }
+            }
+        }
+
+        for name in names {
+            if envir.values.borrow_mut().remove(&name).is_none() {
+                stack.warn(format!("object '{name}' not found"))?;
+            }
+        }
+
+        Ok(Obj::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn assign_in_calling_environment() {
+        assert_eq!(
+            r! {{ r#"
+                f <- function() { assign("y", 2); y }
+                f()
+            "# }},
+            r! { 2 }
+        );
+        assert_eq!(
+            r! {{ r#"
+                f <- function() assign("y", 2)
+                f()
+                exists("y")
+            "# }},
+            r! { false }
+        )
+    }
+
+    #[test]
+    fn assign_in_environment() {
+        assert_eq!(
+            r! {{ r#"
+                g <- function() {
+                    e <- environment()
+                    f <- function() assign("y", 2, envir = e)
+                    f()
+                    y
+                }
+                g()
+            "# }},
+            r! { 2 }
+        )
+    }
+
+    #[test]
+    fn assign_inherits() {
+        assert_eq!(
+            r! {{ r#"
+                x <- 1
+                f <- function() assign("x", 2, inherits = true)
+                f()
+                x
+            "# }},
+            r! { 2 }
+        )
+    }
+
+    #[test]
+    fn get_values() {
+        assert_eq!(r! { x <- 3; get("x") }, r! { 3 });
+        assert_eq!(
+            r! {{ r#"
+                f <- function() { x <- 4; g <- function() get("x"); g() }
+                f()
+            "# }},
+            r! { 4 }
+        );
+        assert!(r! { get("undefined_variable") }.is_err());
+        assert!(r! { x <- 1; f <- function() get("x", inherits = false); f() }.is_err())
+    }
+
+    #[test]
+    fn get_by_mode() {
+        assert_eq!(
+            r! { sum <- 1; get("sum", mode = "function")(1, 2) },
+            r! { 3 }
+        );
+        assert!(r! { x <- 1; get("x", mode = "function") }.is_err())
+    }
+
+    #[test]
+    fn exists_variables() {
+        assert_eq!(r! { x <- 1; exists("x") }, r! { true });
+        assert_eq!(r! { exists("undefined_variable") }, r! { false });
+        assert_eq!(r! { exists("sum", mode = "function") }, r! { true });
+        assert_eq!(r! { exists("sum", mode = "numeric") }, r! { false })
+    }
+
+    #[test]
+    fn rm_variables() {
+        assert_eq!(
+            r! { x <- 1; y <- 2; rm(x, "y"); c(exists("x"), exists("y")) },
+            r! { c(false, false) }
+        );
+        assert_eq!(r! { x <- 1; rm(list = c("x")); exists("x") }, r! { false })
+    }
+}
@@ -0,0 +1,77 @@
+use r_derive::*;
+
+use super::stop::condition_message;
+use crate::callable::core::*;
+use crate::lang::*;
+use crate::object::*;
+
+/// Raise a Warning
+///
+/// Signals a warning. When a `warning` handler has been established by
+/// `tryCatch()`, evaluation is interrupted and the handler is called.
+/// Otherwise, evaluation continues and the warning is reported after the
+/// top-level expression has been evaluated.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// warning(...)
+/// ```
+///
+/// ## Arguments
+///
+/// `...`: Objects which are coerced to `character` and concatenated to
+///   produce the warning message.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// f <- fn(x) { warning("x is ", x); x + 1 }
+/// f(1)
+/// ```
+///
+#[doc(alias = "warning")]
+#[builtin(sym = "warning")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveWarning;
+impl Callable for PrimitiveWarning {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![(None, Expr::Ellipsis(None))])
+    }
+
+    fn call_matched(&self, _args: List, ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let message = condition_message(ellipsis, stack)?;
+
+        stack.warn(message)?;
+        Ok(Obj::Null)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::context::Context;
+    use crate::lang::CallStack;
+    use crate::r;
+
+    #[test]
+    fn continues_evaluation() {
+        assert_eq!(r! { f <- fn() { warning("careful"); 3 }; f() }, r! { 3 })
+    }
+
+    #[test]
+    fn collects_warnings() {
+        let mut stack = CallStack::default();
+        let expr = stack
+            .parse("f <- fn() warning('inner'); warning('a', 1); f()")
+            .expect("parse failed");
+
+        stack.eval_and_finalize(expr).ok();
+        assert_eq!(
+            stack.flush_warnings(),
+            Some("Warning messages:\n1: a1\n2: In f() : inner\n".to_string())
+        );
+        assert_eq!(stack.flush_warnings(), None);
+    }
+}
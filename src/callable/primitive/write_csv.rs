@@ -0,0 +1,214 @@
+use r_derive::*;
+use std::io::Write;
+
+use crate::callable::core::*;
+use crate::error::Error;
+use crate::lang::*;
+use crate::object::*;
+
+/// Write a CSV File
+///
+/// Writes a data frame as comma-separated values, with a header line of
+/// column names. Vectors and lists are first converted to data frames,
+/// and the columns of a matrix are written as separate columns.
+///
+/// # In-Language
+///
+/// ## Usage
+///
+/// ```custom,{class=r}
+/// write.csv(x, file = "", row.names = true, na = "NA", quote = true)
+/// ```
+///
+/// ## Arguments
+///
+/// `x`: A data frame, or an object to convert to a data frame.
+/// `file`: The path of the file to write. When `""`, the output is printed
+///   instead.
+/// `row.names`: Whether to write the row names as the first column.
+/// `na`: The text with which to write `NA` values.
+/// `quote`: Whether to quote names, row names and `character` values.
+///
+/// ## Examples
+///
+/// ```custom,{class=r-repl}
+/// write.csv(data.frame(x = 1:3, y = c("a", NA, "c")))
+/// ```
+///
+/// ```custom,{class=r-repl}
+/// write.csv(data.frame(x = 1:3), "data.csv", row.names = false)
+/// ```
+///
+#[doc(alias = "write.csv")]
+#[builtin(sym = "write.csv")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveWriteCsv;
+
+impl Callable for PrimitiveWriteCsv {
+    fn formals(&self) -> ExprList {
+        ExprList::from(vec![
+            (Some("x".to_string()), Expr::Missing),
+            (Some("file".to_string()), Expr::String("".to_string())),
+            (Some("row.names".to_string()), Expr::Bool(true)),
+            (Some("na".to_string()), Expr::String("NA".to_string())),
+            (Some("quote".to_string()), Expr::Bool(true)),
+        ])
+    }
+
+    fn call_matched(&self, args: List, _ellipsis: List, stack: &mut CallStack) -> EvalResult {
+        let mut args = Obj::List(args);
+        let x = args.try_get_named("x")?.force(stack)?;
+        let file = match args.try_get_named("file")?.force(stack)? {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => String::from(v),
+            _ => return Error::Other("'file' must be a character string".into()).into(),
+        };
+
+        let row_names: bool = args.try_get_named("row.names")?.force(stack)?.try_into()?;
+        let na = match args.try_get_named("na")?.force(stack)? {
+            Obj::Vector(v @ Vector::Character(_)) if v.len() == 1 => String::from(v),
+            _ => return Error::Other("'na' must be a character string".into()).into(),
+        };
+
+        let quote: bool = args.try_get_named("quote")?.force(stack)?.try_into()?;
+        let quoted = |s: String| match quote {
+            true => format!("\"{}\"", s.replace('"', "\"\"")),
+            false => s,
+        };
+
+        let df = as_data_frame(x)?;
+        let columns: Vec<Vec<String>> = df
+            .columns()
+            .into_iter()
+            .map(|(_, v)| format_column(v, &na, quoted))
+            .collect();
+
+        let mut lines: Vec<Vec<String>> = vec![];
+
+        let header = df.columns().into_iter().map(|(k, _)| quoted(k));
+        lines.push(match row_names {
+            true => std::iter::once(quoted(String::new()))
+                .chain(header)
+                .collect(),
+            false => header.collect(),
+        });
+
+        for (i, row_name) in df.row_names().into_iter().enumerate() {
+            let values = columns.iter().map(|c| c[i].clone());
+            lines.push(match row_names {
+                true => std::iter::once(quoted(row_name)).chain(values).collect(),
+                false => values.collect(),
+            });
+        }
+
+        let text: String = lines.iter().map(|l| format!("{}\n", l.join(","))).collect();
+
+        match file.as_str() {
+            "" => {
+                write!(stack.session.output, "{text}").ok();
+            }
+            file => std::fs::write(file, text)
+                .map_err(|e| Error::Other(format!("cannot open file '{file}': {e}")))?,
+        };
+
+        Ok(Obj::Null)
+    }
+}
+
+/// Convert an object to a data frame for writing
+fn as_data_frame(x: Obj) -> Result<List, Signal> {
+    let columns = match x {
+        Obj::List(l) if l.is_data_frame() => return Ok(l),
+        Obj::List(l) => l
+            .values
+            .iter()
+            .enumerate()
+            .map(|(j, (k, v))| match v {
+                Obj::Vector(v) => Ok((k.unwrap_or_else(|| format!("V{}", j + 1)), v)),
+                _ => Error::Other("cannot write a list of non-vector values".into()).into(),
+            })
+            .collect::<Result<Vec<_>, Signal>>()?,
+        Obj::Vector(v) => match v.attributes().dim() {
+            Some(dim) if dim.len() == 2 => (0..dim[1])
+                .map(|j| {
+                    let column = v.subset(Subset::Range(j * dim[0]..(j + 1) * dim[0]));
+                    (format!("V{}", j + 1), column.materialize())
+                })
+                .collect(),
+            _ => vec![("x".to_string(), v)],
+        },
+        Obj::Null => vec![],
+        _ => return Error::Other("cannot write an object of this type".into()).into(),
+    };
+
+    List::data_frame(columns)
+}
+
+/// Format the values of a column as fields
+fn format_column(v: Vector, na: &str, quoted: impl Fn(String) -> String) -> Vec<String> {
+    let is_character = matches!(v, Vector::Character(_));
+    let is_logical = matches!(v, Vector::Logical(_));
+    let Vector::Character(values) = v.as_character() else {
+        unreachable!()
+    };
+
+    values
+        .into_iter()
+        .map(|x| match x {
+            OptionNA::NA => na.to_string(),
+            OptionNA::Some(x) if is_character => quoted(x),
+            OptionNA::Some(x) if is_logical => x.to_uppercase(),
+            OptionNA::Some(x) => x,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lang::CallStack;
+    use crate::r;
+
+    #[test]
+    fn writes_quoted_values() {
+        let path = std::env::temp_dir().join("r-write-csv-quoted.csv");
+        let expr = format!(
+            r#"write.csv(data.frame(x = c(1, NA), y = c("a", 'b"c')), {:?})"#,
+            path.display().to_string()
+        );
+
+        CallStack::default().parse_and_eval(&expr).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\"\",\"x\",\"y\"\n\"1\",1,\"a\"\n\"2\",NA,\"b\"\"c\"\n"
+        );
+    }
+
+    #[test]
+    fn writes_unquoted_without_row_names() {
+        let path = std::env::temp_dir().join("r-write-csv-unquoted.csv");
+        let expr = format!(
+            r#"write.csv(data.frame(x = c(true, NA)), {:?}, row.names = false, na = "", quote = false)"#,
+            path.display().to_string()
+        );
+
+        CallStack::default().parse_and_eval(&expr).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "x\nTRUE\n\n");
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join("r-write-csv-round-trip.csv");
+        let path = format!("{:?}", path.display().to_string());
+        let expr = format!(
+            r#"
+            df <- data.frame(x = 1:3, y = c("a", NA, "c"), z = c(0.5, 1, NA))
+            write.csv(df, {path}, row.names = false)
+            read.csv({path})
+            "#
+        );
+
+        assert_eq!(
+            CallStack::default().parse_and_eval(&expr),
+            r! {{ r#"data.frame(x = c(1L, 2L, 3L), y = c("a", NA, "c"), z = c(0.5, 1, NA))"# }}
+        );
+    }
+}
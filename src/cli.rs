@@ -40,4 +40,25 @@ pub struct Cli {
         arg(short = 'x', long, value_delimiter = ',')
     )]
     pub experiments: Vec<Experiment>,
+
+    /// Expressions to evaluate non-interactively (may be repeated)
+    #[cfg_attr(target_family = "wasm", serde(default))]
+    #[cfg_attr(
+        not(target_family = "wasm"),
+        arg(short = 'e', long = "expr", allow_hyphen_values = true)
+    )]
+    pub expr: Vec<String>,
+
+    /// A script file to evaluate non-interactively
+    #[cfg_attr(target_family = "wasm", serde(default))]
+    #[cfg_attr(not(target_family = "wasm"), arg())]
+    pub script: Option<String>,
+}
+
+impl Cli {
+    /// Whether the runtime should evaluate inputs non-interactively instead
+    /// of launching the REPL
+    pub fn is_non_interactive(&self) -> bool {
+        self.script.is_some() || !self.expr.is_empty()
+    }
 }
@@ -58,7 +58,7 @@ pub enum Error {
 }
 
 impl Error {
-    fn as_str(&self) -> String {
+    pub fn as_str(&self) -> String {
         match self {
             Error::IncorrectContext(x) => format!("'{}' used in an incorrect context", x),
             Error::VariableNotFound(v) => format!("object '{}' not found", v.as_str()),
@@ -151,6 +151,23 @@ impl Error {
             }
         }
     }
+
+    /// Whether a parse failure occurred at the end of the input, such that
+    /// further input may complete the expression
+    pub fn is_incomplete_input(&self, input: &str) -> bool {
+        use pest::error::InputLocation;
+
+        let Error::ParseFailure(e) = self else {
+            return false;
+        };
+
+        let pos = match e.location {
+            InputLocation::Pos(p) => p,
+            InputLocation::Span((_, end)) => end,
+        };
+
+        pos >= input.trim_end().len()
+    }
 }
 
 impl fmt::Display for Error {
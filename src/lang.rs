@@ -21,6 +21,8 @@ pub enum Cond {
     Break,
     Continue,
     Terminate,
+    Warning(String),
+    Message(String),
 }
 
 impl From<Cond> for Signal {
@@ -73,10 +75,12 @@ impl ViewMut for Obj {
                 names,
                 values,
                 subsets,
+                attributes,
             }) => Obj::List(List {
                 names: (*names).view_mut(),
                 values: (*values).view_mut(),
                 subsets: (*subsets).clone(),
+                attributes: (*attributes).view_mut(),
             }),
             // FIXME: this needs to be implemented for all objects that can be mutated
             x => x.clone(),
@@ -101,6 +105,18 @@ impl Obj {
         }
     }
 
+    /// Whether the object is a lazily computed vector
+    pub fn is_lazy(&self) -> bool {
+        matches!(self, Obj::Vector(v) if v.is_lazy())
+    }
+
+    /// Materialize a lazily computed vector in place, before it is modified
+    pub fn materialize_lazy(&self) {
+        if let Obj::Vector(v) = self {
+            v.materialize_lazy()
+        }
+    }
+
     pub fn assign(self, value: Obj) -> EvalResult {
         // TODO(ERROR) cleanup
         let err = Error::Other("Invalid target for assignment".to_string());
@@ -147,6 +163,7 @@ impl Obj {
             Obj::Promise(..) => internal_err!(),
             Obj::Function(..) => internal_err!(),
             Obj::Environment(..) => internal_err!(),
+            Obj::Formula(formula, ..) => Obj::Expr(formula.clone()).as_list(),
         }
     }
 
@@ -166,6 +183,18 @@ impl Obj {
         }
     }
 
+    /// Coerce an object for arithmetic
+    ///
+    /// `logical` and `integer` vectors are kept as they are, so that integer
+    /// arithmetic produces integers, while all other values are coerced to
+    /// `double`.
+    pub fn as_numeric(self) -> EvalResult {
+        match self {
+            Obj::Vector(Vector::Logical(_) | Vector::Integer(_)) => Ok(self),
+            _ => self.as_double(),
+        }
+    }
+
     pub fn as_logical(self) -> EvalResult {
         match self {
             Obj::Vector(v) => Ok(Obj::Vector(v.as_logical())),
@@ -222,6 +251,7 @@ impl Obj {
             Obj::Promise(..) => None,
             Obj::Function(..) => None,
             Obj::Environment(..) => None,
+            Obj::Formula(..) => None,
         }
     }
 
@@ -268,7 +298,10 @@ impl Obj {
 
     pub fn environment(&self) -> Option<Rc<Environment>> {
         match self {
-            Obj::Promise(.., e) | Obj::Function(.., e) | Obj::Environment(e) => Some(e.clone()),
+            Obj::Promise(.., e)
+            | Obj::Function(.., e)
+            | Obj::Environment(e)
+            | Obj::Formula(_, e, _) => Some(e.clone()),
             _ => None,
         }
     }
@@ -286,11 +319,21 @@ impl Obj {
     pub fn try_get(&self, index: Obj) -> EvalResult {
         match self {
             Obj::Vector(v) => v.try_get(index),
+            Obj::List(l) if l.is_data_frame() => l.try_get_frame(vec![Some(index)], true),
             Obj::List(l) => l.try_get(index),
             obj => obj.as_list()?.try_get(index),
         }
     }
 
+    // Used for [ ] syntax with an index per dimension
+    pub fn try_get_dims(&self, indices: Vec<Option<Obj>>, drop: bool) -> EvalResult {
+        match self {
+            Obj::Vector(v) => v.try_get_dims(indices, drop),
+            Obj::List(l) if l.is_data_frame() => l.try_get_frame(indices, drop),
+            _ => Error::Other("incorrect number of dimensions".into()).into(),
+        }
+    }
+
     // Used for [[ ]] syntax
     pub fn try_get_inner(&self, index: Obj) -> EvalResult {
         match self {
@@ -309,6 +352,207 @@ impl Obj {
         }
     }
 
+    /// The classes of an object, as set by its `class` attribute
+    pub fn explicit_class(&self) -> Option<Vec<String>> {
+        match self.get_attr("class") {
+            Some(Obj::Vector(v)) => Some(v.into()),
+            _ => None,
+        }
+    }
+
+    /// The class of an object, either as set by its `class` attribute or
+    /// implied by its type
+    pub fn class(&self) -> Vec<String> {
+        if let Some(class) = self.explicit_class() {
+            return class;
+        }
+
+        if let Obj::Vector(v) = self {
+            match v.attributes().dim().map(|dim| dim.len()) {
+                Some(2) => return vec!["matrix".to_string(), "array".to_string()],
+                Some(_) => return vec!["array".to_string()],
+                None => (),
+            }
+        }
+
+        let class = match self {
+            Obj::Null => "NULL",
+            Obj::Vector(Vector::Double(_)) => "numeric",
+            Obj::Vector(Vector::Integer(_)) => "integer",
+            Obj::Vector(Vector::Logical(_)) => "logical",
+            Obj::Vector(Vector::Character(_)) => "character",
+            Obj::List(_) => "list",
+            Obj::Function(..) => "function",
+            Obj::Environment(_) => "environment",
+            Obj::Formula(..) => "formula",
+            Obj::Expr(Expr::Symbol(_)) => "name",
+            Obj::Expr(_) => "call",
+            Obj::Promise(Some(value), ..) => return value.class(),
+            Obj::Promise(None, ..) => "promise",
+        };
+
+        vec![class.to_string()]
+    }
+
+    /// The extent of each dimension of an object
+    ///
+    /// Data frames have dimensions of their rows and columns, while other
+    /// objects have the dimensions of their `dim` attribute.
+    ///
+    pub fn dim(&self) -> Option<Vec<usize>> {
+        match self {
+            Obj::Vector(v) => v.attributes().dim(),
+            Obj::List(l) if l.is_data_frame() => Some(vec![l.nrow(), l.len()]),
+            Obj::List(l) => l.attributes.dim(),
+            _ => None,
+        }
+    }
+
+    /// Get an attribute of an object
+    ///
+    /// The `names` of a list are derived from the names of its elements.
+    ///
+    pub fn get_attr(&self, name: &str) -> Option<Obj> {
+        match (self, name) {
+            (Obj::Vector(v), _) => v.attributes().get(name),
+            (Obj::List(l), "names") => l.names().map(|names| names.into()),
+            (Obj::List(l), _) => l.attributes.get(name),
+            (Obj::Formula(.., attrs), _) => attrs.get(name),
+            _ => None,
+        }
+    }
+
+    /// Set an attribute of an object
+    ///
+    /// Produces a new object with the attribute set. Setting an attribute
+    /// to `NULL` removes it.
+    ///
+    pub fn set_attr(self, name: &str, value: Obj) -> EvalResult {
+        match (self, name) {
+            (Obj::Null, _) => Error::Other("attempt to set an attribute on NULL".into()).into(),
+            (x @ (Obj::Vector(_) | Obj::List(_)), "names") => x.set_names(value),
+            (Obj::Vector(v), "dim") => Obj::Vector(v).set_dim(value),
+            (Obj::Vector(v), _) => {
+                v.attributes().set(name, value);
+                Ok(Obj::Vector(v))
+            }
+            (Obj::List(l), _) => {
+                l.attributes.set(name, value);
+                Ok(Obj::List(l))
+            }
+            (Obj::Formula(formula, env, attrs), _) => {
+                attrs.set(name, value);
+                Ok(Obj::Formula(formula, env, attrs))
+            }
+            _ => Error::Other(format!("cannot set attribute '{name}' on this object")).into(),
+        }
+    }
+
+    fn set_names(self, value: Obj) -> EvalResult {
+        let n = self.len().unwrap_or_default();
+        let names: Option<Vec<Character>> = match value {
+            Obj::Null => None,
+            value => match value.as_character()? {
+                Obj::Vector(Vector::Character(v)) => Some(v.into_iter().collect()),
+                _ => return internal_err!(),
+            },
+        };
+
+        if let Some(names) = &names {
+            if names.len() > n {
+                let msg = format!(
+                    "'names' attribute [{}] must be the same length as the vector [{n}]",
+                    names.len()
+                );
+                return Error::Other(msg).into();
+            }
+        }
+
+        match self {
+            Obj::Vector(v) => {
+                // names shorter than the vector are padded with NAs
+                let names = names.map(|mut names| {
+                    names.resize(n, OptionNA::NA);
+                    Obj::Vector(names.into())
+                });
+
+                v.attributes().set("names", names.unwrap_or_default());
+                Ok(Obj::Vector(v))
+            }
+            Obj::List(mut l) => {
+                l.set_names(names.unwrap_or_default());
+                Ok(Obj::List(l))
+            }
+            _ => internal_err!(),
+        }
+    }
+
+    fn set_dim(self, value: Obj) -> EvalResult {
+        let Obj::Vector(v) = self else {
+            return internal_err!();
+        };
+
+        if let Obj::Null = value {
+            v.attributes().set("dim", Obj::Null);
+            return Ok(Obj::Vector(v));
+        }
+
+        let Obj::Vector(Vector::Integer(dim)) = value.as_integer()? else {
+            return internal_err!();
+        };
+
+        let dim: Vec<Integer> = dim.into_iter().collect();
+        if dim
+            .iter()
+            .any(|d| !matches!(d, OptionNA::Some(d) if *d >= 0))
+        {
+            return Error::Other("the dims contain missing or negative values".into()).into();
+        }
+
+        let product: i32 = dim
+            .iter()
+            .map(|d| match d {
+                OptionNA::Some(d) => *d,
+                OptionNA::NA => 0,
+            })
+            .product();
+
+        if product as usize != v.len() {
+            let msg = format!(
+                "dims [product {product}] do not match the length of object [{}]",
+                v.len()
+            );
+            return Error::Other(msg).into();
+        }
+
+        // dimensioned objects are no longer named
+        v.attributes().set("names", Obj::Null);
+        v.attributes().set("dim", Obj::Vector(dim.into()));
+        Ok(Obj::Vector(v))
+    }
+
+    /// Get all attributes of an object as a named list
+    pub fn attributes(&self) -> Option<List> {
+        let mut attrs: Vec<(Option<String>, Obj)> = vec![];
+        let others = match self {
+            Obj::Vector(v) => v.attributes().clone(),
+            Obj::List(l) => {
+                if let Some(names) = l.names() {
+                    attrs.push((Some("names".into()), names.into()));
+                }
+                l.attributes.clone()
+            }
+            Obj::Formula(.., attrs) => attrs.clone(),
+            _ => return None,
+        };
+
+        attrs.extend(others.iter().map(|(k, v)| (Some(k), v)));
+        match attrs.len() {
+            0 => None,
+            _ => Some(List::from(attrs)),
+        }
+    }
+
     pub fn len(&self) -> Option<usize> {
         match self {
             Obj::Vector(v) => Some(v.len()),
@@ -353,7 +597,14 @@ impl Display for Obj {
         match self {
             Obj::Vector(v) => write!(f, "{}", v),
             Obj::Null => write!(f, "NULL"),
-            Obj::Environment(x) => write!(f, "<environment {:?}>", x.values.as_ptr()),
+            Obj::Environment(x) => write!(f, "{x}"),
+            Obj::Formula(x, _, attrs) => {
+                write!(f, "{x}")?;
+                for (name, value) in attrs.iter() {
+                    write!(f, "\nattr(,\"{name}\")\n{value}")?;
+                }
+                Ok(())
+            }
             Obj::Function(formals, Expr::Primitive(primitive), _) => {
                 write!(
                     f,
@@ -366,7 +617,14 @@ impl Display for Obj {
                 let parent_env = Obj::Environment(Rc::clone(parent_env));
                 write!(f, "function({}) {}\n{}", formals, body, parent_env)
             }
-            Obj::List(vals) => display_list(vals, f, None),
+            Obj::List(vals) if vals.is_data_frame() => vals.fmt_data_frame(f),
+            Obj::List(vals) => {
+                display_list(vals, f, None)?;
+                for (name, value) in vals.attributes.iter() {
+                    write!(f, "attr(,\"{name}\")\n{value}\n")?;
+                }
+                Ok(())
+            }
             Obj::Promise(None, expr, env) => write!(f, "{expr} @ {env}"),
             Obj::Promise(Some(obj), ..) => write!(f, "{obj}"),
             Obj::Expr(expr) => write!(f, "{}", expr),
@@ -378,12 +636,7 @@ fn display_list(x: &List, f: &mut fmt::Formatter<'_>, bc: Option<String>) -> fmt
     let v = x.values.borrow();
     let s = x.subsets.clone();
 
-    for (i, (_, si)) in s
-        .bind_names(x.names.clone())
-        .into_iter()
-        .take(v.len())
-        .enumerate()
-    {
+    for (i, si) in s.bind_names(x.names.clone()).indices(v.len()).enumerate() {
         let name;
         let value;
 
@@ -424,7 +677,7 @@ impl std::ops::Add for Obj {
     type Output = EvalResult;
 
     fn add(self, rhs: Self) -> Self::Output {
-        match (self.as_double()?, rhs.as_double()?) {
+        match (self.as_numeric()?, rhs.as_numeric()?) {
             (Obj::Vector(l), Obj::Vector(r)) => Ok(Obj::Vector(l + r)),
             _ => internal_err!(),
         }
@@ -435,7 +688,7 @@ impl std::ops::Sub for Obj {
     type Output = EvalResult;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        match (self.as_double()?, rhs.as_double()?) {
+        match (self.as_numeric()?, rhs.as_numeric()?) {
             (Obj::Vector(l), Obj::Vector(r)) => Ok(Obj::Vector(l - r)),
             _ => internal_err!(),
         }
@@ -446,7 +699,7 @@ impl std::ops::Neg for Obj {
     type Output = EvalResult;
 
     fn neg(self) -> Self::Output {
-        match self.as_double()? {
+        match self.as_numeric()? {
             Obj::Vector(x) => Ok(Obj::Vector(-x)),
             _ => internal_err!(),
         }
@@ -468,7 +721,7 @@ impl std::ops::Mul for Obj {
     type Output = EvalResult;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        match (self.as_double()?, rhs.as_double()?) {
+        match (self.as_numeric()?, rhs.as_numeric()?) {
             (Obj::Vector(l), Obj::Vector(r)) => Ok(Obj::Vector(l * r)),
             _ => internal_err!(),
         }
@@ -501,13 +754,24 @@ impl std::ops::Rem for Obj {
     type Output = EvalResult;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        match (self.as_double()?, rhs.as_double()?) {
+        match (self.as_numeric()?, rhs.as_numeric()?) {
             (Obj::Vector(l), Obj::Vector(r)) => Ok(Obj::Vector(l % r)),
             _ => internal_err!(),
         }
     }
 }
 
+impl super::object::IntDiv<Obj> for Obj {
+    type Output = EvalResult;
+
+    fn int_div(self, rhs: Self) -> Self::Output {
+        match (self.as_numeric()?, rhs.as_numeric()?) {
+            (Obj::Vector(l), Obj::Vector(r)) => Ok(Obj::Vector(l.int_div(r))),
+            _ => internal_err!(),
+        }
+    }
+}
+
 impl std::ops::BitOr for Obj {
     type Output = EvalResult;
 
@@ -595,6 +859,26 @@ pub struct Frame {
     pub to: Obj,
     // The evaluation environment for the frame
     pub env: Rc<Environment>,
+    // Expressions to evaluate when the frame exits, registered by `on.exit()`
+    pub on_exit: Vec<Expr>,
+    // Condition classes for which the frame establishes a handler
+    pub handlers: Vec<String>,
+    // S3 dispatch state, for frames which call a method
+    pub dispatch: Option<Dispatch>,
+}
+
+/// The state of an S3 method dispatch
+///
+/// Recorded on the frame which calls a method, so that `NextMethod()` can
+/// continue dispatching from within the method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dispatch {
+    // The name of the generic function
+    pub generic: String,
+    // Classes remaining to be searched for subsequent methods
+    pub classes: Vec<String>,
+    // The arguments with which the method was called
+    pub args: Vec<(Option<String>, Obj)>,
 }
 
 impl Frame {
@@ -604,7 +888,14 @@ impl Frame {
             _ => Obj::Null,
         };
 
-        Self { call, to, env }
+        Self {
+            call,
+            to,
+            env,
+            on_exit: vec![],
+            handlers: vec![],
+            dispatch: None,
+        }
     }
 
     pub fn new_child_env(&self) -> Box<dyn Context> {
@@ -625,6 +916,8 @@ impl Display for Frame {
 pub struct CallStack {
     pub session: Session,
     pub frames: Vec<Frame>,
+    // Warnings raised, but not handled, during evaluation
+    pub warnings: Vec<String>,
 }
 
 impl CallStack {
@@ -641,14 +934,12 @@ impl CallStack {
 
 impl Default for CallStack {
     fn default() -> Self {
-        let global_env = Rc::new(Environment {
-            parent: Some(Environment::from_builtins()),
-            ..Default::default()
-        });
+        let global_env = Environment::global();
 
         CallStack {
             session: Session::default(),
             frames: vec![Frame::new(Expr::Null, global_env)],
+            warnings: vec![],
         }
     }
 }
@@ -724,6 +1015,12 @@ impl CallStack {
         }
     }
 
+    pub fn last_frame_mut(&mut self) -> &mut Frame {
+        self.frames
+            .last_mut()
+            .expect("We've somehow exhausted the entire call stack and are still evaluating")
+    }
+
     pub fn last_frame(&self) -> Frame {
         if let Some(frame) = self.frames.last() {
             frame.clone()
@@ -740,9 +1037,293 @@ impl CallStack {
         }
     }
 
+    /// Find the index of the frame which introduced the current evaluation
+    /// environment. For code evaluated in a function body, this is the frame
+    /// of the function call.
+    pub fn origin_frame(&self) -> usize {
+        let env = self.env();
+        self.frames
+            .iter()
+            .position(|frame| Rc::ptr_eq(&frame.env, &env))
+            .unwrap_or(0)
+    }
+
+    /// Test whether any frame on the stack establishes a handler for a
+    /// condition class
+    pub fn is_handled(&self, class: &str) -> bool {
+        self.frames.iter().any(|frame| {
+            frame
+                .handlers
+                .iter()
+                .any(|h| h == class || h == "condition")
+        })
+    }
+
+    /// Raise a warning
+    ///
+    /// When a handler is established for warnings, the warning is signaled
+    /// as a condition to unwind to it. Otherwise, it is recorded to be
+    /// reported after the top-level evaluation, noting the call from which
+    /// it was raised.
+    ///
+    pub fn warn(&mut self, message: String) -> Result<(), Signal> {
+        if self.is_handled("warning") {
+            return Err(Cond::Warning(message).into());
+        }
+
+        let message = match self.origin_frame() {
+            0 => message,
+            i => format!("In {} : {message}", self.frames[i].call),
+        };
+
+        self.warnings.push(message);
+        Ok(())
+    }
+
+    /// Drain collected warnings, formatted for display after a top-level
+    /// result
+    pub fn flush_warnings(&mut self) -> Option<String> {
+        let warnings = std::mem::take(&mut self.warnings);
+        match warnings.len() {
+            0 => None,
+            1 => Some(format!("Warning message:\n{}\n", warnings[0])),
+            _ => Some(format!(
+                "Warning messages:\n{}",
+                warnings
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| format!("{}: {w}\n", i + 1))
+                    .collect::<String>()
+            )),
+        }
+    }
+
+    /// Assign the result of a replacement function back to its target
+    ///
+    /// Nested targets, such as `names(x$a) <- value`, are themselves
+    /// assigned to through a temporary `*tmp*` variable.
+    ///
+    pub fn assign_replacement(&mut self, to: Expr, from: Obj) -> EvalResult {
+        const TMP: &str = "*tmp*";
+        match to {
+            to @ Expr::Call(..) => {
+                self.env().insert(TMP.to_string(), from);
+                let result = self.assign_lazy(to, Expr::Symbol(TMP.to_string()));
+                self.env().values.borrow_mut().remove(TMP);
+                result
+            }
+            to => self.assign(to, from),
+        }
+    }
+
+    /// Assign to a variable in the environment which defines it, as `<<-`
+    ///
+    /// The parents of the current environment are searched for an existing
+    /// binding, assigning in the global environment when none is found.
+    /// Replacements, like `x[[i]] <<- value`, modify the value found in a
+    /// parent environment and rebind it there.
+    pub fn assign_super(&mut self, to: Expr, from: Expr) -> EvalResult {
+        const TMP: &str = "*tmp*";
+        let Some((name, target)) = retarget(to, TMP) else {
+            return Error::IncorrectContext("<<-".to_string()).into();
+        };
+
+        // bindings of builtins are never reassigned
+        let binding = self
+            .env()
+            .parent
+            .as_ref()
+            .and_then(|parent| parent.binding(&name))
+            .filter(|env| env.parent.is_some());
+
+        if let Expr::Symbol(_) = target {
+            let value = self.eval_and_finalize(from)?;
+            let env = binding.unwrap_or_else(|| self.global_env());
+            env.insert(name, value.clone());
+            return Ok(value);
+        }
+
+        let Some(mut env) = binding else {
+            return Error::VariableNotFound(name).into();
+        };
+
+        let value = env.get(name.clone())?;
+        self.env().insert(TMP.to_string(), value);
+        let result = self.assign_lazy(target, from);
+        if let Some(value) = self.env().values.borrow_mut().remove(TMP) {
+            env.insert(name, value);
+        }
+
+        result
+    }
+
+    /// The environment in which top-level expressions are evaluated
+    pub fn global_env(&self) -> Rc<Environment> {
+        self.frames[0].env.clone()
+    }
+
+    /// Call a function object with already-evaluated arguments
+    ///
+    /// Arguments are bound in a temporary environment so that the call is
+    /// dispatched just like a call written in code, as `FUN(..1, ..2)`.
+    pub fn call_with(&mut self, f: Obj, args: Vec<(Option<String>, Obj)>) -> EvalResult {
+        self.call_with_dispatch(f, args, None)
+    }
+
+    /// Call an S3 method, recording the dispatch state for `NextMethod()`
+    pub fn call_method(&mut self, method: Obj, dispatch: Dispatch) -> EvalResult {
+        let args = dispatch.args.clone();
+        self.call_with_dispatch(method, args, Some(dispatch))
+    }
+
+    /// Find an S3 method for a generic
+    ///
+    /// Functions named `generic.class` are searched for each class in turn,
+    /// through each environment chain in turn. The method is returned
+    /// alongside the classes that follow the one it was found for.
+    pub fn find_method(
+        &self,
+        generic: &str,
+        classes: &[String],
+        envs: &[Rc<Environment>],
+    ) -> Option<(Obj, Vec<String>)> {
+        for (i, class) in classes.iter().enumerate() {
+            for env in envs {
+                let name = format!("{generic}.{class}");
+                if let Ok(f @ Obj::Function(..)) = Environment::get(env, name) {
+                    return Some((f, classes[i + 1..].to_vec()));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Dispatch a call to a builtin generic to an S3 method
+    ///
+    /// Builtins only dispatch on an explicit `class` attribute, falling back
+    /// to their own implementation when no method is found. A builtin which
+    /// is itself being called as the next method of a dispatch is not
+    /// dispatched again.
+    pub fn dispatch(
+        &mut self,
+        generic: &str,
+        object: &Obj,
+        args: Vec<(Option<String>, Obj)>,
+    ) -> Option<EvalResult> {
+        let classes = object.explicit_class()?;
+
+        let n = self.frames.len();
+        let caller = n
+            .checked_sub(2)
+            .and_then(|i| self.frames[i].dispatch.as_ref());
+        if caller.is_some_and(|d| d.generic == generic) {
+            return None;
+        }
+
+        let (method, classes) = self.find_method(generic, &classes, &[self.env()])?;
+        let dispatch = Dispatch {
+            generic: generic.to_string(),
+            classes,
+            args,
+        };
+
+        Some(self.call_method(method, dispatch))
+    }
+
+    /// Dispatch a call to a builtin operator to an S3 method
+    ///
+    /// Methods are searched for the class of the left-hand side, followed
+    /// by the class of the right-hand side.
+    pub fn dispatch_operator(&mut self, op: &str, lhs: &Obj, rhs: &Obj) -> Option<EvalResult> {
+        let args = vec![(None, lhs.clone()), (None, rhs.clone())];
+        self.dispatch(op, lhs, args.clone())
+            .or_else(|| self.dispatch(op, rhs, args))
+    }
+
+    fn call_with_dispatch(
+        &mut self,
+        f: Obj,
+        args: Vec<(Option<String>, Obj)>,
+        dispatch: Option<Dispatch>,
+    ) -> EvalResult {
+        let env = Rc::new(Environment {
+            parent: Some(self.env()),
+            ..Default::default()
+        });
+
+        let args: ExprList = args
+            .into_iter()
+            .enumerate()
+            .map(|(i, (k, v))| {
+                // forced promises are passed on as their value
+                let v = match v {
+                    Obj::Promise(Some(value), ..) => *value,
+                    v => v,
+                };
+
+                let sym = format!("..{}", i + 1);
+                env.insert(sym.clone(), v);
+                (k, Expr::Symbol(sym))
+            })
+            .collect();
+
+        // primitives are called directly, evaluating arguments in this frame
+        let what = match f {
+            Obj::Function(_, Expr::Primitive(p), _) => Expr::Primitive(p),
+            f => {
+                env.insert("FUN".to_string(), f);
+                Expr::Symbol("FUN".to_string())
+            }
+        };
+
+        let call = Expr::Call(Box::new(what), args);
+        self.add_frame(call.clone(), env);
+        if let Some(frame) = self.frames.last_mut() {
+            frame.dispatch = dispatch;
+        }
+
+        let result = self.eval_and_finalize(call);
+        self.pop_frame_and_return(result)
+    }
+
+    /// Evaluate, and clear, the `on.exit()` handlers of the last frame
+    fn run_on_exit(&mut self) -> Result<(), Signal> {
+        let Some(frame) = self.frames.last_mut() else {
+            return Ok(());
+        };
+
+        let handlers = std::mem::take(&mut frame.on_exit);
+        let env = frame.env.clone();
+
+        for expr in handlers {
+            self.add_frame(expr.clone(), env.clone());
+            let result = self.eval_and_finalize(expr);
+            self.pop_frame_and_return(result)?;
+        }
+
+        Ok(())
+    }
+
+    /// Discard frames down to a given stack depth, running the `on.exit()`
+    /// handlers of each frame as it is removed.
+    ///
+    /// Frames are left on the stack when an error is raised so that they
+    /// can be reported in a backtrace. Once an error has been handled, or
+    /// reported at the top level, the stack is unwound.
+    pub fn unwind(&mut self, depth: usize) -> Result<(), Signal> {
+        while self.frames.len() > depth {
+            self.run_on_exit()?;
+            self.frames.pop();
+        }
+
+        Ok(())
+    }
+
     pub fn pop_frame_and_return(&mut self, result: EvalResult) -> EvalResult {
         match result {
             Ok(..) => {
+                self.run_on_exit()?;
                 self.frames.pop();
                 result
             }
@@ -806,10 +1387,52 @@ impl Context for CallStack {
                     let result = self.eval_and_finalize(from)?;
                     return self.assign(Expr::List(args), result);
                 }
+                // replacement functions, `f(x, ...) <- value` is evaluated
+                // as `x <- `f<-`(x, ..., value = value)`
                 Expr::String(s) | Expr::Symbol(s) => {
-                    args.insert(0, from);
+                    let Some(target) = args.get(0) else {
+                        return err;
+                    };
+
+                    args.push((Some("value".to_string()), from));
                     let s = format!("{}<-", s);
-                    return self.eval(Expr::Call(Box::new(Expr::Symbol(s)), args));
+                    let result =
+                        self.eval_and_finalize(Expr::Call(Box::new(Expr::Symbol(s)), args))?;
+                    return self.assign_replacement(target, result);
+                }
+                // nested replacements, `f(x)[i] <- value` is evaluated as
+                // `*tmp*` <- f(x); `*tmp*`[i] <- value; f(x) <- `*tmp*`
+                Expr::Primitive(p) if is_replacement_call(args.values.first()) => {
+                    const TMP: &str = "*tmp*";
+                    let inner = std::mem::replace(&mut args.values[0], Expr::Symbol(TMP.into()));
+                    let value = match self.eval_and_finalize(inner.clone())? {
+                        // replacing part of a value which isn't set, like the
+                        // names of an unnamed vector, starts from a missing
+                        // value for each element of the object
+                        Obj::Null => {
+                            let n = match &inner {
+                                Expr::Call(_, inner_args) => match inner_args.values.first() {
+                                    Some(x) => self.eval_and_finalize(x.clone())?.len(),
+                                    None => None,
+                                },
+                                _ => None,
+                            };
+
+                            Obj::Vector(Vector::from(vec![Logical::NA; n.unwrap_or_default()]))
+                        }
+                        value => value,
+                    };
+
+                    self.env().insert(TMP.to_string(), value);
+                    let result = p.call_assign(from, args, self);
+                    let value = self.env().values.borrow_mut().remove(TMP);
+
+                    let result = result?;
+                    if let Some(value) = value {
+                        self.assign_replacement(inner, value)?;
+                    }
+
+                    return Ok(result);
                 }
                 Expr::Primitive(p) => return p.call_assign(from, args, self),
                 _ => return err,
@@ -902,8 +1525,11 @@ impl Context for CallStack {
     }
 
     fn get(&mut self, name: String) -> EvalResult {
-        let (obj, _) = self.find(name.clone())?;
-        Ok(obj.clone())
+        match self.find(name.clone())? {
+            // forced promises are passed on as their value
+            (Obj::Promise(Some(value), ..), _) => Ok((*value).clone()),
+            (obj, _) => Ok(obj.clone()),
+        }
     }
 
     fn get_mut(&mut self, name: String) -> EvalResult {
@@ -912,10 +1538,12 @@ impl Context for CallStack {
         let objc = match (self.env() == obj_source_env, obj) {
             // when accessed mutably, promises are always masked by materialized value
             (_, Obj::Promise(Some(x), ..)) => *x.clone(),
-            (true, obj) => return Ok(obj),
-            (false, obj) => obj.clone(),
+            (true, obj) if !obj.is_lazy() => return Ok(obj),
+            (_, obj) => obj.clone(),
         };
 
+        // lazy vectors are replaced by their materialized values, to be modified
+        objc.materialize_lazy();
         self.env().insert(name, objc.view_mut());
         Ok(objc)
     }
@@ -928,6 +1556,35 @@ impl Context for CallStack {
     // Try.
 }
 
+/// Replace the variable targeted by an assignment, like `x` in `names(x)[1]`
+///
+/// Produces the name of the variable and the target with the variable
+/// replaced by a symbol.
+///
+fn retarget(to: Expr, with: &str) -> Option<(String, Expr)> {
+    match to {
+        Expr::String(s) | Expr::Symbol(s) => Some((s, Expr::Symbol(with.to_string()))),
+        Expr::Call(what, mut args) => {
+            let first = args.values.first_mut()?;
+            let (name, target) = retarget(first.clone(), with)?;
+            *first = target;
+            Some((name, Expr::Call(what, args)))
+        }
+        _ => None,
+    }
+}
+
+/// Whether an assignment target calls a replacement function, like `names(x)`
+fn is_replacement_call(target: Option<&Expr>) -> bool {
+    match target {
+        Some(Expr::Call(what, _)) => match &**what {
+            Expr::String(s) | Expr::Symbol(s) => s != "list",
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 fn eval_call(callstack: &mut CallStack, expr: Expr, mutable: bool) -> EvalResult {
     let Expr::Call(what, args) = expr.clone() else {
         return internal_err!();
@@ -977,6 +1634,7 @@ fn eval_call(callstack: &mut CallStack, expr: Expr, mutable: bool) -> EvalResult
 
             // introduce a new call frame and evaluate body in new frame
             callstack.add_child_frame(expr, env.clone());
+            callstack.last_frame_mut().to = obj.clone();
 
             // handle tail call recursion
             let mut result = obj.call(args, callstack);
@@ -999,7 +1657,7 @@ fn eval_call(callstack: &mut CallStack, expr: Expr, mutable: bool) -> EvalResult
                         let (args, ellipsis) = what_obj.match_args(args, callstack)?;
 
                         // pop tail frame and add a new local frame
-                        callstack.frames.pop();
+                        callstack.unwind(callstack.frames.len() - 1)?;
                         callstack.add_child_frame(tail, env.clone());
 
                         // call with pre-matched args
@@ -1019,8 +1677,18 @@ fn eval_call(callstack: &mut CallStack, expr: Expr, mutable: bool) -> EvalResult
             callstack.pop_frame_and_return(result)
         }
         _ => {
-            callstack.add_frame(expr, callstack.last_frame().env().clone());
-            let result = (callstack.eval(*what)?).call_mut(args, callstack);
+            let obj = callstack.eval(*what)?;
+
+            // closures are evaluated in a new environment, just like named calls
+            match &obj {
+                Obj::Function(_, body, env) if !matches!(body, Expr::Primitive(_)) => {
+                    callstack.add_child_frame(expr, env.clone())
+                }
+                _ => callstack.add_frame(expr, callstack.last_frame().env().clone()),
+            };
+
+            callstack.last_frame_mut().to = obj.clone();
+            let result = obj.call_mut(args, callstack);
             callstack.pop_frame_and_return(result)
         }
     }
@@ -1215,6 +1883,20 @@ mod test {
         )
     }
 
+    #[test]
+    fn fn_defaults_after_ellipsis() {
+        assert_eq!(r! { f <- fn(..., x = 2) x; f(1) }, r! { 2 });
+        assert_eq!(r! { f <- fn(..., x = 2) x; f(1, x = 3) }, r! { 3 });
+    }
+
+    #[test]
+    fn fn_forced_promises_reused() {
+        assert_eq!(
+            r! { f <- function(x) c(x, x); y <- 1L; f(y) },
+            r! { c(1L, 1L) }
+        );
+    }
+
     #[test]
     fn fn_duplicated_parameters() {
         assert_eq!(
@@ -1252,6 +1934,63 @@ mod test {
         "}}
     }
 
+    #[test]
+    fn super_assign_rebinds_in_defining_scope() {
+        r_expect! {{"
+            counter <- fn() { i <- 0; fn() { i <<- i + 1; i } }
+            count <- counter()
+            count()
+            count() == 2
+        "}}
+    }
+
+    #[test]
+    fn super_assign_binds_globally_when_unbound() {
+        r_expect! {{"
+            f <- fn() { g <- fn() { y <<- 3 }; g() }
+            f()
+            y == 3
+        "}}
+    }
+
+    #[test]
+    fn super_assign_replacement() {
+        r_expect! {{r#"
+            memo <- fn() { cache <- list(); fn(k) { cache[[k]] <<- nchar(k); cache } }
+            m <- memo()
+            m("ab")
+            cache <- m("abc")
+            cache$ab == 2 && cache$abc == 3
+        "#}}
+    }
+
+    #[test]
+    fn super_assign_replacement_copies() {
+        r_expect! {{"
+            x <- c(1, 2)
+            y <- x
+            f <- fn() x[1] <<- 5
+            f()
+            x[1] == 5 && y[1] == 1
+        "}}
+    }
+
+    #[test]
+    fn rightward_assign() {
+        r_expect! {{"
+            f <- fn() { 2 -> a; a ->> b; a }
+            f() == 2 && b == 2
+        "}}
+    }
+
+    #[test]
+    fn anonymous_call_binds_locally() {
+        r_expect! {{"
+            x <- 1
+            (fn(x) x * 2)(5) == 10 && x == 1
+        "}}
+    }
+
     #[test]
     fn fn_assign_curly_causes_binding() {
         r_expect! {{"
@@ -9,5 +9,6 @@ pub mod lang;
 pub mod object;
 pub mod parser;
 pub mod repl;
+pub mod rng;
 pub mod session;
 pub mod utils;
@@ -77,13 +77,18 @@ impl fmt::Display for Expr {
             Expr::Bool(false) => write!(f, "FALSE"),
             Expr::Number(x) => write!(f, "{}", x),
             Expr::Integer(x) => write!(f, "{}L", x),
-            Expr::String(x) => write!(f, "\"{}\"", x),
+            Expr::String(x) => write!(f, "\"{}\"", crate::utils::escape(x)),
             Expr::Symbol(x) => write!(f, "{}", x),
             Expr::List(x) => write!(f, "{}", x),
             Expr::Ellipsis(None) => write!(f, "..."),
             Expr::Ellipsis(Some(s)) => write!(f, "..{s}"),
             Expr::Call(what, args) => match &**what {
                 Expr::Primitive(p) => write!(f, "{}", p.rfmt_call(args)),
+                Expr::Symbol(s)
+                    if s.len() > 1 && s.starts_with('%') && s.ends_with('%') && args.len() == 2 =>
+                {
+                    write!(f, "{} {s} {}", args.values[0], args.values[1])
+                }
                 Expr::String(s) | Expr::Symbol(s) => write!(f, "{}({})", s, args),
                 rexpr => write!(f, "{}({})", rexpr, args),
             },
@@ -0,0 +1,112 @@
+use super::*;
+
+/// Object Attributes
+///
+/// An ordered collection of named values attached to a vector or list, such
+/// as its `names`, `dim` or `class`. Attributes share the copy-on-write
+/// semantics of the data they describe, such that lazy copies of an object
+/// only copy its attributes when they are modified.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Attributes(CowObj<Vec<(String, Obj)>>);
+
+impl ViewMut for Attributes {
+    fn view_mut(&self) -> Self {
+        Attributes(self.0.view_mut())
+    }
+}
+
+impl Attributes {
+    pub fn get(&self, name: &str) -> Option<Obj> {
+        self.0
+            .borrow()
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.clone())
+    }
+
+    /// Set an attribute value
+    ///
+    /// Setting an attribute to `NULL` removes it. New attributes are
+    /// appended after existing ones, while existing attributes retain
+    /// their position.
+    ///
+    pub fn set(&self, name: &str, value: Obj) {
+        self.0.with_inner_mut(|attrs| {
+            let pos = attrs.iter().position(|(k, _)| k == name);
+            match (pos, value) {
+                (Some(i), Obj::Null) => {
+                    attrs.remove(i);
+                }
+                (None, Obj::Null) => (),
+                (Some(i), value) => attrs[i].1 = value,
+                (None, value) => attrs.push((name.to_string(), value)),
+            }
+        })
+    }
+
+    /// The extents of each dimension, as set by the `dim` attribute
+    pub fn dim(&self) -> Option<Vec<usize>> {
+        let Some(Obj::Vector(dim)) = self.get("dim") else {
+            return None;
+        };
+
+        let Vector::Integer(dim) = dim.as_integer() else {
+            unreachable!()
+        };
+
+        dim.into_iter()
+            .map(|d| match d {
+                OptionNA::Some(d) if d >= 0 => Some(d as usize),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> CowObjIter<(String, Obj)> {
+        self.0.iter()
+    }
+
+    /// Combine two sets of attributes
+    ///
+    /// Attributes of `self` take precedence, with any attributes only
+    /// present in `other` appended.
+    ///
+    pub fn merge(&self, other: &Attributes) -> Attributes {
+        let result = self.clone();
+        for (k, v) in other.iter() {
+            if result.get(&k).is_none() {
+                result.set(&k, v)
+            }
+        }
+        result
+    }
+
+    /// Retain only the attributes that are kept when subsetting
+    ///
+    /// When an object is subset, its `names` are subset alongside its
+    /// values while all other attributes are dropped.
+    ///
+    pub fn subset(&self, subset: Subset) -> Attributes {
+        let result = Attributes::default();
+        if let Some(Obj::Vector(names)) = self.get("names") {
+            result.set("names", Obj::Vector(names.subset(subset)))
+        }
+        result
+    }
+}
+
+impl From<Vec<(String, Obj)>> for Attributes {
+    fn from(value: Vec<(String, Obj)>) -> Self {
+        Attributes(CowObj::from(value))
+    }
+}
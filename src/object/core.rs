@@ -19,6 +19,7 @@ pub enum Obj {
     Promise(Option<Box<Obj>>, Expr, Rc<Environment>),
     Function(ExprList, Expr, Rc<Environment>),
     Environment(Rc<Environment>),
+    Formula(Expr, Rc<Environment>, Attributes),
 }
 
 impl PartialEq for Obj {
@@ -35,6 +36,7 @@ impl PartialEq for Obj {
                     .all(|((lk, lv), (rk, rv))| lk == rk && lv == rv)
             }
             (Obj::Expr(l), Obj::Expr(r)) => l == r,
+            (Obj::Formula(l, _, la), Obj::Formula(r, _, ra)) => l == r && la == ra,
             (Obj::Promise(None, lc, lenv), Obj::Promise(None, rc, renv)) => {
                 lc == rc && lenv == renv
             }
@@ -0,0 +1,272 @@
+use std::fmt;
+
+use super::types::{Character, Integer};
+use super::*;
+use crate::error::Error;
+use crate::lang::{EvalResult, Signal};
+
+/// Data Frames
+///
+/// A data frame is a `list` of equal-length column vectors, distinguished
+/// from other lists by its `data.frame` class. Alongside its columns, it
+/// carries a `row.names` attribute naming each of its rows.
+///
+impl List {
+    /// Create a data frame from named columns
+    ///
+    /// Columns are recycled to the length of the longest column, which must
+    /// be a multiple of the length of every other column.
+    ///
+    pub fn data_frame(columns: Vec<(String, Vector)>) -> Result<List, Signal> {
+        let nrow = columns.iter().map(|(_, v)| v.len()).max().unwrap_or(0);
+        if columns
+            .iter()
+            .any(|(_, v)| v.is_empty() && nrow > 0 || !v.is_empty() && nrow % v.len() != 0)
+        {
+            // report each distinct number of rows, in argument order
+            let mut rows: Vec<String> = vec![];
+            for (_, v) in columns.iter() {
+                let n = v.len().to_string();
+                if !rows.contains(&n) {
+                    rows.push(n);
+                }
+            }
+
+            let msg = format!(
+                "arguments imply differing number of rows: {}",
+                rows.join(", ")
+            );
+            return Error::Other(msg).into();
+        }
+
+        let columns = columns
+            .into_iter()
+            .map(|(name, v)| (Some(name), Obj::Vector(recycle(v, nrow))))
+            .collect::<Vec<_>>();
+
+        let row_names: Vec<i32> = (1..=nrow as i32).collect();
+        let result = List::from(columns);
+        result
+            .attributes
+            .set("class", Obj::Vector(vec!["data.frame".to_string()].into()));
+        result
+            .attributes
+            .set("row.names", Obj::Vector(row_names.into()));
+        Ok(result)
+    }
+
+    /// Whether a list is a data frame, as marked by its class
+    pub fn is_data_frame(&self) -> bool {
+        match self.attributes.get("class") {
+            Some(Obj::Vector(class)) => Vec::<String>::from(class).contains(&"data.frame".into()),
+            _ => false,
+        }
+    }
+
+    /// The number of rows of a data frame
+    pub fn nrow(&self) -> usize {
+        match self.attributes.get("row.names") {
+            Some(Obj::Vector(row_names)) => row_names.len(),
+            _ => self.columns().first().map_or(0, |(_, v)| v.len()),
+        }
+    }
+
+    /// The names of the rows of a data frame
+    pub fn row_names(&self) -> Vec<String> {
+        match self.attributes.get("row.names") {
+            Some(Obj::Vector(row_names)) => row_names.into(),
+            _ => (1..=self.nrow()).map(|i| i.to_string()).collect(),
+        }
+    }
+
+    /// The named columns of a data frame
+    pub fn columns(&self) -> Vec<(String, Vector)> {
+        self.materialize()
+            .values
+            .iter()
+            .filter_map(|(k, v)| match v {
+                Obj::Vector(v) => Some((k.unwrap_or_default(), v)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Select rows and columns of a data frame
+    ///
+    /// Rows and columns are selected by their (0-indexed) positions, with
+    /// `None` row positions producing rows of `NA`s.
+    ///
+    pub fn select(&self, rows: Option<Vec<Option<usize>>>, cols: Vec<usize>) -> List {
+        let columns = self.columns();
+        let row_names = self.row_names();
+
+        let subset = rows.as_ref().map(|rows| {
+            let indices: Vec<Integer> = rows
+                .iter()
+                .map(|i| i.map_or(OptionNA::NA, |i| OptionNA::Some(i as i32)))
+                .collect();
+            Subset::Indices(indices.into())
+        });
+
+        let columns = cols
+            .into_iter()
+            .map(|j| {
+                let (name, v) = columns[j].clone();
+                let v = match &subset {
+                    Some(subset) => v.subset(subset.clone()).materialize(),
+                    None => v,
+                };
+                (Some(name), Obj::Vector(v))
+            })
+            .collect::<Vec<_>>();
+
+        let row_names: Vec<Character> = match rows {
+            Some(rows) => rows
+                .iter()
+                .map(|i| i.map_or(OptionNA::NA, |i| OptionNA::Some(row_names[i].clone())))
+                .collect(),
+            None => row_names.into_iter().map(OptionNA::Some).collect(),
+        };
+
+        let mut result = List::from(columns);
+        result.attributes = self.attributes.clone();
+        result
+            .attributes
+            .set("row.names", Obj::Vector(row_names.into()));
+        result
+    }
+
+    /// Index a data frame by rows and columns
+    ///
+    /// A single index selects columns, while two indices select rows and
+    /// columns. Selecting a single column by both rows and columns produces
+    /// that column's vector rather than a data frame, unless `drop` is
+    /// `false`.
+    ///
+    pub fn try_get_frame(&self, mut indices: Vec<Option<Obj>>, drop: bool) -> EvalResult {
+        let (rows, cols, drop) = match indices.len() {
+            1 => (None, indices.pop().flatten(), false),
+            2 => {
+                let cols = indices.pop().flatten();
+                let rows = indices.pop().flatten();
+                let drop = drop && cols.is_some();
+                (rows, cols, drop)
+            }
+            _ => return Error::Other("incorrect number of dimensions".into()).into(),
+        };
+
+        let rows = match rows {
+            Some(rows) => Some(dim_positions(Some(rows), self.nrow())?),
+            None => None,
+        };
+
+        let cols = self.column_positions(cols)?;
+        let result = self.select(rows, cols.clone());
+
+        match (drop, cols.as_slice()) {
+            (true, [_]) => Ok(result.values.iter().next().map_or(Obj::Null, |(_, v)| v)),
+            _ => Ok(Obj::List(result)),
+        }
+    }
+
+    /// Resolve the (0-indexed) positions of columns selected by an index
+    fn column_positions(&self, index: Option<Obj>) -> Result<Vec<usize>, Signal> {
+        let names: Vec<String> = self.columns().into_iter().map(|(k, _)| k).collect();
+        let undefined = || Signal::from(Error::Other("undefined columns selected".into()));
+
+        match index {
+            Some(Obj::Vector(Vector::Character(index))) => Vec::<String>::from(Vector::from(index))
+                .into_iter()
+                .map(|name| names.iter().position(|n| *n == name).ok_or_else(undefined))
+                .collect(),
+            index => dim_positions(index, names.len())
+                .map_err(|_| undefined())?
+                .into_iter()
+                .map(|i| i.ok_or_else(undefined))
+                .collect(),
+        }
+    }
+
+    /// Format a data frame, aligning each column beneath its name
+    pub fn fmt_data_frame(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = self.columns();
+        let nrow = self.nrow();
+
+        if columns.is_empty() {
+            return write!(f, "data frame with 0 columns and {nrow} rows");
+        }
+
+        if nrow == 0 {
+            let names: Vec<_> = columns.into_iter().map(|(k, _)| k).collect();
+            return write!(
+                f,
+                "[1] {}\n<0 rows> (or 0-length row.names)",
+                names.join(" ")
+            );
+        }
+
+        let row_names = self.row_names();
+        let gutter = row_names.iter().map(|r| r.len()).max().unwrap_or(0);
+
+        let cells: Vec<(String, Vec<String>)> = columns
+            .into_iter()
+            .map(|(name, v)| {
+                let na = if let Vector::Character(_) = v {
+                    "<NA>"
+                } else {
+                    "NA"
+                };
+                let Vector::Character(v) = v.as_character() else {
+                    unreachable!()
+                };
+
+                let values = v
+                    .into_iter()
+                    .map(|x| match x {
+                        OptionNA::Some(x) => x,
+                        OptionNA::NA => na.to_string(),
+                    })
+                    .collect();
+
+                (name, values)
+            })
+            .collect();
+
+        let widths: Vec<usize> = cells
+            .iter()
+            .map(|(name, values)| {
+                values
+                    .iter()
+                    .chain([name])
+                    .map(|x| x.len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        write!(f, "{:gutter$}", "")?;
+        for ((name, _), width) in cells.iter().zip(&widths) {
+            write!(f, " {name:>width$}")?;
+        }
+
+        for (i, row_name) in row_names.iter().enumerate() {
+            write!(f, "\n{row_name:<gutter$}")?;
+            for ((_, values), width) in cells.iter().zip(&widths) {
+                write!(f, " {:>width$}", values[i])?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Recycle a vector to a given length
+pub fn recycle(v: Vector, n: usize) -> Vector {
+    if v.len() == n {
+        return v;
+    }
+
+    let len = v.len().max(1);
+    let indices: Vec<Integer> = (0..n).map(|i| OptionNA::Some((i % len) as i32)).collect();
+    v.subset(Subset::Indices(indices.into())).materialize()
+}
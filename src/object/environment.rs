@@ -16,6 +16,8 @@ use super::{Expr, ExprList, List, Obj};
 pub struct Environment {
     pub values: RefCell<HashMap<String, Obj>>,
     pub parent: Option<Rc<Environment>>,
+    // A name for well-known environments, such as the global environment
+    pub name: Option<String>,
 }
 
 impl fmt::Debug for Environment {
@@ -28,7 +30,10 @@ impl fmt::Debug for Environment {
 
 impl Environment {
     pub fn from_builtins() -> Rc<Environment> {
-        let env = Rc::new(Environment::default());
+        let env = Rc::new(Environment {
+            name: Some("base".to_string()),
+            ..Default::default()
+        });
         for (name, builtin) in BUILTIN.iter() {
             let builtin_fn = Obj::Function(
                 ExprList::new(),
@@ -41,6 +46,23 @@ impl Environment {
         env
     }
 
+    /// A new global environment, whose parent is a fresh builtins environment
+    pub fn global() -> Rc<Environment> {
+        Rc::new(Environment {
+            parent: Some(Environment::from_builtins()),
+            name: Some("R_GlobalEnv".to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// An environment without any bindings or parent
+    pub fn empty() -> Rc<Environment> {
+        Rc::new(Environment {
+            name: Some("R_EmptyEnv".to_string()),
+            ..Default::default()
+        })
+    }
+
     pub fn len(&self) -> usize {
         self.values.borrow().len()
     }
@@ -62,6 +84,19 @@ impl Environment {
         }
     }
 
+    /// The environment, either this one or one of its parents, which binds
+    /// a name
+    pub fn binding(self: &Rc<Self>, name: &str) -> Option<Rc<Environment>> {
+        let mut env = self.clone();
+        loop {
+            if env.values.borrow().contains_key(name) {
+                return Some(env);
+            }
+
+            env = env.parent.clone()?;
+        }
+    }
+
     pub fn get(&self, name: String) -> EvalResult {
         let (x, _) = self.find(name.clone())?;
         EvalResult::Ok(x.clone())
@@ -108,13 +143,15 @@ impl Environment {
 
     pub fn get_mut(&self, name: String) -> EvalResult {
         let (x, env) = self.find(name.clone())?;
-        if *self == *env {
+        if *self == *env && !x.is_lazy() {
             return EvalResult::Ok(x.view_mut());
         }
 
         // we found it in the parent environment, which means we first have to find it in the
-        // current environment so we then modify it in the correct scope
+        // current environment so we then modify it in the correct scope. Lazy vectors are
+        // likewise replaced by their materialized values.
         let xc = x.clone();
+        xc.materialize_lazy();
         let xm = xc.view_mut();
         self.insert(name, xc);
 
@@ -124,23 +161,9 @@ impl Environment {
 
 impl Display for Environment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "<environment {:?}", self.values.as_ptr())?;
-
-        // // print defined variable names
-        // if self.values.borrow().len() > 0 {
-        //     write!(f, " [")?;
-        // }
-        // for (i, k) in self.values.borrow().keys().enumerate() {
-        //     if i > 0 {
-        //         write!(f, ", ")?;
-        //     }
-        //     write!(f, "{}", k)?;
-        // }
-        // if self.values.borrow().len() > 0 {
-        //     write!(f, "]")?;
-        // }
-
-        write!(f, ">")?;
-        Ok(())
+        match &self.name {
+            Some(name) => write!(f, "<environment: {name}>"),
+            None => write!(f, "<environment: {:?}>", self.values.as_ptr()),
+        }
     }
 }
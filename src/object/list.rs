@@ -1,8 +1,9 @@
 use hashbrown::HashMap;
 
 use crate::error::Error;
-use crate::lang::EvalResult;
+use crate::lang::{EvalResult, Signal};
 
+use super::types::Character;
 use super::*;
 
 type ListNameMap = HashMap<String, Vec<usize>>;
@@ -12,6 +13,7 @@ pub struct List {
     pub names: CowObj<ListNameMap>,
     pub values: CowObj<Vec<(Option<String>, Obj)>>,
     pub subsets: Subsets,
+    pub attributes: Attributes,
 }
 
 impl From<Vec<(Option<String>, Obj)>> for List {
@@ -42,6 +44,62 @@ impl List {
         })
     }
 
+    /// Collect the values of a list, applying any subsets
+    pub fn materialize(&self) -> List {
+        if self.subsets.0.is_empty() {
+            return self.clone();
+        }
+
+        let values = self.values.borrow();
+        let items = self
+            .subsets
+            .clone()
+            .bind_names(self.names.clone())
+            .indices(values.len())
+            .map(|i| i.map_or((None, Obj::Null), |i| values[i].clone()))
+            .collect::<Vec<_>>();
+
+        let mut result = List::from(items);
+        result.attributes = self.attributes.clone();
+        result
+    }
+
+    /// Get the names of list elements, or `None` if no element is named
+    pub fn names(&self) -> Option<Vec<Character>> {
+        let values = self.values.borrow();
+        if values.iter().all(|(k, _)| k.is_none()) {
+            return None;
+        }
+
+        Some(
+            values
+                .iter()
+                .map(|(k, _)| match k {
+                    Some(name) => OptionNA::Some(name.clone()),
+                    None => OptionNA::NA,
+                })
+                .collect(),
+        )
+    }
+
+    /// Set the names of list elements
+    ///
+    /// Element names are replaced by `names`, with missing or `NA` names
+    /// leaving the element unnamed.
+    ///
+    pub fn set_names(&mut self, names: Vec<Character>) {
+        *self = self.materialize();
+        self.values.with_inner_mut(|values| {
+            for (i, (k, _)) in values.iter_mut().enumerate() {
+                *k = match names.get(i) {
+                    Some(OptionNA::Some(name)) => Some(name.clone()),
+                    _ => None,
+                }
+            }
+        });
+        self.reindex();
+    }
+
     pub fn subset(&self, by: Subset) -> List {
         let Subsets(mut inner) = self.subsets.clone();
         inner.push(by);
@@ -49,6 +107,7 @@ impl List {
             names: self.names.clone(),
             values: self.values.view_mut(),
             subsets: Subsets(inner),
+            attributes: Attributes::default(),
         }
     }
 
@@ -61,11 +120,15 @@ impl List {
                     .subsets
                     .clone()
                     .bind_names(self.names.clone())
-                    .into_iter()
-                    .take(n);
+                    .indices(n);
+
+                // remove from the back, such that indices remain valid
+                let mut indices: Vec<usize> = indices.flatten().collect();
+                indices.sort();
+                indices.dedup();
 
                 self.values.with_inner_mut(|values| {
-                    for (i, _) in indices {
+                    for i in indices.into_iter().rev() {
                         values.remove(i);
                     }
                 });
@@ -79,6 +142,7 @@ impl List {
                     names: self.names.clone(),
                     values: self.values.clone(),
                     subsets: self.subsets.clone(),
+                    attributes: self.attributes.clone(),
                 }))
             }
 
@@ -89,27 +153,12 @@ impl List {
                     .subsets
                     .clone()
                     .bind_names(self.names.clone())
-                    .into_iter()
-                    .take(n);
+                    .indices(n);
 
                 self.values.with_inner_mut(|v| {
-                    // first check to see if we need to extend
-                    if let Some(max) = self
-                        .subsets
-                        .clone()
-                        .bind_names(self.names.clone())
-                        .into_iter()
-                        .map(|(i, _)| i)
-                        .max()
-                    {
-                        v.reserve(max.saturating_sub(n))
-                    }
-
                     // then assign to indices
-                    for (_, i) in indices {
-                        if let Some(i) = i {
-                            v[i].1 = any.clone()
-                        }
+                    for i in indices.flatten() {
+                        v[i].1 = any.clone()
                     }
                 });
 
@@ -117,6 +166,7 @@ impl List {
                     names: self.names.clone(),
                     values: self.values.clone(),
                     subsets: self.subsets.clone(),
+                    attributes: self.attributes.clone(),
                 }))
             }
             // vectorized assignment
@@ -127,24 +177,11 @@ impl List {
                     .subsets
                     .clone()
                     .bind_names(self.names.clone())
-                    .into_iter()
-                    .take(n);
+                    .indices(n);
 
                 self.values.with_inner_mut(|v| {
-                    // first check to see if we need to extend
-                    if let Some(max) = self
-                        .subsets
-                        .clone()
-                        .bind_names(self.names.clone())
-                        .into_iter()
-                        .map(|(i, _)| i)
-                        .max()
-                    {
-                        v.reserve(max.saturating_sub(n))
-                    }
-
                     // then assign to indices
-                    for (any_i, (_, i)) in indices.enumerate() {
+                    for (any_i, i) in indices.enumerate() {
                         if let (Some(value), Some(i)) = (any.get(any_i), i) {
                             v[i].1 = value;
                         }
@@ -155,6 +192,7 @@ impl List {
                     names: self.names.clone(),
                     values: self.values.clone(),
                     subsets: self.subsets.clone(),
+                    attributes: self.attributes.clone(),
                 }))
             }
             other => {
@@ -163,27 +201,12 @@ impl List {
                     .subsets
                     .clone()
                     .bind_names(self.names.clone())
-                    .into_iter()
-                    .take(n);
+                    .indices(n);
 
                 self.values.with_inner_mut(|v| {
-                    // first check to see if we need to extend
-                    if let Some(max) = self
-                        .subsets
-                        .clone()
-                        .bind_names(self.names.clone())
-                        .into_iter()
-                        .map(|(i, _)| i)
-                        .max()
-                    {
-                        v.reserve(max.saturating_sub(n))
-                    }
-
                     // then assign to indices
-                    for (_, i) in indices {
-                        if let Some(i) = i {
-                            v[i].1 = other.clone()
-                        }
+                    for i in indices.flatten() {
+                        v[i].1 = other.clone()
                     }
                 });
 
@@ -191,6 +214,7 @@ impl List {
                     names: self.names.clone(),
                     values: self.values.clone(),
                     subsets: self.subsets.clone(),
+                    attributes: self.attributes.clone(),
                 }))
             }
         }
@@ -199,37 +223,107 @@ impl List {
     pub fn try_get(&self, index: Obj) -> EvalResult {
         let err = Error::Other("Cannot use object for indexing".to_string());
         match index.as_vector()? {
-            Obj::Vector(v) => Ok(Obj::List(self.subset(v.try_into()?))),
+            Obj::Vector(v) => {
+                let result = self.subset(v.try_into()?);
+
+                // elements out of bounds are NULL, which only exist once materialized
+                let n = self.values.len();
+                let out_of_bounds = result
+                    .subsets
+                    .clone()
+                    .bind_names(self.names.clone())
+                    .indices(n)
+                    .any(|i| i.is_none());
+
+                match out_of_bounds {
+                    true => Ok(Obj::List(result.materialize())),
+                    false => Ok(Obj::List(result)),
+                }
+            }
             _ => Err(err.into()),
         }
     }
 
-    pub fn try_get_inner_mut(&self, index: Obj) -> EvalResult {
+    /// Find the position of the element selected by a `[[` index
+    fn inner_index(&self, index: Obj) -> Result<Option<usize>, Signal> {
         let err_invalid = Error::Other("Cannot use object for indexing".to_string());
-        let err_index_invalid = Error::Other("Index out of bounds".to_string());
 
         match index.as_vector()? {
             Obj::Vector(v) if v.len() == 1 => {
+                let subset: Subset = v.try_into()?;
+                let by_name = matches!(subset, Subset::Names(_));
+
                 let Subsets(mut subsets) = self.subsets.clone();
-                subsets.push(v.try_into()?);
+                subsets.push(subset);
 
-                if let Some((i, _)) = Subsets(subsets)
+                let n = self.values.len();
+                let i = Subsets(subsets)
                     .bind_names(self.names.clone())
-                    .into_iter()
+                    .indices(n)
                     .next()
-                {
-                    self.values.with_inner_mut(|v| {
-                        v.get_mut(i)
-                            .map_or(Err(err_index_invalid.into()), |(_, i)| Ok(i.view_mut()))
-                    })
-                } else {
-                    Ok(Obj::Null)
+                    .flatten();
+
+                // missing names select nothing, while other indices must be in bounds
+                match (i, by_name) {
+                    (None, false) => Err(Error::Other("Index out of bounds".to_string()).into()),
+                    (i, _) => Ok(i),
                 }
             }
             _ => Err(err_invalid.into()),
         }
     }
 
+    pub fn try_get_inner_mut(&self, index: Obj) -> EvalResult {
+        let err_index_invalid = Error::Other("Index out of bounds".to_string());
+
+        if let Some(i) = self.inner_index(index)? {
+            self.values.with_inner_mut(|v| {
+                v.get_mut(i)
+                    .map_or(Err(err_index_invalid.into()), |(_, i)| {
+                        // lazy elements are materialized, as views may modify them
+                        i.materialize_lazy();
+                        Ok(i.view_mut())
+                    })
+            })
+        } else {
+            Ok(Obj::Null)
+        }
+    }
+
+    /// Replace the element selected by a `[[` index
+    ///
+    /// Unlike assignment into an element, the element may be replaced by a
+    /// value of any type.
+    ///
+    pub fn set_inner(&self, index: Obj, value: Obj) -> EvalResult {
+        let err_index_invalid = Error::Other("Index out of bounds".to_string());
+        let err_target = Error::Other("Invalid target for assignment".to_string());
+
+        let Some(i) = self.inner_index(index.clone())? else {
+            // elements of new names are appended
+            let (Obj::Vector(name), true) = (index, self.subsets.0.is_empty()) else {
+                return Err(err_target.into());
+            };
+
+            let name: Vec<String> = name.into();
+            let i = self.values.len();
+            self.values
+                .with_inner_mut(|v| v.push((Some(name[0].clone()), value.clone())));
+            self.names
+                .with_inner_mut(|names| names.entry(name[0].clone()).or_default().push(i));
+
+            return Ok(value);
+        };
+
+        self.values.with_inner_mut(|v| match v.get_mut(i) {
+            Some((_, x)) => {
+                *x = value.clone();
+                Ok(value)
+            }
+            None => Err(err_index_invalid.into()),
+        })
+    }
+
     pub fn try_get_inner(&self, index: Obj) -> EvalResult {
         #[allow(clippy::map_clone)]
         self.try_get_inner_mut(index).map(|v| v.clone())
@@ -265,11 +359,7 @@ impl List {
     }
 
     pub fn len(&self) -> usize {
-        let Subsets(inner) = &self.subsets;
-        match inner.as_slice() {
-            [] => self.values.borrow().len(),
-            [.., last] => std::cmp::min(self.values.borrow().len(), last.len()),
-        }
+        self.subsets.selected_len(self.values.len())
     }
 
     #[must_use]
@@ -289,6 +379,22 @@ mod tests {
         assert_eq!(r!((1,)), r!(list(1)));
     }
 
+    #[test]
+    fn negative_index() {
+        r_expect! {{"
+            l <- list(1, 2, 3)[-1]
+            l[[1]] == 2 && l[[2]] == 3
+        "}};
+        r_expect! { length(list(1, 2, 3)[c(-1, -2)]) == 1 };
+    }
+
+    #[test]
+    fn out_of_bounds_index() {
+        assert_eq!(r! { list(1, 2, 3)[5] }, r! { list(NULL) });
+        assert_eq!(r! { list(1, 2, 3)[c(1, 5)] }, r! { list(1, NULL) });
+        r_expect! { is.null(list(1, 2, 3)[5][[1]]) };
+    }
+
     #[test]
     fn copy_on_write_single_bracket() {
         r_expect! {{"
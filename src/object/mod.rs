@@ -15,3 +15,9 @@ pub use list::*;
 
 mod cow;
 pub use cow::*;
+
+mod attributes;
+pub use attributes::*;
+
+mod data_frame;
+pub use data_frame::*;
@@ -15,6 +15,9 @@ pub trait AtomicMode {
     fn is_character() -> bool {
         false
     }
+
+    /// Produce an element of a sequence from its numeric value
+    fn from_seq(x: f64) -> Self;
 }
 
 pub trait CoercibleInto<T>: Sized {
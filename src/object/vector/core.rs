@@ -2,8 +2,8 @@ use std::fmt::Debug;
 use std::fmt::Display;
 
 use crate::error::Error;
-use crate::lang::EvalResult;
-use crate::object::Obj;
+use crate::lang::{EvalResult, Signal};
+use crate::object::{Attributes, Obj};
 
 use super::coercion::CoercibleInto;
 use super::rep::Rep;
@@ -81,10 +81,64 @@ impl Vector {
         }
     }
 
+    /// Get the vector's attributes
+    pub fn attributes(&self) -> &Attributes {
+        match self {
+            Vector::Double(x) => x.attributes(),
+            Vector::Integer(x) => x.attributes(),
+            Vector::Logical(x) => x.attributes(),
+            Vector::Character(x) => x.attributes(),
+        }
+    }
+
+    /// Replace the vector's attributes
+    pub fn with_attributes(self, attrs: Attributes) -> Self {
+        match self {
+            Vector::Double(x) => Vector::Double(x.with_attributes(attrs)),
+            Vector::Integer(x) => Vector::Integer(x.with_attributes(attrs)),
+            Vector::Logical(x) => Vector::Logical(x.with_attributes(attrs)),
+            Vector::Character(x) => Vector::Character(x.with_attributes(attrs)),
+        }
+    }
+
+    /// Find the (1-indexed) positions of names within the vector's names
+    ///
+    /// Names which are not found produce `NA` indices.
+    ///
+    fn name_indices(&self, index: Vector) -> Vector {
+        let names: Vec<Character> = match self.attributes().get("names") {
+            Some(Obj::Vector(Vector::Character(names))) => names.into_iter().collect(),
+            _ => vec![],
+        };
+
+        let Vector::Character(index) = index.as_character() else {
+            unreachable!()
+        };
+
+        index
+            .into_iter()
+            .map(|name| match name {
+                OptionNA::Some(_) => names
+                    .iter()
+                    .position(|n| *n == name)
+                    .map_or(OptionNA::NA, |i| OptionNA::Some(i as i32 + 1)),
+                OptionNA::NA => OptionNA::NA,
+            })
+            .collect::<Vec<Integer>>()
+            .into()
+    }
+
     pub fn try_get(&self, index: Obj) -> EvalResult {
         let err =
             Error::Other("Vector index cannot be coerced into a valid indexing type.".to_string());
-        match (self, index.as_vector()?) {
+
+        // vectors are indexed by name through their names attribute
+        let index = match index.as_vector()? {
+            Obj::Vector(i @ Vector::Character(_)) => Obj::Vector(self.name_indices(i)),
+            index => index,
+        };
+
+        match (self, index) {
             (Vector::Double(v), Obj::Vector(i)) => {
                 Ok(Obj::Vector(Vector::from(v.subset(i.try_into()?))))
             }
@@ -101,6 +155,60 @@ impl Vector {
         }
     }
 
+    /// Index an array by one index per dimension
+    ///
+    /// Each index selects positions along its dimension, with `None`
+    /// selecting the entire dimension. Selected positions are combined into
+    /// a single subset of the underlying vector, such that the result can
+    /// also be assigned into. Unless `drop` is `false`, dimensions of extent
+    /// one are dropped from the result.
+    ///
+    pub fn try_get_dims(&self, indices: Vec<Option<Obj>>, drop: bool) -> EvalResult {
+        let dim = match self.attributes().dim() {
+            Some(dim) if dim.len() == indices.len() => dim,
+            _ => return Error::Other("incorrect number of dimensions".into()).into(),
+        };
+
+        let positions = indices
+            .into_iter()
+            .zip(dim.iter())
+            .map(|(index, extent)| dim_positions(index, *extent))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // walk the selected positions in column-major order
+        let mut linear: Vec<Integer> = vec![];
+        let n: usize = positions.iter().map(|p| p.len()).product();
+        for i in 0..n {
+            let mut rem = i;
+            let mut stride = 1;
+            let mut index = OptionNA::Some(0);
+            for (p, extent) in positions.iter().zip(dim.iter()) {
+                index = match (index, p[rem % p.len()]) {
+                    (OptionNA::Some(index), Some(j)) => OptionNA::Some(index + (j * stride) as i32),
+                    _ => OptionNA::NA,
+                };
+                rem /= p.len();
+                stride *= extent;
+            }
+            linear.push(index);
+        }
+
+        let result = self.subset(Subset::Indices(linear.into()));
+
+        // dimensions of extent one are dropped
+        let dim: Vec<i32> = positions
+            .iter()
+            .map(|p| p.len() as i32)
+            .filter(|n| !drop || *n != 1)
+            .collect();
+
+        if dim.len() >= 2 {
+            result.attributes().set("dim", Obj::Vector(dim.into()));
+        }
+
+        Ok(Obj::Vector(result))
+    }
+
     pub fn subset(&self, subset: Subset) -> Self {
         match self {
             Vector::Double(x) => x.subset(subset).into(),
@@ -113,17 +221,28 @@ impl Vector {
     pub fn assign(&mut self, other: Obj) -> EvalResult {
         let err =
             Error::Other("Cannot assign to a vector from a different type".to_string()).into();
+        use Vector::*;
+
+        // values are coerced to the type of the vector they are assigned to,
+        // which must be at least as general as the value
         match (self, other) {
-            (Vector::Double(l), Obj::Vector(Vector::Double(r))) => {
-                Ok(Obj::Vector(Vector::from(l.assign(r))))
-            }
-            (Vector::Integer(l), Obj::Vector(Vector::Integer(r))) => {
+            (Double(l), Obj::Vector(r @ (Double(_) | Integer(_) | Logical(_)))) => {
+                let Double(r) = r.as_double() else {
+                    unreachable!()
+                };
                 Ok(Obj::Vector(Vector::from(l.assign(r))))
             }
-            (Vector::Logical(l), Obj::Vector(Vector::Logical(r))) => {
+            (Integer(l), Obj::Vector(r @ (Integer(_) | Logical(_)))) => {
+                let Integer(r) = r.as_integer() else {
+                    unreachable!()
+                };
                 Ok(Obj::Vector(Vector::from(l.assign(r))))
             }
-            (Vector::Character(l), Obj::Vector(Vector::Character(r))) => {
+            (Logical(l), Obj::Vector(Logical(r))) => Ok(Obj::Vector(Vector::from(l.assign(r)))),
+            (Character(l), Obj::Vector(r)) => {
+                let Character(r) = r.as_character() else {
+                    unreachable!()
+                };
                 Ok(Obj::Vector(Vector::from(l.assign(r))))
             }
             _ => Err(err),
@@ -139,6 +258,26 @@ impl Vector {
         }
     }
 
+    /// Whether the vector is computed lazily, rather than stored
+    pub fn is_lazy(&self) -> bool {
+        match self {
+            Vector::Double(x) => x.is_lazy(),
+            Vector::Integer(x) => x.is_lazy(),
+            Vector::Logical(x) => x.is_lazy(),
+            Vector::Character(x) => x.is_lazy(),
+        }
+    }
+
+    /// Materialize a lazily computed vector in place
+    pub fn materialize_lazy(&self) {
+        match self {
+            Vector::Double(x) => x.materialize_lazy(),
+            Vector::Integer(x) => x.materialize_lazy(),
+            Vector::Logical(x) => x.materialize_lazy(),
+            Vector::Character(x) => x.materialize_lazy(),
+        }
+    }
+
     pub fn vec_coerce<T, U>(v: &[OptionNA<T>]) -> Vec<OptionNA<U>>
     where
         T: CoercibleInto<U> + Clone,
@@ -231,6 +370,72 @@ impl Vector {
     }
 }
 
+/// Resolve the 0-indexed positions selected along a single dimension
+///
+/// Missing indices select the entire dimension, logical indices are recycled
+/// along the dimension and negative indices exclude positions. `NA` indices
+/// produce `None` positions.
+///
+pub fn dim_positions(index: Option<Obj>, extent: usize) -> Result<Vec<Option<usize>>, Signal> {
+    let Some(index) = index else {
+        return Ok((0..extent).map(Some).collect());
+    };
+
+    let oob = || Error::Other("subscript out of bounds".into()).into();
+    let positions = match index.as_vector()? {
+        Obj::Vector(Vector::Logical(mask)) => {
+            let mask: Vec<Logical> = mask.into_iter().collect();
+            if mask.len() > extent {
+                return oob();
+            }
+
+            (0..extent)
+                .filter_map(|i| match mask.get(i % mask.len().max(1)) {
+                    Some(OptionNA::Some(true)) => Some(Some(i)),
+                    Some(OptionNA::NA) => Some(None),
+                    _ => None,
+                })
+                .collect()
+        }
+        Obj::Vector(Vector::Character(_)) => return oob(),
+        Obj::Vector(v) => {
+            let Vector::Integer(v) = v.as_integer() else {
+                unreachable!()
+            };
+
+            let v: Vec<Integer> = v.into_iter().collect();
+            let negative = v.iter().any(|i| matches!(i, OptionNA::Some(i) if *i < 0));
+            let positive = v.iter().any(|i| matches!(i, OptionNA::Some(i) if *i > 0));
+
+            if negative && positive {
+                let msg = "can't mix positive and negative subscripts";
+                return Error::Other(msg.into()).into();
+            }
+
+            if negative {
+                (0..extent)
+                    .filter(|i| !v.contains(&OptionNA::Some(-(*i as i32) - 1)))
+                    .map(Some)
+                    .collect()
+            } else {
+                let mut positions = vec![];
+                for i in v {
+                    match i {
+                        OptionNA::Some(0) => (),
+                        OptionNA::Some(i) if i as usize > extent => return oob(),
+                        OptionNA::Some(i) => positions.push(Some(i as usize - 1)),
+                        OptionNA::NA => positions.push(None),
+                    }
+                }
+                positions
+            }
+        }
+        _ => return Error::Other("invalid subscript type".into()).into(),
+    };
+
+    Ok(positions)
+}
+
 impl TryInto<bool> for Vector {
     type Error = ();
     fn try_into(self) -> Result<bool, Self::Error> {
@@ -394,7 +599,7 @@ where
 impl Debug for OptionNA<String> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            OptionNA::Some(x) => write!(f, "\"{}\"", x),
+            OptionNA::Some(x) => write!(f, "\"{}\"", crate::utils::escape(x)),
             OptionNA::NA => write!(f, "NA"),
         }
     }
@@ -423,71 +628,131 @@ impl Display for Vector {
     }
 }
 
-impl<T, U, O> std::ops::Add<OptionNA<U>> for OptionNA<T>
-where
-    T: std::ops::Add<U, Output = O>,
-{
-    type Output = OptionNA<O>;
-    fn add(self, rhs: OptionNA<U>) -> Self::Output {
+/// Arithmetic producing `NA` where a result can't be represented
+///
+/// Integer results outside of the range of an `integer`, as well as integer
+/// division and remainders by zero, are `NA`. Double arithmetic follows
+/// IEEE 754, producing infinite and `NaN` values instead. As in R, the
+/// remainder takes the sign of the divisor and integer division rounds
+/// towards negative infinity.
+pub trait CheckedArith: Sized {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_int_div(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+
+impl CheckedArith for i32 {
+    // the smallest i32 is reserved by R to represent NA integers, so it is
+    // likewise treated as an overflow
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i32::checked_add(self, rhs).filter(|x| *x != i32::MIN)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i32::checked_sub(self, rhs).filter(|x| *x != i32::MIN)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i32::checked_mul(self, rhs).filter(|x| *x != i32::MIN)
+    }
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        i32::checked_div(self, rhs).filter(|x| *x != i32::MIN)
+    }
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        let rem = i32::checked_rem(self, rhs)?;
+        match rem != 0 && (rem < 0) != (rhs < 0) {
+            true => Some(rem + rhs),
+            false => Some(rem),
+        }
+    }
+    fn checked_int_div(self, rhs: Self) -> Option<Self> {
+        let div = i32::checked_div(self, rhs)?;
+        match self % rhs != 0 && (self < 0) != (rhs < 0) {
+            true => Some(div - 1),
+            false => Some(div),
+        }
+    }
+    fn checked_neg(self) -> Option<Self> {
+        i32::checked_neg(self).filter(|x| *x != i32::MIN)
+    }
+}
+
+impl CheckedArith for f64 {
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(self / rhs)
+    }
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        Some(self - (self / rhs).floor() * rhs)
+    }
+    fn checked_int_div(self, rhs: Self) -> Option<Self> {
+        Some((self / rhs).floor())
+    }
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl<T: CheckedArith> std::ops::Add for OptionNA<T> {
+    type Output = OptionNA<T>;
+    fn add(self, rhs: Self) -> Self::Output {
         use OptionNA::*;
         match (self, rhs) {
-            (Some(l), Some(r)) => Some(l + r),
+            (Some(l), Some(r)) => l.checked_add(r).map_or(NA, Some),
             _ => NA,
         }
     }
 }
 
-impl<T> std::ops::Sub for OptionNA<T>
-where
-    T: std::ops::Sub<Output = T>,
-{
+impl<T: CheckedArith> std::ops::Sub for OptionNA<T> {
     type Output = OptionNA<T>;
     fn sub(self, rhs: Self) -> Self::Output {
         use OptionNA::*;
         match (self, rhs) {
-            (Some(l), Some(r)) => Some(l - r),
+            (Some(l), Some(r)) => l.checked_sub(r).map_or(NA, Some),
             _ => NA,
         }
     }
 }
 
-impl<T> std::ops::Neg for OptionNA<T>
-where
-    T: std::ops::Neg<Output = T>,
-{
+impl<T: CheckedArith> std::ops::Neg for OptionNA<T> {
     type Output = OptionNA<T>;
     fn neg(self) -> Self::Output {
         use OptionNA::*;
         match self {
-            Some(x) => Some(x.neg()),
+            Some(x) => x.checked_neg().map_or(NA, Some),
             _ => NA,
         }
     }
 }
 
-impl<T> std::ops::Mul for OptionNA<T>
-where
-    T: std::ops::Mul<Output = T>,
-{
+impl<T: CheckedArith> std::ops::Mul for OptionNA<T> {
     type Output = OptionNA<T>;
     fn mul(self, rhs: Self) -> Self::Output {
         use OptionNA::*;
         match (self, rhs) {
-            (Some(l), Some(r)) => Some(l * r),
+            (Some(l), Some(r)) => l.checked_mul(r).map_or(NA, Some),
             _ => NA,
         }
     }
 }
 
-impl<T> std::ops::Div for OptionNA<T>
-where
-    T: std::ops::Div<Output = T>,
-{
+impl<T: CheckedArith> std::ops::Div for OptionNA<T> {
     type Output = OptionNA<T>;
     fn div(self, rhs: Self) -> Self::Output {
         use OptionNA::*;
         match (self, rhs) {
-            (Some(l), Some(r)) => Some(l / r),
+            (Some(l), Some(r)) => l.checked_div(r).map_or(NA, Some),
             _ => NA,
         }
     }
@@ -537,15 +802,29 @@ where
     }
 }
 
-impl<T> std::ops::Rem for OptionNA<T>
-where
-    T: std::ops::Rem,
-{
-    type Output = OptionNA<<T as std::ops::Rem>::Output>;
+impl<T: CheckedArith> std::ops::Rem for OptionNA<T> {
+    type Output = OptionNA<T>;
     fn rem(self, rhs: Self) -> Self::Output {
         use OptionNA::*;
         match (self, rhs) {
-            (Some(l), Some(r)) => Some(l.rem(r)),
+            (Some(l), Some(r)) => l.checked_rem(r).map_or(NA, Some),
+            _ => NA,
+        }
+    }
+}
+
+pub trait IntDiv<Rhs> {
+    type Output;
+    /// divide self by the rhs, rounding down to a whole number
+    fn int_div(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<T: CheckedArith> IntDiv<OptionNA<T>> for OptionNA<T> {
+    type Output = OptionNA<T>;
+    fn int_div(self, rhs: OptionNA<T>) -> Self::Output {
+        use OptionNA::*;
+        match (self, rhs) {
+            (Some(l), Some(r)) => l.checked_int_div(r).map_or(NA, Some),
             _ => NA,
         }
     }
@@ -665,17 +944,10 @@ impl std::ops::Div for Vector {
     type Output = Vector;
     fn div(self, rhs: Self) -> Self::Output {
         use Vector::*;
-        match (self, rhs) {
+        // division always produces doubles, even of integers
+        match (self.as_double(), rhs.as_double()) {
             (Double(l), Double(r)) => (l / r).into(),
-            (Double(l), Integer(r)) => (l / r).into(),
-            (Double(l), Logical(r)) => (l / r).into(),
-            (Integer(l), Double(r)) => (l / r).into(),
-            (Integer(l), Integer(r)) => (l / r).into(),
-            (Integer(l), Logical(r)) => (l / r).into(),
-            (Logical(l), Double(r)) => (l / r).into(),
-            (Logical(l), Integer(r)) => (l / r).into(),
-            (Logical(l), Logical(r)) => (l / r).into(),
-            _ => todo!(),
+            _ => unreachable!(),
         }
     }
 }
@@ -863,6 +1135,25 @@ impl std::ops::Rem for Vector {
     }
 }
 
+impl IntDiv<Vector> for Vector {
+    type Output = Vector;
+    fn int_div(self, rhs: Self) -> Self::Output {
+        use Vector::*;
+        match (self, rhs) {
+            (Double(l), Double(r)) => l.int_div(r).into(),
+            (Double(l), Integer(r)) => l.int_div(r).into(),
+            (Double(l), Logical(r)) => l.int_div(r).into(),
+            (Integer(l), Double(r)) => l.int_div(r).into(),
+            (Integer(l), Integer(r)) => l.int_div(r).into(),
+            (Integer(l), Logical(r)) => l.int_div(r).into(),
+            (Logical(l), Double(r)) => l.int_div(r).into(),
+            (Logical(l), Integer(r)) => l.int_div(r).into(),
+            (Logical(l), Logical(r)) => l.int_div(r).into(),
+            _ => todo!(),
+        }
+    }
+}
+
 impl std::ops::BitOr for Vector {
     type Output = Vector;
     fn bitor(self, rhs: Self) -> Self::Output {
@@ -2,33 +2,47 @@ use std::cell::{Ref, RefCell};
 use std::fmt::{Debug, Display};
 
 use super::coercion::{AtomicMode, CoercibleInto, CommonCmp, CommonNum, MinimallyNumeric};
-use super::iterators::{map_common_numeric, zip_recycle};
 use super::reptype::RepType;
 use super::reptype::RepTypeIter;
 use super::subset::Subset;
 use super::types::*;
-use super::{OptionNA, Pow, VecPartialCmp};
-use crate::object::CowObj;
-use crate::object::ViewMut;
+use super::{IntDiv, OptionNA, Pow, VecPartialCmp};
+use crate::object::{Attributes, CowObj, Obj, ViewMut};
 
 /// Vector Representation
 ///
 /// The ref-cell is used so vectors can change there internal representation,
-/// e.g. by materializing.
-#[derive(Debug, PartialEq)]
-pub struct Rep<T: Clone>(pub RefCell<RepType<T>>);
+/// e.g. by materializing. Alongside the representation, a vector carries its
+/// [Attributes].
+#[derive(Debug)]
+pub struct Rep<T: Clone>(pub RefCell<RepType<T>>, pub Attributes);
+
+impl<T> PartialEq for Rep<T>
+where
+    T: AtomicMode + Clone + Default + PartialEq,
+{
+    /// Vectors are compared by their values, irrespective of whether those
+    /// values are materialized or a subset view.
+    fn eq(&self, other: &Self) -> bool {
+        self.inner() == other.inner() && self.1 == other.1
+    }
+}
 
 impl<T: Clone + AtomicMode + Default> Clone for Rep<T> {
     fn clone(&self) -> Self {
         match self.borrow().clone() {
-            RepType::Subset(v, s) => Rep(RefCell::new(RepType::Subset(v.clone(), s.clone()))),
+            RepType::Subset(v, s) => Rep(
+                RefCell::new(RepType::Subset(v.clone(), s.clone())),
+                self.1.clone(),
+            ),
+            seq @ RepType::Seq { .. } => Rep(RefCell::new(seq), self.1.clone()),
         }
     }
 }
 
 impl<T: Clone + AtomicMode + Default> ViewMut for Rep<T> {
     fn view_mut(&self) -> Self {
-        Self(RefCell::new(self.borrow().view_mut()))
+        Self(RefCell::new(self.borrow().view_mut()), self.1.view_mut())
     }
 }
 
@@ -48,7 +62,23 @@ where
     }
 
     pub fn materialize(&self) -> Self {
-        self.borrow().materialize().into()
+        Rep(RefCell::new(self.borrow().materialize()), self.1.clone())
+    }
+
+    /// Whether the vector is computed lazily, rather than stored
+    pub fn is_lazy(&self) -> bool {
+        self.borrow().is_lazy()
+    }
+
+    /// Materialize a lazily computed vector in place
+    ///
+    /// Mutable views of a vector share its data, so lazy vectors must be
+    /// materialized before views are taken to modify them.
+    ///
+    pub fn materialize_lazy(&self) {
+        if self.is_lazy() {
+            self.materialize_inplace();
+        }
     }
 
     /// Create an empty vector
@@ -78,17 +108,59 @@ where
     }
 
     pub fn len(&self) -> usize {
-        // TODO: Only materialize when necessary
-        self.materialize_inplace();
         self.borrow().len()
     }
 
+    /// Iterate over the vector's values
+    ///
+    /// Values are visited through any subsets without first materializing
+    /// the vector.
+    ///
+    pub fn iter(&self) -> RepIter<T> {
+        RepIter(self.borrow().view_mut().into_iter())
+    }
+
     /// Subsetting a Vector
     ///
     /// Introduce a new subset into the aggregate list of subset indices.
+    /// Names are subset alongside the vector, while all other attributes
+    /// are dropped.
     ///
     pub fn subset(&self, subset: Subset) -> Self {
-        (*self.borrow()).subset(subset).into()
+        let attrs = self.1.subset(subset.clone());
+        Rep(RefCell::new((*self.borrow()).subset(subset)), attrs)
+    }
+
+    /// Get the vector's attributes
+    pub fn attributes(&self) -> &Attributes {
+        &self.1
+    }
+
+    /// Replace the vector's attributes
+    pub fn with_attributes(self, attrs: Attributes) -> Self {
+        Rep(self.0, attrs)
+    }
+
+    /// Determine the attributes of the result of a binary operation
+    ///
+    /// Following R, attributes are taken from the longer operand. When both
+    /// operands are of the same length, attributes of both are kept, with
+    /// those of the left-hand side taking precedence.
+    ///
+    fn binary_attributes<R>(&self, rhs: &Rep<R>) -> Attributes
+    where
+        R: AtomicMode + Clone + Default,
+    {
+        if self.1.is_empty() && rhs.1.is_empty() {
+            return Attributes::default();
+        }
+
+        use std::cmp::Ordering::*;
+        match self.len().cmp(&rhs.len()) {
+            Greater => self.1.clone(),
+            Less => rhs.1.clone(),
+            Equal => self.1.merge(&rhs.1),
+        }
     }
 
     #[must_use]
@@ -102,7 +174,8 @@ where
     }
 
     pub fn assign(&mut self, value: Self) -> Self {
-        self.0.borrow_mut().assign(value.0.into_inner()).into()
+        let result = self.0.borrow_mut().assign(value.0.into_inner());
+        Rep(RefCell::new(result), self.1.clone())
     }
     /// Test the mode of the internal vector type
     ///
@@ -152,9 +225,9 @@ where
     pub fn as_mode<Mode>(&self) -> Rep<Mode>
     where
         T: CoercibleInto<Mode>,
-        Mode: Clone,
+        Mode: AtomicMode + Clone + Default,
     {
-        Rep(RefCell::new(self.borrow().as_mode()))
+        Rep(RefCell::new(self.borrow().as_mode()), self.1.clone())
     }
 
     /// See [Self::as_mode] for more information
@@ -189,6 +262,23 @@ where
         self.as_mode::<Character>()
     }
 
+    /// Apply a function over the vector's values
+    ///
+    /// Values are first coerced into their minimally numeric type, such that
+    /// `logical` values are treated as `integer`s. The vector's attributes
+    /// are kept.
+    ///
+    pub fn map_numeric<N, O, F>(&self, mut f: F) -> Rep<O>
+    where
+        T: MinimallyNumeric<As = N> + CoercibleInto<N>,
+        O: AtomicMode + Clone + Default,
+        RepType<O>: From<Vec<O>>,
+        F: FnMut(N) -> O,
+    {
+        let result: Vec<O> = self.iter().map(|x| f(x.coerce_into())).collect();
+        Rep(RefCell::new(RepType::from(result)), self.1.clone())
+    }
+
     /// Apply over the vector contents to produce a vector of [std::cmp::Ordering]
     ///
     /// This function is used primarily in support of the implementation of
@@ -220,7 +310,7 @@ where
     T: AtomicMode + Clone + Default,
 {
     fn default() -> Self {
-        Rep(RefCell::new(RepType::default()))
+        Rep(RefCell::new(RepType::default()), Attributes::default())
     }
 }
 
@@ -253,7 +343,7 @@ where
     T: AtomicMode + Clone + Default,
 {
     fn from(rep: RepType<T>) -> Self {
-        Rep(RefCell::new(rep))
+        Rep(RefCell::new(rep), Attributes::default())
     }
 }
 
@@ -275,49 +365,49 @@ where
 
 impl From<Vec<OptionNA<f64>>> for Rep<Double> {
     fn from(value: Vec<OptionNA<f64>>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<f64>> for Rep<Double> {
     fn from(value: Vec<f64>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<OptionNA<i32>>> for Rep<Integer> {
     fn from(value: Vec<OptionNA<i32>>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<i32>> for Rep<Integer> {
     fn from(value: Vec<i32>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<OptionNA<bool>>> for Rep<Logical> {
     fn from(value: Vec<OptionNA<bool>>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<bool>> for Rep<Logical> {
     fn from(value: Vec<bool>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<OptionNA<String>>> for Rep<Character> {
     fn from(value: Vec<OptionNA<String>>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
 impl From<Vec<String>> for Rep<Character> {
     fn from(value: Vec<String>) -> Self {
-        Rep(RefCell::new(value.into()))
+        Rep(RefCell::new(value.into()), Attributes::default())
     }
 }
 
@@ -326,6 +416,157 @@ where
     T: AtomicMode + Debug + Default + Clone,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.1.dim(), self.1.get("names")) {
+            (Some(dim), _) if dim.len() >= 2 => self.fmt_array(f, &dim)?,
+            (_, Some(Obj::Vector(names))) if !self.is_empty() => self.fmt_named(f, names.into())?,
+            _ => self.fmt_values(f)?,
+        }
+
+        let printed = ["names", "dim"];
+        for (name, value) in self
+            .1
+            .iter()
+            .filter(|(k, _)| !printed.contains(&k.as_str()))
+        {
+            write!(f, "\nattr(,\"{name}\")\n{value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Rep<T>
+where
+    T: AtomicMode + Debug + Default + Clone,
+{
+    /// Format a named vector, printing names above their aligned values
+    fn fmt_named(&self, f: &mut std::fmt::Formatter<'_>, names: Vec<String>) -> std::fmt::Result {
+        let x_strs: Vec<String> = self.iter().map(|xi| format!("{:?}", xi)).collect();
+
+        let width = x_strs
+            .iter()
+            .chain(names.iter())
+            .fold(0, |max_len, xi| std::cmp::max(max_len, xi.len()));
+
+        // hard coded console width
+        let per_line = std::cmp::max(1, 80 / (width + 1));
+
+        for (i, (names, values)) in names
+            .chunks(per_line)
+            .zip(x_strs.chunks(per_line))
+            .enumerate()
+        {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            let names: Vec<_> = names.iter().map(|n| format!("{n:>width$}")).collect();
+            let values: Vec<_> = values.iter().map(|v| format!("{v:>width$}")).collect();
+            write!(f, "{}\n{}", names.join(" "), values.join(" "))?;
+        }
+
+        Ok(())
+    }
+
+    /// Format an array, printing each of its 2-dimensional slices as a matrix
+    fn fmt_array(&self, f: &mut std::fmt::Formatter<'_>, dim: &[usize]) -> std::fmt::Result {
+        let x_strs: Vec<String> = self.iter().map(|xi| format!("{:?}", xi)).collect();
+
+        if x_strs.is_empty() {
+            let dims: Vec<String> = dim.iter().map(|d| d.to_string()).collect();
+            let kind = if dim.len() == 2 { "matrix" } else { "array" };
+            return write!(f, "<{} {kind}>", dims.join(" x "));
+        }
+
+        let (nrow, ncol) = (dim[0], dim[1]);
+        let nslices: usize = dim[2..].iter().product();
+        for (i, slice) in x_strs.chunks(nrow * ncol).take(nslices).enumerate() {
+            // slices are labelled by their position along higher dimensions
+            if dim.len() > 2 {
+                let mut rem = i;
+                let labels: Vec<String> = dim[2..]
+                    .iter()
+                    .map(|d| {
+                        let label = rem % d + 1;
+                        rem /= d;
+                        label.to_string()
+                    })
+                    .collect();
+
+                if i > 0 {
+                    write!(f, "\n\n")?;
+                }
+                write!(f, ", , {}\n\n", labels.join(", "))?;
+            }
+
+            self.fmt_matrix(f, slice, nrow, ncol)?;
+        }
+
+        Ok(())
+    }
+
+    /// Format a matrix, aligning the values in each column beneath its label
+    fn fmt_matrix(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        cells: &[String],
+        nrow: usize,
+        ncol: usize,
+    ) -> std::fmt::Result {
+        let row_labels: Vec<String> = (1..=nrow).map(|i| format!("[{i},]")).collect();
+        let col_labels: Vec<String> = (1..=ncol).map(|j| format!("[,{j}]")).collect();
+        let gutter = row_labels.iter().map(|l| l.len()).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..ncol)
+            .map(|j| {
+                cells[j * nrow..(j + 1) * nrow]
+                    .iter()
+                    .chain(std::iter::once(&col_labels[j]))
+                    .map(|c| c.len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        // character values are left-aligned, all others right-aligned
+        let left = self.is_character();
+        let align = |s: &str, width: usize| match left {
+            true => format!("{s:<width$}"),
+            false => format!("{s:>width$}"),
+        };
+
+        // columns are wrapped in blocks fitting the hard coded console width
+        let mut start = 0;
+        while start < ncol {
+            let mut end = start;
+            let mut used = gutter;
+            while end < ncol && (end == start || used + 1 + widths[end] <= 80) {
+                used += 1 + widths[end];
+                end += 1;
+            }
+
+            if start > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{:gutter$}", "")?;
+            for j in start..end {
+                write!(f, " {}", align(&col_labels[j], widths[j]))?;
+            }
+
+            for (i, label) in row_labels.iter().enumerate() {
+                write!(f, "\n{label:<gutter$}")?;
+                for j in start..end {
+                    write!(f, " {}", align(&cells[j * nrow + i], widths[j]))?;
+                }
+            }
+
+            start = end;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_values(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let n = self.len();
         if n == 0 {
             if self.is_double() {
@@ -346,13 +587,8 @@ where
         // TODO: iteratively calculate when we hit max print so our
         // max_len isn't inflated by a value that is omitted
 
-        let xc = self.inner().clone();
-        let xb = xc.borrow();
-
-        let x_strs = xb.iter().map(|xi| format!("{:?}", xi));
-        let max_len = x_strs
-            .clone()
-            .fold(0, |max_len, xi| std::cmp::max(max_len, xi.len()));
+        let x_strs = || self.iter().map(|xi| format!("{:?}", xi));
+        let max_len = x_strs().fold(0, |max_len, xi| std::cmp::max(max_len, xi.len()));
 
         let mut col = 0;
         let gutterlen = 2 + nlen + 1;
@@ -360,7 +596,7 @@ where
         // hard coded max print & console width
         let maxprint = 20 * ((80 - gutterlen) / max_len);
 
-        x_strs
+        x_strs()
             .take(maxprint)
             .enumerate()
             .try_for_each(|(i, x_str)| {
@@ -395,44 +631,39 @@ where
 {
     type Output = Rep<O>;
     fn neg(self) -> Self::Output {
+        let attrs = self.1.clone();
         let result = -(self.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
 impl<L, R, C, O, LNum, RNum> std::ops::Add<Rep<R>> for Rep<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    L: CoercibleInto<Double>,
     R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    R: CoercibleInto<Double>,
     (LNum, RNum): CommonNum<Common = C>,
+    C: AtomicMode + Default,
     C: Clone + std::ops::Add<Output = O>,
     RepType<C>: From<Vec<O>>,
 {
     type Output = Rep<C>;
     fn add(self, rhs: Rep<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
-        let result = RepType::from(
-            map_common_numeric(zip_recycle(lhs, rhs))
-                .map(|(l, r)| l + r)
-                .collect::<Vec<O>>(),
-        );
-
-        Rep(RefCell::new(result))
+        let attrs = self.binary_attributes(&rhs);
+        let result = (self.0.into_inner()) + (rhs.0.into_inner());
+        Rep(RefCell::new(result), attrs)
     }
 }
 
 impl<L, R, C, O, LNum, RNum> std::ops::Sub<Rep<R>> for Rep<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    L: CoercibleInto<Double>,
     R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    R: CoercibleInto<Double>,
     (LNum, RNum): CommonNum<Common = C>,
+    C: AtomicMode + Default,
     C: std::ops::Sub<Output = O>,
     RepType<C>: From<Vec<O>>,
     O: Clone,
@@ -440,16 +671,20 @@ where
 {
     type Output = Rep<C>;
     fn sub(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         let result = (self.0.into_inner()) - (rhs.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
 impl<L, R, C, O, LNum, RNum> std::ops::Mul<Rep<R>> for Rep<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    L: CoercibleInto<Double>,
     R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    R: CoercibleInto<Double>,
     (LNum, RNum): CommonNum<Common = C>,
+    C: AtomicMode + Default,
     C: std::ops::Mul<Output = O>,
     RepType<C>: From<Vec<O>>,
     O: Clone,
@@ -457,10 +692,11 @@ where
 {
     type Output = Rep<C>;
     fn mul(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         use std::ops::Mul;
         let result = Mul::mul(self.0.into_inner(), rhs.0.into_inner());
 
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -476,8 +712,9 @@ where
 {
     type Output = Rep<C>;
     fn div(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         let result = (self.0.into_inner()) / (rhs.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -495,9 +732,30 @@ where
 {
     type Output = Rep<C>;
     fn rem(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         pub use std::ops::Rem;
         let result = Rem::rem(self.0.into_inner(), rhs.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
+    }
+}
+
+impl<L, R, C, O, LNum, RNum> IntDiv<Rep<R>> for Rep<L>
+where
+    L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    (LNum, RNum): CommonNum<Common = C>,
+    C: IntDiv<C, Output = O>,
+    RepType<C>: From<Vec<O>>,
+    L: Clone,
+    R: Clone,
+    C: Clone,
+    O: Clone,
+{
+    type Output = Rep<C>;
+    fn int_div(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
+        let result = IntDiv::int_div(self.0.into_inner(), rhs.0.into_inner());
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -513,8 +771,9 @@ where
 {
     type Output = Rep<O>;
     fn power(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         let result = Pow::power(self.0.into_inner(), rhs.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -530,8 +789,9 @@ where
 {
     type Output = Rep<O>;
     fn bitor(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         let result: RepType<O> = (self.0.into_inner()) | (rhs.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -547,8 +807,9 @@ where
 {
     type Output = Rep<O>;
     fn bitand(self, rhs: Rep<R>) -> Self::Output {
+        let attrs = self.binary_attributes(&rhs);
         let result: RepType<O> = (self.0.into_inner()) & (rhs.0.into_inner());
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -561,8 +822,9 @@ where
 {
     type Output = Rep<O>;
     fn not(self) -> Self::Output {
+        let attrs = self.1.clone();
         let result: RepType<O> = !self.0.into_inner();
-        Rep(RefCell::new(result))
+        Rep(RefCell::new(result), attrs)
     }
 }
 
@@ -580,79 +842,91 @@ where
 
     fn vec_gt(self, rhs: Rep<R>) -> Self::Output {
         use std::cmp::Ordering::*;
-        self.vectorized_partial_cmp(rhs)
+        let attrs = self.binary_attributes(&rhs);
+        let result: Vec<Logical> = self
+            .vectorized_partial_cmp(rhs)
             .into_iter()
             .map(|i| match i {
                 Some(Greater) => OptionNA::Some(true),
                 Some(_) => OptionNA::Some(false),
                 None => OptionNA::NA,
             })
-            .collect::<Vec<Logical>>()
-            .into()
+            .collect();
+        Rep::from(result).with_attributes(attrs)
     }
 
     fn vec_gte(self, rhs: Rep<R>) -> Self::Output {
         use std::cmp::Ordering::*;
-        self.vectorized_partial_cmp(rhs)
+        let attrs = self.binary_attributes(&rhs);
+        let result: Vec<Logical> = self
+            .vectorized_partial_cmp(rhs)
             .into_iter()
             .map(|i| match i {
                 Some(Greater | Equal) => OptionNA::Some(true),
                 Some(_) => OptionNA::Some(false),
                 None => OptionNA::NA,
             })
-            .collect::<Vec<Logical>>()
-            .into()
+            .collect();
+        Rep::from(result).with_attributes(attrs)
     }
 
     fn vec_lt(self, rhs: Rep<R>) -> Self::Output {
         use std::cmp::Ordering::*;
-        self.vectorized_partial_cmp(rhs)
+        let attrs = self.binary_attributes(&rhs);
+        let result: Vec<Logical> = self
+            .vectorized_partial_cmp(rhs)
             .into_iter()
             .map(|i| match i {
                 Some(Less) => OptionNA::Some(true),
                 Some(_) => OptionNA::Some(false),
                 None => OptionNA::NA,
             })
-            .collect::<Vec<Logical>>()
-            .into()
+            .collect();
+        Rep::from(result).with_attributes(attrs)
     }
 
     fn vec_lte(self, rhs: Rep<R>) -> Self::Output {
         use std::cmp::Ordering::*;
-        self.vectorized_partial_cmp(rhs)
+        let attrs = self.binary_attributes(&rhs);
+        let result: Vec<Logical> = self
+            .vectorized_partial_cmp(rhs)
             .into_iter()
             .map(|i| match i {
                 Some(Less | Equal) => OptionNA::Some(true),
                 Some(_) => OptionNA::Some(false),
                 None => OptionNA::NA,
             })
-            .collect::<Vec<Logical>>()
-            .into()
+            .collect();
+        Rep::from(result).with_attributes(attrs)
     }
 
     fn vec_eq(self, rhs: Rep<R>) -> Self::Output {
         use std::cmp::Ordering::*;
-        self.vectorized_partial_cmp(rhs)
+        let attrs = self.binary_attributes(&rhs);
+        let result: Vec<Logical> = self
+            .vectorized_partial_cmp(rhs)
             .into_iter()
             .map(|i| match i {
                 Some(Equal) => OptionNA::Some(true),
                 Some(_) => OptionNA::Some(false),
                 None => OptionNA::NA,
             })
-            .collect::<Vec<Logical>>()
-            .into()
+            .collect();
+        Rep::from(result).with_attributes(attrs)
     }
 
     fn vec_neq(self, rhs: Rep<R>) -> Self::Output {
         use std::cmp::Ordering::*;
-        self.vectorized_partial_cmp(rhs)
+        let attrs = self.binary_attributes(&rhs);
+        let result: Vec<Logical> = self
+            .vectorized_partial_cmp(rhs)
             .into_iter()
             .map(|i| match i {
                 Some(Equal) => OptionNA::Some(false),
                 Some(_) => OptionNA::Some(true),
                 None => OptionNA::NA,
             })
-            .collect::<Vec<Logical>>()
-            .into()
+            .collect();
+        Rep::from(result).with_attributes(attrs)
     }
 }
@@ -1,11 +1,11 @@
 use std::fmt::Debug;
+use std::rc::Rc;
 
 use super::coercion::{AtomicMode, CoercibleInto, CommonCmp, CommonNum, MinimallyNumeric};
-use super::iterators::{map_common_numeric, zip_recycle};
 use super::subset::Subset;
 use super::subsets::Subsets;
 use super::types::*;
-use super::{OptionNA, Pow, VecPartialCmp};
+use super::{IntDiv, OptionNA, Pow, VecPartialCmp};
 use crate::object::{CowObj, ViewMut};
 
 /// Vector
@@ -13,14 +13,20 @@ use crate::object::{CowObj, ViewMut};
 pub enum RepType<T: Clone> {
     // Vector::Subset encompasses a "raw" vector (no subsetting)
     Subset(CowObj<Vec<T>>, Subsets),
-    // Iterator includes things like ranges 1:Inf, and lazily computed values
-    // Iter(Box<dyn Iterator<Item = &T>>)
+    // Seq is an arithmetic sequence, like `1:n`, whose elements are computed
+    // as they are accessed
+    Seq { start: f64, by: f64, len: usize },
 }
 
 impl<T: Clone> Clone for RepType<T> {
     fn clone(&self) -> Self {
         match self {
             RepType::Subset(v, s) => RepType::Subset(v.view_mut(), s.clone()),
+            RepType::Seq { start, by, len } => RepType::Seq {
+                start: *start,
+                by: *by,
+                len: *len,
+            },
         }
     }
 }
@@ -38,16 +44,20 @@ where
     type Item = T;
     type IntoIter = RepTypeIter<T>;
     fn into_iter(self) -> Self::IntoIter {
-        // FIXME: this might materialize
-        let n = self.len();
         match self {
-            RepType::Subset(..) => RepTypeIter::SubsetIter(self, 0, n),
+            RepType::Subset(v, subsets) => {
+                let data = v.borrow().clone();
+                let indices = subsets.indices(data.len());
+                RepTypeIter::IndexIter(data, indices)
+            }
+            RepType::Seq { len, .. } => RepTypeIter::SubsetIter(self, 0, len),
         }
     }
 }
 
 pub enum RepTypeIter<T: Clone> {
     SubsetIter(RepType<T>, usize, usize),
+    IndexIter(Rc<Vec<T>>, Box<dyn Iterator<Item = Option<usize>>>),
 }
 
 impl<T: AtomicMode + Clone + Default> Iterator for RepTypeIter<T> {
@@ -63,6 +73,10 @@ impl<T: AtomicMode + Clone + Default> Iterator for RepTypeIter<T> {
                     None
                 }
             }
+            RepTypeIter::IndexIter(data, indices) => {
+                let i = indices.next()?;
+                Some(i.map_or_else(T::default, |i| data[i].clone()))
+            }
         }
     }
 }
@@ -71,6 +85,13 @@ impl<T: Clone> ViewMut for RepType<T> {
     fn view_mut(&self) -> Self {
         match self {
             RepType::Subset(v, s) => RepType::Subset(v.view_mut(), s.clone()),
+            // sequences have no data to share, and must be materialized
+            // before they can be modified through a view
+            RepType::Seq { start, by, len } => RepType::Seq {
+                start: *start,
+                by: *by,
+                len: *len,
+            },
         }
     }
 }
@@ -98,16 +119,51 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
         RepType::Subset(Vec::new().into(), Subsets(Vec::new()))
     }
 
+    /// Create an arithmetic sequence
+    ///
+    /// The sequence `start`, `start + by`, `start + 2 * by`, ... of length
+    /// `len` is represented in constant space, computing elements only as
+    /// they are accessed.
+    ///
+    pub fn seq(start: f64, by: f64, len: usize) -> Self {
+        RepType::Seq { start, by, len }
+    }
+
+    /// Whether the vector is computed lazily, rather than stored
+    pub fn is_lazy(&self) -> bool {
+        matches!(self, RepType::Seq { .. })
+    }
+
     /// Access a lazy copy of the internal vector data
+    ///
+    /// Subsets are applied and sequences are computed, producing a new
+    /// vector of the selected values.
+    ///
     pub fn inner(&self) -> CowObj<Vec<T>> {
-        match self.materialize() {
-            RepType::Subset(v, _) => v.clone(),
+        match self {
+            RepType::Subset(v, subsets) => {
+                let vb = v.borrow();
+                subsets
+                    .indices(vb.len())
+                    .map(|i| match i {
+                        Some(i) => vb[i].clone(),
+                        None => T::default(),
+                    })
+                    .collect::<Vec<T>>()
+                    .into()
+            }
+            RepType::Seq { len, .. } => (0..*len)
+                .map(|i| self.get_atom(i))
+                .collect::<Vec<T>>()
+                .into(),
         }
     }
 
     /// Subsetting a Vector
     ///
     /// Introduce a new subset into the aggregate list of subset indices.
+    /// Ranges of a sequence are themselves sequences, while other subsets
+    /// of a sequence collect only the selected elements.
     ///
     pub fn subset(&self, subset: Subset) -> Self {
         match self {
@@ -116,15 +172,31 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
                 subsets.push(subset);
                 RepType::Subset(v.view_mut(), Subsets(subsets))
             }
+            RepType::Seq { start, by, len } => match subset {
+                Subset::Range(range) => {
+                    let from = range.start.min(*len);
+                    let to = range.end.clamp(from, *len);
+                    RepType::seq(start + by * from as f64, *by, to - from)
+                }
+                subset => {
+                    let values: Vec<T> = Subsets(vec![subset])
+                        .indices(*len)
+                        .map(|i| match i {
+                            Some(i) => self.get_atom(i),
+                            None => T::default(),
+                        })
+                        .collect();
+
+                    RepType::Subset(values.into(), Subsets::new())
+                }
+            },
         }
     }
 
     pub fn len(&self) -> usize {
         match self {
-            RepType::Subset(v, Subsets(s)) => match s.as_slice() {
-                [] => v.borrow().len(),
-                _ => unimplemented!(),
-            },
+            RepType::Subset(v, subsets) => subsets.selected_len(v.len()),
+            RepType::Seq { len, .. } => *len,
         }
     }
 
@@ -142,12 +214,11 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
         T: Clone,
     {
         match self {
-            RepType::Subset(v, subsets) => {
-                let vb = v.borrow();
-                let index = subsets.get_index_at(index)?;
-                let elem = vb.get(index)?;
-                Some(RepType::Subset(vec![elem.clone()].into(), Subsets::new()))
+            _ if index < self.len() => {
+                let elem = self.get_atom(index);
+                Some(RepType::Subset(vec![elem].into(), Subsets::new()))
             }
+            _ => None,
         }
     }
 
@@ -155,9 +226,12 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
         match self {
             RepType::Subset(v, subsets) => {
                 let vb = v.borrow();
-                let index = subsets.get_index_at(index).unwrap();
-                vb[index].clone()
+                match subsets.get_index_at(index, vb.len()) {
+                    Some(i) => vb[i].clone(),
+                    None => T::default(),
+                }
             }
+            RepType::Seq { start, by, .. } => T::from_seq(start + by * index as f64),
         }
     }
 
@@ -171,21 +245,29 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
         T: Clone + Default,
     {
         match (self, value) {
+            // sequences are materialized before they are assigned to or from
+            (l @ RepType::Seq { .. }, value) => {
+                *l = l.materialize();
+                l.assign(value)
+            }
+            (l, r @ RepType::Seq { .. }) => l.assign(r.materialize()),
             (RepType::Subset(lv, ls), RepType::Subset(rv, rs)) => {
+                // values are recycled over the assigned indices
+                let rvb = rv.borrow().clone();
+                let r_indices: Vec<Option<usize>> = rs.indices(rvb.len()).collect();
+
                 lv.with_inner_mut(|lvb| {
-                    let rvc = rv.clone();
-                    let rvb = rvc.borrow();
-
-                    let lv_len = lvb.len();
-                    let l_indices = ls.clone().into_iter().take_while(|(i, _)| i < &lv_len);
-                    let r_indices = rs.clone().into_iter().take_while(|(i, _)| i < &lv_len);
-
-                    for ((_, li), (_, ri)) in l_indices.zip(r_indices) {
-                        match (li, ri) {
-                            (Some(li), None) => lvb[li] = T::default(),
-                            (Some(li), Some(ri)) => lvb[li] = rvb[ri % rvb.len()].clone(),
-                            _ => (),
-                        }
+                    if r_indices.is_empty() {
+                        return;
+                    }
+
+                    let l_indices = ls.indices(lvb.len());
+                    for (i, li) in l_indices.enumerate() {
+                        let Some(li) = li else { continue };
+                        lvb[li] = match r_indices[i % r_indices.len()] {
+                            Some(ri) => rvb[ri].clone(),
+                            None => T::default(),
+                        };
                     }
                 });
 
@@ -202,23 +284,19 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
     where
         T: Clone,
     {
-        match self {
-            RepType::Subset(v, subsets) => {
-                let vc = v.clone();
-                let vb = vc.borrow();
-                let mut res: Vec<T> = vec![];
-                let vb_len = vb.len();
-
-                let iter = subsets.clone().into_iter().take_while(|(i, _)| i < &vb_len);
-                for (_, i) in iter {
-                    match i {
-                        Some(i) => res.push(vb[i].clone()),
-                        None => res.push(T::default()),
-                    }
-                }
+        RepType::Subset(self.inner(), Subsets(vec![]))
+    }
 
-                RepType::Subset(res.into(), Subsets(vec![]))
-            }
+    /// A vector whose elements can be accessed by position in constant time
+    ///
+    /// Sequences already compute their elements by position, while
+    /// subsetted vectors are materialized.
+    ///
+    fn positional(&self) -> Self {
+        match self {
+            RepType::Seq { .. } => self.clone(),
+            RepType::Subset(_, Subsets(subsets)) if subsets.is_empty() => self.clone(),
+            RepType::Subset(..) => self.materialize(),
         }
     }
 
@@ -241,9 +319,21 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
     pub fn as_mode<Mode>(&self) -> RepType<Mode>
     where
         T: CoercibleInto<Mode>,
-        Mode: Clone,
+        Mode: AtomicMode + Clone + Default,
     {
+        let is_numeric = |double: bool, integer: bool| double || integer;
         match self {
+            // numeric sequences remain sequences of another numeric mode
+            RepType::Seq { start, by, len }
+                if is_numeric(T::is_double(), T::is_integer())
+                    && is_numeric(Mode::is_double(), Mode::is_integer()) =>
+            {
+                RepType::seq(*start, *by, *len)
+            }
+            RepType::Seq { len, .. } => {
+                let values: Vec<Mode> = (0..*len).map(|i| self.get_atom(i).coerce_into()).collect();
+                RepType::Subset(values.into(), Subsets::new())
+            }
             RepType::Subset(v, subsets) => {
                 let vc = v.clone();
                 let vb = vc.borrow();
@@ -290,31 +380,17 @@ impl<T: AtomicMode + Clone + Default> RepType<T> {
         (T, R): CommonCmp<Common = C>,
         C: PartialOrd,
     {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = other.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
-        zip_recycle(lhs, rhs)
+        recycled_pairs(self, other)
             .map(|(l, r)| {
-                let lc = CoercibleInto::<C>::coerce_into(l.clone());
-                let rc = CoercibleInto::<C>::coerce_into(r.clone());
+                let lc = CoercibleInto::<C>::coerce_into(l);
+                let rc = CoercibleInto::<C>::coerce_into(r);
                 lc.partial_cmp(&rc)
             })
             .collect()
     }
 
     pub fn get_inner(&self, index: usize) -> Option<T> {
-        match self {
-            RepType::Subset(v, subsets) => {
-                let vb = v.borrow();
-                let index = subsets.get_index_at(index)?;
-                vb.get(index).cloned()
-            }
-        }
+        (index < self.len()).then(|| self.get_atom(index))
     }
 }
 
@@ -393,15 +469,92 @@ impl From<Vec<String>> for RepType<Character> {
 impl<F, T> From<(Vec<F>, Subsets)> for RepType<T>
 where
     RepType<T>: From<Vec<F>>,
-    T: Clone,
+    T: AtomicMode + Clone + Default,
 {
     fn from(value: (Vec<F>, Subsets)) -> Self {
-        match Self::from(value.0) {
-            RepType::Subset(v, _) => RepType::Subset(v, value.1),
-        }
+        let (values, Subsets(subsets)) = value;
+        subsets
+            .into_iter()
+            .fold(Self::from(values), |v, subset| v.subset(subset))
     }
 }
 
+/// Pairs of elements of two vectors, recycling the shorter
+///
+/// Elements are visited by position, such that sequences are computed only
+/// as their elements are needed, rather than first being materialized.
+///
+fn recycled_pairs<L, R>(lhs: RepType<L>, rhs: RepType<R>) -> impl Iterator<Item = (L, R)>
+where
+    L: AtomicMode + Clone + Default,
+    R: AtomicMode + Clone + Default,
+{
+    let (lhs, rhs) = (lhs.positional(), rhs.positional());
+    let (llen, rlen) = (lhs.len(), rhs.len());
+    let n = if llen == 0 || rlen == 0 {
+        0
+    } else {
+        std::cmp::max(llen, rlen)
+    };
+
+    (0..n).map(move |i| (lhs.get_atom(i % llen), rhs.get_atom(i % rlen)))
+}
+
+/// Pairs of elements of two vectors, coerced to their common numeric type
+fn common_numeric_pairs<L, R, C, LNum, RNum>(
+    lhs: RepType<L>,
+    rhs: RepType<R>,
+) -> impl Iterator<Item = (C, C)>
+where
+    L: AtomicMode + Clone + Default + CoercibleInto<LNum>,
+    R: AtomicMode + Clone + Default + CoercibleInto<RNum>,
+    (LNum, RNum): CommonNum<Common = C>,
+{
+    recycled_pairs(lhs, rhs).map(|(l, r)| {
+        (
+            CoercibleInto::<LNum>::coerce_into(l),
+            CoercibleInto::<RNum>::coerce_into(r),
+        )
+            .into_common()
+    })
+}
+
+/// Apply a scalar to a sequence, producing a new sequence
+///
+/// `f` maps the sequence's `start` and `by` and the scalar value to those
+/// of the resulting sequence. `None` is produced when `scalar` is not a
+/// single, non-missing value, or when the result would leave the range of
+/// its mode.
+///
+fn seq_with_scalar<T, S, C, F>(seq: &RepType<T>, scalar: &RepType<S>, f: F) -> Option<RepType<C>>
+where
+    T: Clone,
+    S: AtomicMode + Clone + Default + CoercibleInto<Double>,
+    C: AtomicMode + Clone + Default,
+    F: Fn(f64, f64, f64) -> (f64, f64),
+{
+    let RepType::Seq { start, by, len } = *seq else {
+        return None;
+    };
+
+    if scalar.len() != 1 {
+        return None;
+    }
+
+    let OptionNA::Some(x) = CoercibleInto::<Double>::coerce_into(scalar.get_atom(0)) else {
+        return None;
+    };
+
+    let (start, by) = f(start, by, x);
+    let end = start + by * len.saturating_sub(1) as f64;
+    let in_range = |v: f64| match C::is_integer() {
+        true => (i32::MIN as f64..=i32::MAX as f64).contains(&v),
+        false => v.is_finite(),
+    };
+
+    (in_range(start) && in_range(end) && by.is_finite()).then(|| RepType::seq(start, by, len))
+}
+
 impl<L, LNum, O> std::ops::Neg for RepType<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
@@ -412,9 +565,8 @@ where
     type Output = RepType<O>;
     fn neg(self) -> Self::Output {
         RepType::from(
-            self.inner()
-                .iter()
-                .map(|l| CoercibleInto::<LNum>::coerce_into(l.clone()).neg())
+            self.into_iter()
+                .map(|l| CoercibleInto::<LNum>::coerce_into(l).neg())
                 .collect::<Vec<O>>(),
         )
     }
@@ -423,23 +575,26 @@ where
 impl<L, R, C, O, LNum, RNum> std::ops::Add<RepType<R>> for RepType<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    L: CoercibleInto<Double>,
     R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    R: CoercibleInto<Double>,
     (LNum, RNum): CommonNum<Common = C>,
+    C: AtomicMode + Default,
     C: Clone + std::ops::Add<Output = O>,
     RepType<C>: From<Vec<O>>,
 {
     type Output = RepType<C>;
     fn add(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
+        // a sequence and a scalar remain a sequence
+        let seq = seq_with_scalar(&self, &rhs, |start, by, x| (start + x, by))
+            .or_else(|| seq_with_scalar(&rhs, &self, |start, by, x| (x + start, by)));
 
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
+        if let Some(seq) = seq {
+            return seq;
+        }
 
         RepType::from(
-            map_common_numeric(zip_recycle(lhs, rhs))
+            common_numeric_pairs(self, rhs)
                 .map(|(l, r)| l + r)
                 .collect::<Vec<O>>(),
         )
@@ -449,24 +604,27 @@ where
 impl<L, R, C, O, LNum, RNum> std::ops::Sub<RepType<R>> for RepType<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    L: CoercibleInto<Double>,
     R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    R: CoercibleInto<Double>,
     (LNum, RNum): CommonNum<Common = C>,
+    C: AtomicMode + Default,
     C: std::ops::Sub<Output = O> + Clone,
     RepType<C>: From<Vec<O>>,
     O: Clone,
 {
     type Output = RepType<C>;
     fn sub(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
+        // a sequence and a scalar remain a sequence
+        let seq = seq_with_scalar(&self, &rhs, |start, by, x| (start - x, by))
+            .or_else(|| seq_with_scalar(&rhs, &self, |start, by, x| (x - start, -by)));
 
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
+        if let Some(seq) = seq {
+            return seq;
+        }
 
         RepType::from(
-            map_common_numeric(zip_recycle(lhs, rhs))
+            common_numeric_pairs(self, rhs)
                 .map(|(l, r)| l - r)
                 .collect::<Vec<O>>(),
         )
@@ -476,23 +634,26 @@ where
 impl<L, R, C, O, LNum, RNum> std::ops::Mul<RepType<R>> for RepType<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    L: CoercibleInto<Double>,
     R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    R: CoercibleInto<Double>,
     (LNum, RNum): CommonNum<Common = C>,
+    C: AtomicMode + Default,
     C: std::ops::Mul<Output = O> + Clone,
     RepType<C>: From<Vec<O>>,
 {
     type Output = RepType<C>;
     fn mul(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
+        // a sequence and a scalar remain a sequence
+        let seq = seq_with_scalar(&self, &rhs, |start, by, x| (start * x, by * x))
+            .or_else(|| seq_with_scalar(&rhs, &self, |start, by, x| (x * start, x * by)));
 
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
+        if let Some(seq) = seq {
+            return seq;
+        }
 
         RepType::from(
-            map_common_numeric(zip_recycle(lhs, rhs))
+            common_numeric_pairs(self, rhs)
                 .map(|(l, r)| l * r)
                 .collect::<Vec<O>>(),
         )
@@ -510,16 +671,8 @@ where
 {
     type Output = RepType<C>;
     fn div(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
         RepType::from(
-            map_common_numeric(zip_recycle(lhs, rhs))
+            common_numeric_pairs(self, rhs)
                 .map(|(l, r)| l / r)
                 .collect::<Vec<O>>(),
         )
@@ -537,22 +690,33 @@ where
 {
     type Output = RepType<C>;
     fn rem(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
         RepType::from(
-            map_common_numeric(zip_recycle(lhs.into_iter(), rhs.into_iter()))
+            common_numeric_pairs(self, rhs)
                 .map(|(l, r)| l.rem(r))
                 .collect::<Vec<O>>(),
         )
     }
 }
 
+impl<L, R, C, O, LNum, RNum> IntDiv<RepType<R>> for RepType<L>
+where
+    L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
+    R: AtomicMode + Default + Clone + MinimallyNumeric<As = RNum> + CoercibleInto<RNum>,
+    (LNum, RNum): CommonNum<Common = C>,
+    C: IntDiv<C, Output = O> + Clone,
+    O: Clone,
+    RepType<C>: From<Vec<O>>,
+{
+    type Output = RepType<C>;
+    fn int_div(self, rhs: RepType<R>) -> Self::Output {
+        RepType::from(
+            common_numeric_pairs(self, rhs)
+                .map(|(l, r)| l.int_div(r))
+                .collect::<Vec<O>>(),
+        )
+    }
+}
+
 impl<L, R, O, LNum, RNum> Pow<RepType<R>> for RepType<L>
 where
     L: AtomicMode + Default + Clone + MinimallyNumeric<As = LNum> + CoercibleInto<LNum>,
@@ -563,17 +727,9 @@ where
 {
     type Output = RepType<O>;
     fn power(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
         RepType::from(
-            zip_recycle(lhs, rhs)
-                .map(|(l, r)| l.clone().coerce_into().power(r.clone().coerce_into()))
+            recycled_pairs(self, rhs)
+                .map(|(l, r)| l.coerce_into().power(r.coerce_into()))
                 .collect::<Vec<O>>(),
         )
     }
@@ -589,17 +745,9 @@ where
 {
     type Output = RepType<O>;
     fn bitor(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
         RepType::from(
-            zip_recycle(lhs, rhs)
-                .map(|(l, r)| l.clone().coerce_into().bitor(r.clone().coerce_into()))
+            recycled_pairs(self, rhs)
+                .map(|(l, r)| l.coerce_into().bitor(r.coerce_into()))
                 .collect::<Vec<O>>(),
         )
     }
@@ -615,17 +763,9 @@ where
 {
     type Output = RepType<O>;
     fn bitand(self, rhs: RepType<R>) -> Self::Output {
-        let lc = self.inner().clone();
-        let lb = lc.borrow();
-        let lhs = lb.iter();
-
-        let rc = rhs.inner().clone();
-        let rb = rc.borrow();
-        let rhs = rb.iter();
-
         RepType::from(
-            zip_recycle(lhs, rhs)
-                .map(|(l, r)| l.clone().coerce_into().bitand(r.clone().coerce_into()))
+            recycled_pairs(self, rhs)
+                .map(|(l, r)| l.coerce_into().bitand(r.coerce_into()))
                 .collect::<Vec<O>>(),
         )
     }
@@ -641,9 +781,8 @@ where
     type Output = RepType<O>;
     fn not(self) -> Self::Output {
         RepType::from(
-            self.inner()
-                .iter()
-                .map(|l| CoercibleInto::<Logical>::coerce_into(l.clone()).not())
+            self.into_iter()
+                .map(|l| CoercibleInto::<Logical>::coerce_into(l).not())
                 .collect::<Vec<O>>(),
         )
     }
@@ -845,4 +984,63 @@ mod test {
         assert_eq!(xsi.next(), Option::Some(OptionNA::Some("a".to_string())));
         assert_eq!(xsi.next(), Option::None);
     }
+
+    #[test]
+    fn seq() {
+        let x = RepType::<Double>::seq(1.0, 2.0, 1_000_000_000);
+        assert_eq!(x.len(), 1_000_000_000);
+        assert_eq!(x.get_atom(999_999_999), Some(1_999_999_999_f64));
+        assert_eq!(x.get_inner(1_000_000_000), Option::None);
+        assert!(x.is_lazy());
+    }
+
+    #[test]
+    fn seq_subset() {
+        use crate::object::Subset;
+
+        let x = RepType::<Integer>::seq(1.0, 1.0, 10);
+        let range = x.subset(Subset::Range(2..5));
+        assert!(range.is_lazy());
+        assert_eq!(range.materialize(), RepType::from(vec![3, 4, 5]));
+
+        let indices = x.subset(vec![1, 3].into());
+        assert_eq!(indices, RepType::from(vec![2, 4]));
+    }
+
+    #[test]
+    fn seq_arithmetic() {
+        let x = RepType::<Integer>::seq(1.0, 1.0, 3);
+        let y = RepType::from(vec![10, 20, 30]);
+        assert_eq!(x + y, RepType::from(vec![11, 22, 33]));
+    }
+
+    #[test]
+    fn seq_scalar_arithmetic() {
+        let x = RepType::<Integer>::seq(1.0, 1.0, 1_000_000_000);
+        let y = x * RepType::from(vec![2]) - RepType::from(vec![1]);
+        assert!(y.is_lazy());
+        assert_eq!(y.get_atom(999_999_999), Some(1_999_999_999));
+
+        let z = RepType::from(vec![10]) - RepType::<Integer>::seq(1.0, 1.0, 3);
+        assert!(z.is_lazy());
+        assert_eq!(z.materialize(), RepType::from(vec![9, 8, 7]));
+
+        let half = RepType::<Integer>::seq(1.0, 1.0, 2) + RepType::from(vec![0.5]);
+        assert!(half.is_lazy() && half.is_double());
+    }
+
+    #[test]
+    fn seq_leaving_integer_range() {
+        let x = RepType::<Integer>::seq(2_147_483_646.0, 1.0, 2);
+        let y = x + RepType::from(vec![1]);
+        assert!(!y.is_lazy());
+        assert_eq!(y, RepType::from(vec![Some(2_147_483_647), NA]));
+    }
+
+    #[test]
+    fn seq_comparison() {
+        let x = RepType::<Integer>::seq(1.0, 1.0, 4);
+        let y = x.vec_gt(RepType::from(vec![2]));
+        assert_eq!(y, RepType::from(vec![false, false, true, true]));
+    }
 }
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::ops::Range;
 use std::rc::Rc;
 
+use crate::error::Error;
 use crate::lang::Signal;
 use crate::object::CowObj;
 
@@ -21,168 +22,136 @@ pub enum Subset {
 }
 
 impl Subset {
-    pub fn get_index_at(&self, index: usize) -> Option<usize> {
+    /// Whether indices exclude, rather than select, elements
+    ///
+    /// Negative indices `-k` exclude the `k`-th element (0-indexed `k - 1`),
+    /// and may not be mixed with positive indices.
+    ///
+    pub fn is_exclusion(&self) -> bool {
         match self {
-            Subset::Indices(indices) => indices.clone().borrow().get(index).and_then(|i| match i {
-                OptionNA::Some(i) => Some((*i as usize).saturating_sub(1)),
-                OptionNA::NA => None,
-            }),
-            Subset::Range(range) => {
-                if range.start <= index && index < range.end {
-                    Some(range.start + index)
-                } else {
-                    None
-                }
-            }
-            Subset::Mask(mask) => mask
-                .clone()
+            Subset::Indices(indices) => indices
                 .borrow()
                 .iter()
-                .enumerate()
-                .filter_map(|(i, m)| match m {
-                    OptionNA::Some(true) => Some(Some(i)),
-                    OptionNA::NA => Some(None),
-                    _ => None,
-                })
-                .nth(index)
-                .unwrap_or(None),
-            Subset::Names(_) => unimplemented!(),
+                .any(|i| matches!(i, OptionNA::Some(i) if *i < 0)),
+            _ => false,
         }
     }
 
-    pub fn len(&self) -> usize {
+    /// The sorted, 0-indexed positions excluded by negative indices
+    fn excluded(&self) -> Vec<usize> {
+        let Subset::Indices(indices) = self else {
+            return vec![];
+        };
+
+        let mut excluded: Vec<usize> = indices
+            .borrow()
+            .iter()
+            .filter_map(|i| match i {
+                OptionNA::Some(i) if *i < 0 => Some((-(*i as i64) - 1) as usize),
+                _ => None,
+            })
+            .collect();
+
+        excluded.sort();
+        excluded.dedup();
+        excluded
+    }
+
+    /// The number of elements selected from a vector of length `len`
+    pub fn selected_len(&self, len: usize) -> usize {
         match self {
+            Subset::Indices(_) if self.is_exclusion() => {
+                len - self.excluded().iter().filter(|&&i| i < len).count()
+            }
             Subset::Indices(i) => i.len(),
-            Subset::Range(r) => r.end - r.start,
-            Subset::Mask(_) => usize::MAX,
+            Subset::Mask(mask) => {
+                // masks are recycled to the length of the vector, and values
+                // beyond the end of the vector are selected as NA
+                let mask = mask.borrow();
+                let m = mask.len();
+                if m == 0 {
+                    return 0;
+                }
+
+                let n = std::cmp::max(len, m);
+                let selects = |x: &Logical| x != &OptionNA::Some(false);
+                let per_cycle = mask.iter().filter(|x| selects(x)).count();
+                let rest = mask[..n % m].iter().filter(|x| selects(x)).count();
+                (n / m) * per_cycle + rest
+            }
             Subset::Names(n) => n.len(),
+            Subset::Range(r) => r.end.min(len).saturating_sub(r.start),
         }
     }
 
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-
-    pub fn filter<'a, I>(
+    /// Select from a stream of indices
+    ///
+    /// Provided the `len` indices selected by preceding subsets, produce
+    /// the indices selected by this subset. Indices are `None` where an
+    /// element is `NA` or out of bounds. When `identity` is set, the
+    /// preceding indices are known to be `0..len`, which avoids having to
+    /// collect them for random access.
+    ///
+    pub fn select(
         &self,
-        mut iter: I,
-    ) -> Box<dyn Iterator<Item = (usize, Option<usize>)> + 'a>
-    where
-        I: Iterator<Item = (usize, Option<usize>)> + 'a,
-    {
-        match self.clone() {
-            Subset::Indices(i) => {
-                let l = self.len();
-
-                // fastest case, when no indices are selected
-                if l == 0 {
-                    Box::new(vec![].into_iter())
-
-                // very fast case, when one index is selected
-                } else if l == 1 {
-                    let msg = "Expected at least one element to index by";
-                    if let OptionNA::Some(to_first) = i.clone().borrow().first().expect(msg) {
-                        Box::new(iter.skip(*to_first as usize).take(1))
-                    } else {
-                        let (i_orig, _) = iter.next().unwrap_or((0, None));
-                        Box::new(vec![(i_orig, None)].into_iter())
-                    }
-
-                // fast case, when indices are already sorted
-                } else if i.borrow().windows(2).all(|w| w[0] <= w[1]) {
-                    // when sorted, we can keep our existing iterator and
-                    // embed the indices, scanning along the iterator
-                    // and yielding indices as they are encountered
-                    let ic = i.clone();
-                    let ib = ic.borrow().clone();
-
-                    Box::new(
-                        iter.enumerate()
-                            .scan(
-                                (ib, 0),
-                                |(indices, i), (xi, (xi_orig, x))| -> Option<Vec<(usize, Option<usize>)>> {
-                                    if *i >= indices.len() {
-                                        return None;
-                                    }
-
-                                    let mut n = 0;
-                                    while *i < indices.len()
-                                        && indices[*i] == OptionNA::Some(xi as i32)
-                                    {
-                                        n += 1;
-                                        *i += 1;
-                                    }
-
-                                    Some(vec![(xi_orig, x); n])
-                                },
-                            )
-                            .flatten(),
-                    )
-                // worst case, indices in random order
-                } else {
-                    // enumerate indices and swap so it's (index, enumeration)
-                    let ic = i.clone();
-                    let ib = ic.borrow();
-
-                    let mut order = ib
-                        .iter()
-                        .map(|i| match i {
-                            OptionNA::NA => -1,
-                            OptionNA::Some(i) => *i,
-                        })
-                        .enumerate()
-                        .map(|(i, v)| (v, i)) // cast NAs to -1's
-                        .collect::<Vec<_>>();
-
-                    // sort by index to get (sorted indices, order)
-                    // we'll use this to sample the existing iterator then
-                    // permute it back into the original order
-                    order.sort();
-                    let (mut i, order): (Vec<_>, Vec<_>) = order.into_iter().unzip();
-
-                    // we'll populate this with the sorted indices first
-                    let mut indices: Vec<(usize, Option<usize>)> = vec![(0, None); i.len()];
-                    let n_na = i.iter().take_while(|&i| *i == -1).count();
-
-                    // populate non-na elements
-                    i.insert(n_na, 0);
-                    let diffs = i[n_na..].windows(2).map(|w| w[1] - w[0]);
-
-                    let msg = "exhausted iterator";
-                    let (mut i_orig, mut last) = iter.nth(0).expect(msg);
-                    for (i, diff) in diffs.enumerate() {
-                        if diff > 0 {
-                            (i_orig, last) = iter.nth((diff - 1) as usize).expect(msg);
-                        }
-                        indices[order[i + n_na]] = (i_orig, last);
+        iter: Box<dyn Iterator<Item = Option<usize>>>,
+        len: usize,
+        identity: bool,
+    ) -> Box<dyn Iterator<Item = Option<usize>>> {
+        match self {
+            Subset::Indices(_) if self.is_exclusion() => {
+                let excluded = self.excluded();
+                Box::new(
+                    iter.enumerate()
+                        .filter(move |(i, _)| excluded.binary_search(i).is_err())
+                        .map(|(_, x)| x),
+                )
+            }
+            Subset::Indices(indices) => {
+                let indices = indices.borrow().clone();
+
+                // only as many preceding indices as are selected are needed
+                let prev: Vec<Option<usize>> = match identity {
+                    true => vec![],
+                    false => {
+                        let max = indices
+                            .iter()
+                            .filter_map(|i| match i {
+                                OptionNA::Some(i) => Some(*i as usize),
+                                OptionNA::NA => None,
+                            })
+                            .max();
+
+                        iter.take(max.map_or(0, |i| i + 1)).collect()
                     }
+                };
 
-                    // and finally we convert our new indices into an iterator
-                    Box::new(indices.into_iter())
-                }
+                Box::new((0..indices.len()).map(move |j| match indices[j] {
+                    OptionNA::Some(i) if identity => Some(i as usize).filter(|i| *i < len),
+                    OptionNA::Some(i) => prev.get(i as usize).copied().flatten(),
+                    OptionNA::NA => None,
+                }))
             }
             Subset::Mask(mask) => {
+                let mask = mask.borrow().clone();
+                let m = mask.len();
+                let n = if m == 0 { 0 } else { std::cmp::max(len, m) };
+
                 Box::new(
-                    (**mask.borrow())
-                        .clone()
-                        .into_iter()
-                        .cycle()
-                        .zip(iter)
-                        .filter_map(|(mask, i @ (i_orig, _))| match mask {
-                            OptionNA::Some(true) => Some(i),      // accept index
-                            OptionNA::NA => Some((i_orig, None)), // accept, but NA
-                            _ => None,                            // filter falses
+                    iter.chain(std::iter::repeat(None))
+                        .take(n)
+                        .enumerate()
+                        .filter_map(move |(i, x)| match mask[i % m] {
+                            OptionNA::Some(true) => Some(x),
+                            OptionNA::NA => Some(None),
+                            OptionNA::Some(false) => None,
                         }),
                 )
             }
-            Subset::Range(range) => Box::new(
-                iter.skip(range.start)
-                    .enumerate()
-                    .take_while(move |(i, _)| i < &(range.end - range.start))
-                    .map(|(_, v)| v),
-            ),
-            Subset::Names(_) => unimplemented!(),
+            // names of vectors are resolved to indices before subsetting,
+            // any remaining names are not found
+            Subset::Names(names) => Box::new(std::iter::repeat_n(None, names.len())),
+            Subset::Range(range) => Box::new(iter.skip(range.start).take(self.selected_len(len))),
         }
     }
 }
@@ -216,14 +185,28 @@ impl TryFrom<Vector> for Subset {
         match value {
             value @ Vector::Double(_) => Subset::try_from(value.as_integer()),
             Vector::Integer(v) => {
-                let y = v
+                // positive indices are shifted to be 0-indexed, negative
+                // indices are kept to mark exclusions and zeros are dropped
+                let y: Vec<Integer> = v
                     .into_iter()
+                    .filter(|i| i != &OptionNA::Some(0))
                     .map(|i| match i {
-                        OptionNA::Some(x) => OptionNA::Some(x - 1),
-                        OptionNA::NA => OptionNA::NA,
+                        OptionNA::Some(x) if x > 0 => OptionNA::Some(x - 1),
+                        x => x,
                     })
                     .collect();
 
+                let negative = y.iter().any(|i| matches!(i, OptionNA::Some(i) if *i < 0));
+                if negative && y.iter().any(|i| matches!(i, OptionNA::Some(i) if *i >= 0)) {
+                    let msg = "can't mix positive and negative subscripts";
+                    return Error::Other(msg.to_string()).into();
+                }
+
+                if negative && y.contains(&OptionNA::NA) {
+                    let msg = "can't mix NAs with negative subscripts";
+                    return Error::Other(msg.to_string()).into();
+                }
+
                 Ok(Subset::Indices(CowObj::new(Rc::new(RefCell::new(
                     Rc::new(y),
                 )))))
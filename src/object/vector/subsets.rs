@@ -1,7 +1,7 @@
 use crate::object::CowObj;
 use hashbrown::HashMap;
 
-use super::Subset;
+use super::{OptionNA, Subset};
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Subsets(pub Vec<Subset>);
@@ -18,18 +18,70 @@ impl Subsets {
 
     /// Get the raw index of a index applied to a subset
     ///
-    /// Provided a vector with multiple subsets applied, determine which
-    /// original index corresponds with the index applied to the subset.
+    /// Provided a vector of length `len` with multiple subsets applied,
+    /// determine which original index corresponds with the index applied to
+    /// the subset. Returns `None` when the element is `NA` or out of bounds.
     ///
-    pub fn get_index_at(&self, mut index: usize) -> Option<usize> {
-        let Subsets(subsets) = self;
-        for subset in subsets.iter().rev() {
-            match subset.get_index_at(index) {
-                Some(i) => index = i,
-                None => return None,
-            }
+    pub fn get_index_at(&self, index: usize, len: usize) -> Option<usize> {
+        match self.0.as_slice() {
+            [] => Some(index).filter(|i| *i < len),
+            _ => self.indices(len).nth(index).flatten(),
         }
-        Some(index)
+    }
+
+    /// The number of elements selected from a vector of length `len`
+    pub fn selected_len(&self, len: usize) -> usize {
+        self.0.iter().fold(len, |n, subset| subset.selected_len(n))
+    }
+
+    /// Resolve subsets into indices of a vector of length `len`
+    ///
+    /// Produces, for each element of the subsetted vector, the index of the
+    /// original element, or `None` where the element is `NA` or out of
+    /// bounds. Indices are produced lazily, such that only as many
+    /// elements as are consumed are ever visited.
+    ///
+    pub fn indices(&self, len: usize) -> Box<dyn Iterator<Item = Option<usize>>> {
+        self.resolve(len, None)
+    }
+
+    fn resolve(
+        &self,
+        len: usize,
+        names: Option<&CowObj<HashMap<String, Vec<usize>>>>,
+    ) -> Box<dyn Iterator<Item = Option<usize>>> {
+        let mut iter: Box<dyn Iterator<Item = Option<usize>>> = Box::new((0..len).map(Some));
+        let mut n = len;
+
+        for (i, subset) in self.0.iter().enumerate() {
+            iter = match (subset, names) {
+                (Subset::Names(subset_names), Some(names)) => {
+                    let selected: Vec<Option<usize>> = iter.collect();
+                    let names = names.borrow();
+
+                    // each name selects its first element within the subset
+                    let indices: Vec<Option<usize>> = subset_names
+                        .borrow()
+                        .iter()
+                        .map(|name| match name {
+                            OptionNA::Some(name) => names
+                                .get(name)?
+                                .iter()
+                                .find(|i| selected.contains(&Some(**i)))
+                                .copied(),
+                            OptionNA::NA => None,
+                        })
+                        .collect();
+
+                    Box::new(indices.into_iter())
+                }
+                _ => subset.select(iter, n, i == 0),
+            };
+
+            n = subset.selected_len(n);
+        }
+
+        iter
     }
 
     pub fn push<T>(self, subset: T)
@@ -48,6 +100,17 @@ impl Subsets {
     }
 }
 
+impl NamedSubsets {
+    /// Resolve subsets into indices of a list of length `len`
+    ///
+    /// See [Subsets::indices]. Names are resolved to the first element of
+    /// the subset with that name.
+    ///
+    pub fn indices(&self, len: usize) -> Box<dyn Iterator<Item = Option<usize>>> {
+        self.subsets.resolve(len, Some(&self.names))
+    }
+}
+
 impl<T> From<Vec<T>> for Subsets
 where
     T: Into<Subset>,
@@ -58,88 +121,6 @@ where
     }
 }
 
-impl IntoIterator for NamedSubsets {
-    type Item = (usize, Option<usize>);
-    type IntoIter = Box<dyn Iterator<Item = Self::Item>>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        let mut iter = Box::new((0_usize..).map(|i| (i, Some(i)))) as Self::IntoIter;
-        let Subsets(subsets) = self.subsets;
-        for subset in subsets {
-            match subset {
-                Subset::Names(names) => {
-                    use super::OptionNA;
-                    let snames = self.names.borrow();
-
-                    // grab indices within subset to find first named index
-                    let (_, hint_n_max) = iter.size_hint();
-                    let subset_indices: Vec<_> = match hint_n_max {
-                        Some(n) => iter.map(|(i, _)| i).take(n).collect(),
-                        None => {
-                            // figure out the absolute maximum value we may require
-                            let mut n = 0_usize;
-                            for name in names.borrow().iter() {
-                                let OptionNA::Some(name) = name else { continue };
-                                let name_max = snames
-                                    .get(name)
-                                    .and_then(|name| name.iter().reduce(|l, r| std::cmp::max(l, r)))
-                                    .unwrap_or(&0);
-
-                                n = std::cmp::max(n, *name_max)
-                            }
-                            iter.map(|(i, _)| i).take(n + 1).collect()
-                        }
-                    };
-
-                    // for each name, find the first index in the subset
-                    let named_indices = names
-                        .borrow()
-                        .iter()
-                        .filter_map(|name| match name {
-                            OptionNA::NA => None,
-                            OptionNA::Some(name) => snames
-                                .get(name)
-                                .and_then(|name_indices| {
-                                    for i in name_indices {
-                                        if subset_indices.contains(i) {
-                                            return Some(Some((*i, Some(*i))));
-                                        }
-                                    }
-                                    None
-                                })
-                                .unwrap_or(None),
-                        })
-                        .collect::<Vec<_>>();
-
-                    iter = Box::new(named_indices.into_iter()) as Self::IntoIter
-                }
-                _ => iter = subset.filter(iter),
-            }
-        }
-        iter
-    }
-}
-
-impl IntoIterator for Subsets {
-    type Item = (usize, Option<usize>);
-    type IntoIter = Box<dyn Iterator<Item = Self::Item>>;
-
-    /// Convert Subsets into an iterator of indices
-    ///
-    /// Builds an iterator of indices from a collection of subsets. Iterators
-    /// will provide the maximum number of indices, meaning that ranges
-    /// and masks may be infinite.
-    ///
-    fn into_iter(self) -> Self::IntoIter {
-        let Subsets(subsets) = self;
-        let mut iter = Box::new((0_usize..).map(|i| (i, Some(i)))) as Self::IntoIter;
-        for subset in subsets {
-            iter = subset.filter(iter);
-        }
-        iter
-    }
-}
-
 #[cfg(test)]
 mod test {
     use crate::object::Vector;
@@ -227,6 +208,43 @@ mod test {
         assert_eq!(result, expect);
     }
 
+    #[test]
+    fn nested_subset_length() {
+        let x: Vector = vec![-1, 2, -3, 4, 5].into();
+        let mask: Vector = vec![false, true, false, true, true].into();
+        let subset = x.subset(mask.try_into().unwrap()).subset((0..2).into());
+        assert_eq!(subset.len(), 2);
+        assert_eq!(subset.materialize(), Vector::from(vec![2, 4]));
+    }
+
+    #[test]
+    fn subset_excluded_indices() {
+        let x: Vector = (1..=5).collect::<Vec<_>>().into();
+        let index: Vector = vec![-1, -4, -9].into();
+        let result = x.subset(index.try_into().unwrap());
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.materialize(), Vector::from(vec![2, 3, 5]));
+    }
+
+    #[test]
+    fn subset_out_of_bounds_indices() {
+        use crate::object::OptionNA;
+        let x: Vector = vec![1, 2, 3].into();
+        let result = x.subset(vec![2, 5].into()).materialize();
+        let expect = Vector::from(vec![OptionNA::Some(3), OptionNA::NA]);
+        assert_eq!(result, expect);
+    }
+
+    #[test]
+    fn subset_recycled_mask() {
+        use crate::object::OptionNA;
+        let x: Vector = vec![1, 2, 3].into();
+        let mask: Vector = vec![OptionNA::Some(true), OptionNA::NA].into();
+        let result = x.subset(mask.try_into().unwrap()).materialize();
+        let expect = Vector::from(vec![OptionNA::Some(1), OptionNA::NA, OptionNA::Some(3)]);
+        assert_eq!(result, expect);
+    }
+
     #[test]
     fn subset_assignment() {
         let x: Vector = (1..=10).collect::<Vec<_>>().into();
@@ -1,4 +1,4 @@
-use super::coercion::AtomicMode;
+use super::coercion::{AtomicMode, CoercibleInto};
 use super::OptionNA;
 
 pub type Double = OptionNA<f64>;
@@ -6,6 +6,9 @@ impl AtomicMode for Double {
     fn is_double() -> bool {
         true
     }
+    fn from_seq(x: f64) -> Self {
+        OptionNA::Some(x).coerce_into()
+    }
 }
 
 pub type Integer = OptionNA<i32>;
@@ -13,6 +16,9 @@ impl AtomicMode for Integer {
     fn is_integer() -> bool {
         true
     }
+    fn from_seq(x: f64) -> Self {
+        OptionNA::Some(x).coerce_into()
+    }
 }
 
 pub type Logical = OptionNA<bool>;
@@ -20,6 +26,9 @@ impl AtomicMode for Logical {
     fn is_logical() -> bool {
         true
     }
+    fn from_seq(x: f64) -> Self {
+        OptionNA::Some(x).coerce_into()
+    }
 }
 
 pub type Character = OptionNA<String>;
@@ -27,4 +36,7 @@ impl AtomicMode for Character {
     fn is_character() -> bool {
         true
     }
+    fn from_seq(x: f64) -> Self {
+        OptionNA::Some(x).coerce_into()
+    }
 }
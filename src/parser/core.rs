@@ -33,9 +33,38 @@ where
 {
     pratt
         .map_primary(|pair| parse_primary(config, parser, pratt, pair))
+        .map_prefix(|op, rhs| match op.as_rule().into() {
+            // one-sided formulas, `~ x`
+            en::Rule::tilde_prefix => Ok(Expr::new_primitive_call(PrefixTilde, vec![rhs?].into())),
+            rule => {
+                let span = (op.as_span().start(), op.as_span().end());
+                Err(Error::ParseUnexpected(rule, span).into())
+            }
+        })
         .map_infix(|lhs, op, rhs| {
             // infix operator with two unnamed arguments
             let args = vec![(None, lhs?), (None, rhs?)].into();
+
+            // special operators, `%op%`, are calls to functions of that name
+            if let en::Rule::special = op.as_rule().into() {
+                let what = Expr::Symbol(op.as_str().to_string());
+                return Ok(Expr::Call(Box::new(what), args));
+            }
+
+            // rightward assignment, `value -> x`, is assignment with its
+            // arguments reversed
+            let rightward: Option<Box<dyn Builtin>> = match op.as_rule().into() {
+                en::Rule::right_assign => Some(Box::new(InfixAssign)),
+                en::Rule::right_super_assign => Some(Box::new(InfixSuperAssign)),
+                _ => None,
+            };
+
+            if let Some(op) = rightward {
+                let (value, target) = args.unnamed_binary_args();
+                let args = vec![(None, target), (None, value)].into();
+                return Ok(Expr::Call(Box::new(Expr::Primitive(op)), args));
+            }
+
             let op: Box<dyn Builtin> = match op.as_rule().into() {
                 en::Rule::add => Box::new(InfixAdd),
                 en::Rule::subtract => Box::new(InfixSub),
@@ -46,6 +75,7 @@ where
                 en::Rule::colon => Box::new(InfixColon),
                 en::Rule::modulo => Box::new(InfixMod),
                 en::Rule::assign => Box::new(InfixAssign),
+                en::Rule::super_assign => Box::new(InfixSuperAssign),
                 en::Rule::or => Box::new(InfixOr),
                 en::Rule::and => Box::new(InfixAnd),
                 en::Rule::vor => Box::new(InfixVectorOr),
@@ -57,6 +87,7 @@ where
                 en::Rule::eq => Box::new(InfixEqual),
                 en::Rule::neq => Box::new(InfixNotEqual),
                 en::Rule::pipe => Box::new(InfixPipe),
+                en::Rule::tilde => Box::new(InfixTilde),
                 rule => {
                     let span = (op.as_span().start(), op.as_span().end());
                     return Err(Error::ParseUnexpected(rule, span).into());
@@ -97,6 +128,7 @@ where
         en::Rule::kw_break => Ok(Expr::Break),
         en::Rule::kw_continue => Ok(Expr::Continue),
         en::Rule::kw_return => parse_return(config, parser, pratt, pair),
+        en::Rule::kw_match => parse_match(config, parser, pratt, pair),
 
         // reserved values
         en::Rule::val_true => Ok(Expr::Bool(true)),
@@ -108,6 +140,9 @@ where
         // reserved symbols
         en::Rule::more => Ok(Expr::More),
 
+        // empty arguments, as in `x[, 1]`
+        en::Rule::elem_empty => Ok(Expr::Missing),
+
         // atomic values
         en::Rule::number => Ok(Expr::Number(
             pair.as_str()
@@ -121,8 +156,8 @@ where
                 .parse::<i32>()
                 .map_or(internal_err!(), Ok)?,
         )),
-        en::Rule::single_quoted_string => Ok(Expr::String(String::from(pair.as_str()))),
-        en::Rule::double_quoted_string => Ok(Expr::String(String::from(pair.as_str()))),
+        en::Rule::single_quoted_string => Ok(Expr::String(unescape(pair.as_str()))),
+        en::Rule::double_quoted_string => Ok(Expr::String(unescape(pair.as_str()))),
 
         // structured values
         en::Rule::vec => parse_vec(config, parser, pratt, pair),
@@ -193,6 +228,12 @@ where
 {
     let mut inner = pair.into_inner();
     let name = String::from(inner.next().unwrap().as_str());
+
+    // named arguments without a value, as in `switch(x, a = , b = 1)`
+    if inner.peek().is_none() {
+        return Ok((Some(name), Expr::Missing));
+    }
+
     Ok((Some(name), parse_expr(config, parser, pratt, inner)?))
 }
 
@@ -309,6 +350,118 @@ where
     Ok(Expr::new_primitive_call(KeywordReturn, args))
 }
 
+fn parse_match<P, R>(
+    config: &SessionParserConfig,
+    parser: &P,
+    pratt: &PrattParser<R>,
+    pair: Pair<R>,
+) -> ParseResult
+where
+    P: Parser<R> + LocalizedParser,
+    R: RuleType + Into<en::Rule>,
+{
+    let mut inner = pair.into_inner();
+    let inner_value = inner.next().map_or(internal_err!(), Ok)?.into_inner();
+    let value = parse_expr(config, parser, pratt, inner_value)?;
+
+    // each arm is represented as a list of its pattern, guard and body
+    let mut args = vec![value];
+    for arm in inner {
+        let mut guard = Expr::Missing;
+        let mut body = Expr::Missing;
+
+        let mut inner_arm = arm.into_inner();
+        let pattern = inner_arm.next().map_or(internal_err!(), Ok)?;
+        let pattern = parse_pattern(config, parser, pratt, pattern)?;
+
+        for part in inner_arm {
+            match part.as_rule().into() {
+                en::Rule::match_guard => {
+                    let inner_guard = part.into_inner().next().map_or(internal_err!(), Ok)?;
+                    guard = parse_expr(config, parser, pratt, inner_guard.into_inner())?;
+                }
+                _ => body = parse_expr(config, parser, pratt, part.into_inner())?,
+            }
+        }
+
+        args.push(Expr::List(ExprList::from(vec![pattern, guard, body])));
+    }
+
+    Ok(Expr::new_primitive_call(KeywordMatch, ExprList::from(args)))
+}
+
+/// Parse a destructuring pattern
+///
+/// Patterns reuse expressions for their representation. Symbols bind values
+/// (with `_` as a wildcard), rest patterns such as `..rest` are ellipses,
+/// literals are their values and list and vector patterns are calls to
+/// `(...)` and `[...]` of their element patterns.
+///
+fn parse_pattern<P, R>(
+    config: &SessionParserConfig,
+    parser: &P,
+    pratt: &PrattParser<R>,
+    pair: Pair<R>,
+) -> ParseResult
+where
+    P: Parser<R> + LocalizedParser,
+    R: RuleType + Into<en::Rule>,
+{
+    match pair.as_rule().into() {
+        en::Rule::pattern_wildcard => Ok(Expr::Symbol("_".to_string())),
+        en::Rule::pattern_bind => {
+            let symbol = pair.into_inner().next().map_or(internal_err!(), Ok)?;
+            Ok(Expr::Symbol(symbol.as_str().to_string()))
+        }
+        en::Rule::pattern_rest => {
+            let name = pair.into_inner().next().map(|s| s.as_str().to_string());
+            Ok(Expr::Ellipsis(name))
+        }
+        rule @ (en::Rule::pattern_list | en::Rule::pattern_vec) => {
+            let elements: ExprList = pair
+                .into_inner()
+                .map(|i| match i.as_rule().into() {
+                    en::Rule::pattern_named => {
+                        let mut inner = i.into_inner();
+                        let name = inner.next().map_or(internal_err!(), Ok)?.as_str();
+                        let pattern = inner.next().map_or(internal_err!(), Ok)?;
+                        Ok((
+                            Some(name.to_string()),
+                            parse_pattern(config, parser, pratt, pattern)?,
+                        ))
+                    }
+                    _ => Ok((None, parse_pattern(config, parser, pratt, i)?)),
+                })
+                .collect::<Result<_, Signal>>()?;
+
+            match rule {
+                en::Rule::pattern_list => Ok(Expr::new_primitive_call(PrimList, elements)),
+                _ => Ok(Expr::new_primitive_call(PrimVec, elements)),
+            }
+        }
+        en::Rule::pattern_literal => {
+            let mut inner = pair.into_inner();
+            let mut literal = inner.next().map_or(internal_err!(), Ok)?;
+
+            let negative = matches!(literal.as_rule().into(), en::Rule::pattern_negative);
+            if negative {
+                literal = inner.next().map_or(internal_err!(), Ok)?;
+            }
+
+            match (negative, parse_primary(config, parser, pratt, literal)?) {
+                (true, Expr::Number(x)) => Ok(Expr::Number(-x)),
+                (true, Expr::Integer(x)) => Ok(Expr::Integer(-x)),
+                (true, Expr::Inf) => Ok(Expr::Number(f64::NEG_INFINITY)),
+                (_, literal) => Ok(literal),
+            }
+        }
+        rule => {
+            let span = (pair.as_span().start(), pair.as_span().end());
+            Err(Error::ParseUnexpected(rule, span).into())
+        }
+    }
+}
+
 fn parse_symbol<P, R>(
     _config: &SessionParserConfig,
     _parser: &P,
@@ -508,6 +661,40 @@ where
     Ok(result)
 }
 
+/// Resolve the escape sequences of a string literal
+///
+/// The grammar only admits escaped quotes, backslashes and slashes, the
+/// control characters `\b`, `\f`, `\n`, `\r` and `\t`, and unicode
+/// escapes of four hexadecimal digits, like `\u00e9`.
+///
+fn unescape(x: &str) -> String {
+    let mut result = String::with_capacity(x.len());
+    let mut chars = x.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('b') => result.push('\u{8}'),
+            Some('f') => result.push('\u{c}'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                let code = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32);
+                result.push(code.unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            Some(c) => result.push(c),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
 fn parse_vec<P, R>(
     config: &SessionParserConfig,
     parser: &P,
@@ -585,4 +772,25 @@ mod test {
             r! {{"0.000123"}}
         }
     }
+    #[test]
+    fn string_escapes() {
+        assert_eq! {
+            r! {{r#"nchar("a\tb\n\"\\")"#}},
+            r! {{"6L"}}
+        }
+    }
+    #[test]
+    fn string_unicode_escapes() {
+        assert_eq! {
+            r! {{r#""\u00e9""#}},
+            r! {{"\"é\""}}
+        }
+    }
+    #[test]
+    fn strings_print_escaped() {
+        assert_eq!(
+            format!("{}", r! {{ r#""say \"hi\"\n""# }}.unwrap()),
+            "[1] \"say \\\"hi\\\"\\n\""
+        );
+    }
 }
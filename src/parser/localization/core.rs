@@ -36,6 +36,135 @@ pub enum Localization {
     Emoji,
 }
 
+impl Localization {
+    /// The keywords of the localized grammar
+    ///
+    /// Keywords are the accepted spellings of each of the localization's
+    /// `loc_*` grammar rules, in the order of those rules.
+    ///
+    pub fn keywords(&self) -> &'static [&'static str] {
+        use Localization::*;
+        match self {
+            En => &[
+                "if", "else", "for", "in", "while", "repeat", "return", "break", "continue",
+                "match", "function", "fn", "NA", "na", "NULL", "null", "Inf", "inf", "TRUE",
+                "true", "FALSE", "false",
+            ],
+            Es => &[
+                "si",
+                "sino",
+                "para",
+                "en",
+                "mientras",
+                "repetir",
+                "retorno",
+                "interrumpir",
+                "continuar",
+                "coincidir",
+                "función",
+                "funcion",
+                "fn",
+                "NA",
+                "na",
+                "NULO",
+                "nulo",
+                "Inf",
+                "inf",
+                "VERDADERO",
+                "verdadero",
+                "FALSO",
+                "falso",
+            ],
+            De => &[
+                "falls",
+                "sonst",
+                "für",
+                "in",
+                "solange",
+                "wiederhole",
+                "zurück",
+                "abbruch",
+                "weiter",
+                "vergleiche",
+                "funktion",
+                "fn",
+                "NA",
+                "na",
+                "NULL",
+                "null",
+                "UNENDL",
+                "unendl",
+                "WAHR",
+                "wahr",
+                "FALSCH",
+                "falsch",
+            ],
+            Zh => &[
+                "假使",
+                "否則",
+                "取",
+                "自",
+                "當",
+                "重复",
+                "傳回",
+                "中斷",
+                "繼續",
+                "匹配",
+                "函数",
+                "不适用",
+                "空",
+                "无穷",
+                "真",
+                "實",
+                "假",
+                "虛",
+            ],
+            Pirate => &[
+                "ho",
+                "heave",
+                "thar",
+                "be",
+                "whilst",
+                "maroon",
+                "X marks the",
+                "mutiny!",
+                "ahead!",
+                "parley",
+                "Ahoy",
+                "ahoy",
+                "BILGE",
+                "bilge",
+                "HORNSWAGGLE",
+                "hornswaggle",
+                "BEYOND",
+                "beyond",
+                "AYE",
+                "aye",
+                "NAY",
+                "nay",
+            ],
+            Emoji => &[
+                "🤔",
+                "🤷",
+                "🫡",
+                "👈",
+                "🥱",
+                "😵‍💫",
+                "💁",
+                "💔",
+                "💫",
+                "🧩",
+                "🤖",
+                "😶‍🌫️",
+                "🫥",
+                "👽",
+                "💯",
+                "🔥",
+            ],
+        }
+    }
+}
+
 impl LocalizedParser for Localization {
     fn parse_input_with(&self, input: &str, config: &SessionParserConfig) -> ParseResult {
         use Localization::*;
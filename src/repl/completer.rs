@@ -0,0 +1,194 @@
+use hashbrown::HashMap;
+use reedline::{Completer, Span, Suggestion};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+use crate::callable::builtins::BUILTIN;
+use crate::object::{Environment, Obj, OptionNA};
+use crate::parser::Localization;
+use crate::session::Session;
+
+/// Names in scope, captured from a session's environment
+#[derive(Debug, Clone, Default, PartialEq)]
+struct CompletionNames {
+    symbols: BTreeSet<String>,
+    members: HashMap<String, Vec<String>>,
+}
+
+// A thread-safe snapshot of the names in scope of a session for passing to
+// reedline::Completer, refreshed after each evaluation
+#[derive(Debug, Clone, Default)]
+pub struct SessionCompleter {
+    pub locale: Localization,
+    names: Arc<Mutex<CompletionNames>>,
+}
+
+impl From<Session> for SessionCompleter {
+    fn from(val: Session) -> Self {
+        SessionCompleter {
+            locale: val.locale,
+            ..Default::default()
+        }
+    }
+}
+
+impl SessionCompleter {
+    /// Refresh the snapshot of names from an environment and its parents
+    pub fn update(&self, env: &Environment) {
+        let mut names = CompletionNames::default();
+        names.symbols.extend(BUILTIN.keys().map(|k| k.to_string()));
+
+        let mut env = Some(env);
+        while let Some(e) = env {
+            for (name, value) in e.values.borrow().iter() {
+                names.symbols.insert(name.clone());
+
+                // lists contribute the names of their members, of which
+                // those of the innermost environment take precedence
+                if let Obj::List(l) = value {
+                    let members = l.names().unwrap_or_default().into_iter();
+                    let members = members.filter_map(|name| match name {
+                        OptionNA::Some(name) => Some(name),
+                        OptionNA::NA => None,
+                    });
+
+                    names
+                        .members
+                        .entry(name.clone())
+                        .or_insert_with(|| members.collect());
+                }
+            }
+
+            env = e.parent.as_deref();
+        }
+
+        if let Ok(mut snapshot) = self.names.lock() {
+            *snapshot = names;
+        }
+    }
+}
+
+/// Whether a character may be part of a symbol
+fn is_symbol_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '.' || c == '_'
+}
+
+/// Find the start of the symbol ending at a position
+fn symbol_start(line: &str, end: usize) -> usize {
+    line[..end]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_symbol_char(*c))
+        .last()
+        .map_or(end, |(i, _)| i)
+}
+
+impl Completer for SessionCompleter {
+    fn complete(&mut self, line: &str, pos: usize) -> Vec<Suggestion> {
+        let Ok(names) = self.names.lock() else {
+            return vec![];
+        };
+
+        let start = symbol_start(line, pos);
+        let prefix = &line[start..pos];
+
+        // following `x$`, complete the names of the members of `x`
+        let candidates: Vec<&str> = match line[..start].strip_suffix('$') {
+            Some(before) => {
+                let symbol = &before[symbol_start(before, before.len())..];
+                match names.members.get(symbol) {
+                    Some(members) => members.iter().map(|m| m.as_str()).collect(),
+                    None => vec![],
+                }
+            }
+            None if prefix.is_empty() => vec![],
+            None => names
+                .symbols
+                .iter()
+                .map(|s| s.as_str())
+                .chain(self.locale.keywords().iter().copied())
+                .collect(),
+        };
+
+        let mut values: Vec<&str> = candidates
+            .into_iter()
+            .filter(|c| c.starts_with(prefix))
+            .collect();
+
+        values.sort();
+        values.dedup();
+
+        values
+            .into_iter()
+            .map(|value| Suggestion {
+                value: value.to_string(),
+                description: None,
+                extra: None,
+                span: Span::new(start, pos),
+                append_whitespace: false,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::CallStack;
+
+    fn completer(expr: &str) -> SessionCompleter {
+        let env = Environment::global();
+
+        let stack = CallStack::default().with_global_env(env.clone());
+        stack.parse_and_eval(expr).unwrap();
+
+        let completer = SessionCompleter::default();
+        completer.update(&env);
+        completer
+    }
+
+    fn complete(completer: &mut SessionCompleter, line: &str) -> Vec<String> {
+        completer
+            .complete(line, line.len())
+            .into_iter()
+            .map(|s| s.value)
+            .collect()
+    }
+
+    #[test]
+    fn completes_symbols() {
+        let mut c = completer("my_value <- 1\nmy_other <- 2");
+        assert_eq!(complete(&mut c, "1 + my_"), vec!["my_other", "my_value"]);
+    }
+
+    #[test]
+    fn completes_builtins_and_keywords() {
+        let mut c = completer("NULL");
+        assert!(complete(&mut c, "paste").contains(&"paste".to_string()));
+        assert!(complete(&mut c, "fun").contains(&"function".to_string()));
+    }
+
+    #[test]
+    fn completes_list_members() {
+        let mut c = completer("l <- list(alpha = 1, beta = 2, also = 3)");
+        assert_eq!(complete(&mut c, "l$al"), vec!["alpha", "also"]);
+        assert_eq!(complete(&mut c, "l$"), vec!["alpha", "also", "beta"]);
+    }
+
+    #[test]
+    fn replaces_symbol_span() {
+        let mut c = completer("my_value <- 1");
+        let suggestions = c.complete("f(my_v", 6);
+        assert_eq!(suggestions[0].span, Span::new(2, 6));
+    }
+
+    #[test]
+    fn localized_keywords() {
+        let mut c = completer("NULL");
+        c.locale = Localization::Es;
+        assert!(complete(&mut c, "mien").contains(&"mientras".to_string()));
+
+        c.locale = Localization::Pirate;
+        assert_eq!(complete(&mut c, "X"), vec!["X marks the"]);
+    }
+}
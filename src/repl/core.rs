@@ -1,9 +1,13 @@
-use reedline::{FileBackedHistory, Reedline};
+use reedline::{
+    default_emacs_keybindings, ColumnarMenu, Emacs, FileBackedHistory, KeyCode, KeyModifiers,
+    Reedline, ReedlineEvent, ReedlineMenu,
+};
 use std::io::Write;
-use std::rc::Rc;
 
+use super::completer::SessionCompleter;
 use super::prompt::Prompt;
 use super::release::*;
+use crate::callable::primitive::print_value;
 use crate::context::Context;
 use crate::lang::{CallStack, Cond, Signal};
 use crate::object::Environment;
@@ -12,10 +16,7 @@ use crate::session::{Session, SessionParserConfig};
 
 pub fn repl(mut session: Session) -> Result<(), Signal> {
     writeln!(session.output, "{}", session_header(&session)).ok();
-    let global_env = Rc::new(Environment {
-        parent: Some(Environment::from_builtins()),
-        ..Default::default()
-    });
+    let global_env = Environment::global();
 
     let history = session
         .history
@@ -27,9 +28,27 @@ pub fn repl(mut session: Session) -> Result<(), Signal> {
         });
 
     let parser_config: SessionParserConfig = session.clone().into();
+    let completer: SessionCompleter = session.clone().into();
+    completer.update(&global_env);
+
+    // tab opens the completion menu, or cycles through its suggestions
+    let mut keybindings = default_emacs_keybindings();
+    keybindings.add_binding(
+        KeyModifiers::NONE,
+        KeyCode::Tab,
+        ReedlineEvent::UntilFound(vec![
+            ReedlineEvent::Menu("completion_menu".to_string()),
+            ReedlineEvent::MenuNext,
+        ]),
+    );
+
+    let completion_menu = ColumnarMenu::default().with_name("completion_menu");
     let mut line_editor = Reedline::create()
         .with_validator(Box::new(parser_config.clone()))
         .with_highlighter(Box::new(parser_config.clone()))
+        .with_completer(Box::new(completer.clone()))
+        .with_menu(ReedlineMenu::EngineCompleter(Box::new(completion_menu)))
+        .with_edit_mode(Box::new(Emacs::new(keybindings)))
         .with_history(Box::new(history));
 
     // initialize our repl prompt
@@ -52,20 +71,34 @@ pub fn repl(mut session: Session) -> Result<(), Signal> {
                         let mut stack =
                             CallStack::from(session.clone()).with_global_env(global_env.clone());
 
-                        match stack.eval_and_finalize(expr) {
-                            Err(Signal::Condition(Cond::Terminate)) => break,
-                            Err(Signal::Return(value, true)) => {
-                                write!(session.output, "{value}").ok();
+                        // values are printed as by `print()`, dispatching on their class
+                        let result = match stack.eval_and_finalize(expr) {
+                            Ok(value) | Err(Signal::Return(value, true)) => {
+                                print_value(value, vec![], &mut stack)
                             }
+                            result => result,
+                        };
+
+                        match result {
+                            Err(Signal::Condition(Cond::Terminate)) => break,
                             Err(Signal::Return(_value, false)) => (),
                             Err(e) => {
                                 write!(session.output, "{e}").ok();
                                 write!(session.output, "backtrace:\n{stack}").ok();
                             }
-                            Ok(val) => {
-                                writeln!(session.output, "{val}").ok();
-                            }
+                            Ok(_) => (),
                         }
+
+                        // run on.exit() handlers of any frames left by an error
+                        if let Err(e) = stack.unwind(1) {
+                            write!(session.output, "{e}").ok();
+                        }
+
+                        if let Some(warnings) = stack.flush_warnings() {
+                            write!(session.output, "{warnings}").ok();
+                        }
+
+                        completer.update(&global_env);
                     }
                     Err(e) => eprint!("{e}"),
                 }
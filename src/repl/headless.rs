@@ -57,10 +57,7 @@ pub fn wasm_runtime(args: JsValue) -> JsValue {
     log(&format!("Launching runtime with args: {args:?}"));
 
     // build our global environment
-    let global_env = Rc::new(Environment {
-        parent: Some(Environment::from_builtins()),
-        ..Default::default()
-    });
+    let global_env = Environment::global();
 
     // build a callback to evaluate with a enclosed environment, allows
     // for a callback to be provided to handle stdout
@@ -141,11 +138,22 @@ pub fn wasm_eval_in(args: &Session, env: &Rc<Environment>, input: &str) -> Optio
     match parser_config.parse_input(input) {
         Ok(expr) => {
             let mut stack = CallStack::from(args.clone()).with_global_env(env.clone());
-            match stack.eval_and_finalize(expr) {
-                Err(Signal::Condition(Cond::Terminate)) => None,
-                Ok(val) => Some(format!("{val}")),
-                Err(e) => Some(format!("{e}")),
+            let mut output = match stack.eval_and_finalize(expr) {
+                Err(Signal::Condition(Cond::Terminate)) => return None,
+                Ok(val) => format!("{val}"),
+                Err(e) => format!("{e}"),
+            };
+
+            // run on.exit() handlers of any frames left by an error
+            if let Err(e) = stack.unwind(1) {
+                output.push_str(&format!("{e}"));
             }
+
+            if let Some(warnings) = stack.flush_warnings() {
+                output.push_str(&format!("\n{warnings}"));
+            }
+
+            Some(output)
         }
         Err(Signal::Thunk) => None,
         Err(e) => Some(format!("{e}")),
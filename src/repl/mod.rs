@@ -1,9 +1,15 @@
 mod release;
 
+mod script;
+pub use script::*;
+
 #[cfg(feature = "repl")]
 mod core;
 pub use core::*;
 
+#[cfg(feature = "repl")]
+pub mod completer;
+
 #[cfg(feature = "repl")]
 pub mod highlight;
 
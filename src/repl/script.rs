@@ -0,0 +1,216 @@
+use std::rc::Rc;
+
+use crate::callable::primitive::print_value;
+use crate::context::Context;
+use crate::error::Error;
+use crate::lang::{CallStack, Cond, Signal};
+use crate::object::Environment;
+use crate::parser::LocalizedParser;
+use crate::session::{Session, SessionParserConfig};
+
+/// Evaluate expressions and an optional script file non-interactively
+///
+/// Expressions are evaluated first, followed by the contents of the script
+/// file, all within a single fresh global environment. Visible results are
+/// printed just as they would be in the REPL. Evaluation stops at the first
+/// uncaught error, which is reported alongside its backtrace and returned.
+pub fn script(mut session: Session, exprs: &[String], file: Option<&str>) -> Result<(), Signal> {
+    let global_env = Environment::global();
+
+    let mut inputs = exprs.to_vec();
+    if let Some(path) = file {
+        match std::fs::read_to_string(path) {
+            Ok(content) => inputs.push(content),
+            Err(e) => {
+                let e = Error::Other(format!("cannot open file '{path}': {e}"));
+                eprintln!("{e}");
+                return Err(e.into());
+            }
+        }
+    }
+
+    for input in inputs {
+        match eval_input(&mut session, &global_env, &input) {
+            Err(Signal::Condition(Cond::Terminate)) => break,
+            Err(e) => return Err(e),
+            Ok(()) => (),
+        }
+    }
+
+    Ok(())
+}
+
+/// Evaluate each top-level expression of an input
+///
+/// Lines are accumulated until they form a complete expression, mirroring
+/// how the REPL validates multi-line entries. Input is only continued while
+/// the parser fails at the end of the accumulated lines; any other parse
+/// failure is reported immediately at its position in the input.
+fn eval_input(
+    session: &mut Session,
+    global_env: &Rc<Environment>,
+    input: &str,
+) -> Result<(), Signal> {
+    let parser_config: SessionParserConfig = session.clone().into();
+    let mut buffer = String::new();
+
+    // byte offsets of the lines accumulated in the buffer
+    let mut buffer_lines: Vec<usize> = vec![];
+
+    for (offset, line) in lines_with_offsets(input) {
+        if !buffer.is_empty() {
+            buffer.push('\n');
+        }
+        buffer.push_str(line);
+        buffer_lines.push(offset);
+
+        // skip all-whitespace entries
+        if buffer.chars().all(char::is_whitespace) {
+            buffer.clear();
+            buffer_lines.clear();
+            continue;
+        }
+
+        let expr = match parser_config.parse_input(&buffer) {
+            Ok(expr) => expr,
+            Err(Signal::Thunk) => {
+                buffer.clear();
+                buffer_lines.clear();
+                continue;
+            }
+            // incomplete expression, continue reading lines
+            Err(Signal::Error(e)) if e.is_incomplete_input(&buffer) => continue,
+            Err(Signal::Error(e)) => {
+                let e = relocate_parse_error(e, &buffer, &buffer_lines, input);
+                eprintln!("{e}");
+                return Err(e.into());
+            }
+            Err(e) => return Err(e),
+        };
+
+        buffer_lines.clear();
+        buffer.clear();
+        let mut stack = CallStack::from(session.clone()).with_global_env(global_env.clone());
+
+        // values are printed as by `print()`, dispatching on their class
+        let result = match stack.eval_and_finalize(expr) {
+            Ok(value) | Err(Signal::Return(value, true)) => print_value(value, vec![], &mut stack),
+            result => result,
+        };
+
+        if let Err(e @ Signal::Error(_)) = &result {
+            eprint!("{e}");
+            eprint!("backtrace:\n{stack}");
+        }
+
+        // run on.exit() handlers of any frames left by an error
+        if let Err(e) = stack.unwind(1) {
+            eprint!("{e}");
+            return Err(e);
+        }
+
+        if let Some(warnings) = stack.flush_warnings() {
+            eprint!("{warnings}");
+        }
+
+        if let Err(e @ (Signal::Error(_) | Signal::Condition(_))) = result {
+            return Err(e);
+        }
+    }
+
+    // any remaining input could not be parsed into a complete expression
+    if !buffer.is_empty() {
+        if let Err(e) = parser_config.parse_input(&buffer) {
+            let e = match e {
+                Signal::Error(e) => relocate_parse_error(e, &buffer, &buffer_lines, input).into(),
+                e => e,
+            };
+            eprintln!("{e}");
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Split an input into lines, alongside the byte offset at which each starts
+fn lines_with_offsets(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        Some((start, line.strip_suffix('\r').unwrap_or(line)))
+    })
+}
+
+/// Report a parse failure within the buffer at its position in the input
+fn relocate_parse_error(e: Error, buffer: &str, buffer_lines: &[usize], input: &str) -> Error {
+    use pest::error::{Error as E, InputLocation};
+    use pest::Position;
+
+    let Error::ParseFailure(e) = e else {
+        return e;
+    };
+
+    let pos = match e.location {
+        InputLocation::Pos(p) | InputLocation::Span((p, _)) => p,
+    };
+
+    // find the buffered line and column of the failure
+    let line = buffer[..pos].matches('\n').count();
+    let column = pos - buffer[..pos].rfind('\n').map_or(0, |i| i + 1);
+
+    match Position::new(input, buffer_lines[line] + column) {
+        Some(pos) => Error::ParseFailure(E::new_from_pos(e.variant, pos)),
+        None => Error::ParseFailure(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::session::SessionOutput;
+
+    fn run(input: &str) -> (String, Result<(), Signal>) {
+        let output = Rc::new(RefCell::new(String::new()));
+        let sink = output.clone();
+        let session =
+            Session::default().with_output(SessionOutput::Callback(Rc::new(move |s: String| {
+                sink.borrow_mut().push_str(&s)
+            })));
+
+        let result = script(session, &[input.to_string()], None);
+        let output = output.borrow().clone();
+        (output, result)
+    }
+
+    #[test]
+    fn evaluates_multiline_expressions() {
+        let (output, result) = run("f <- function(x) {\n  x +\n    1\n}\nf(2)");
+        assert!(result.is_ok());
+        assert_eq!(output.lines().last(), Some("[1] 3"));
+    }
+
+    #[test]
+    fn prints_values_by_class() {
+        let (output, result) = run(
+            "print.money <- function(x, ...) print(paste(\"money:\", unclass(x)))\nstructure(5, class = \"money\")",
+        );
+        assert!(result.is_ok());
+        assert_eq!(output.lines().last(), Some("[1] \"money: 5\""));
+    }
+
+    #[test]
+    fn reports_parse_errors_at_their_line() {
+        let (output, result) = run("print(1)\nx <- )\nprint(2)\nprint(3)");
+        assert!(!output.contains("[1] 2"));
+
+        let Err(Signal::Error(Error::ParseFailure(e))) = result else {
+            panic!("expected a parse failure");
+        };
+
+        assert_eq!(e.line_col, pest::error::LineColLocation::Pos((2, 6)));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::lang::Signal;
 use crate::{parser::*, session::SessionParserConfig};
 use reedline::{ValidationResult, Validator};
 
@@ -5,8 +6,8 @@ impl Validator for Localization {
     fn validate(&self, line: &str) -> ValidationResult {
         let res = self.parse_input(line);
         match res {
-            Ok(_) => ValidationResult::Complete,
-            Err(_) => ValidationResult::Incomplete,
+            Err(Signal::Error(e)) if e.is_incomplete_input(line) => ValidationResult::Incomplete,
+            _ => ValidationResult::Complete,
         }
     }
 }
@@ -15,8 +16,8 @@ impl Validator for SessionParserConfig {
     fn validate(&self, line: &str) -> ValidationResult {
         let res = self.locale.parse_input_with(line, self);
         match res {
-            Ok(_) => ValidationResult::Complete,
-            Err(_) => ValidationResult::Incomplete,
+            Err(Signal::Error(e)) if e.is_incomplete_input(line) => ValidationResult::Incomplete,
+            _ => ValidationResult::Complete,
         }
     }
 }
@@ -0,0 +1,464 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::object::types::Double;
+use crate::object::OptionNA;
+
+/// Kinds of random number generators
+#[derive(Debug, Copy, Clone, Default, PartialEq, strum::EnumString, strum::Display)]
+pub enum RngKind {
+    /// The 32-bit Mersenne-Twister, seeded and sampled as in GNU R
+    #[default]
+    #[strum(serialize = "Mersenne-Twister")]
+    MersenneTwister,
+    /// The ChaCha generator of the `rand` crate
+    #[strum(serialize = "ChaCha")]
+    ChaCha,
+}
+
+const N: usize = 624;
+const M: usize = 397;
+
+/// The Mersenne-Twister (MT19937) generator
+///
+/// Seeding follows GNU R, scrambling the seed with a linear congruential
+/// generator to fill the generator's state, such that the same seed
+/// produces the same stream of values.
+///
+#[derive(Debug, Clone)]
+pub struct MersenneTwister {
+    mt: [u32; N],
+    mti: usize,
+}
+
+impl MersenneTwister {
+    pub fn from_seed(seed: u32) -> Self {
+        let lcg = |seed: u32| seed.wrapping_mul(69069).wrapping_add(1);
+
+        let mut seed = seed;
+        for _ in 0..50 {
+            seed = lcg(seed);
+        }
+
+        // the first value seeds the position within the state, which is
+        // immediately reset
+        seed = lcg(seed);
+
+        let mut mt = [0; N];
+        for x in mt.iter_mut() {
+            seed = lcg(seed);
+            *x = seed;
+        }
+
+        MersenneTwister { mt, mti: N }
+    }
+
+    fn generate(&mut self) {
+        let mag01 = [0, 0x9908b0df];
+        let (upper, lower) = (0x80000000, 0x7fffffff);
+        let mt = &mut self.mt;
+
+        for kk in 0..N {
+            let y = (mt[kk] & upper) | (mt[(kk + 1) % N] & lower);
+            mt[kk] = mt[(kk + M) % N] ^ (y >> 1) ^ mag01[(y & 1) as usize];
+        }
+
+        self.mti = 0;
+    }
+}
+
+impl RngCore for MersenneTwister {
+    fn next_u32(&mut self) -> u32 {
+        if self.mti >= N {
+            self.generate();
+        }
+
+        let mut y = self.mt[self.mti];
+        self.mti += 1;
+
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c5680;
+        y ^= (y << 15) & 0xefc60000;
+        y ^ (y >> 18)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let lo = self.next_u32() as u64;
+        let hi = self.next_u32() as u64;
+        (hi << 32) | lo
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// A random number generator of any kind
+#[derive(Debug, Clone)]
+pub enum Generator {
+    MersenneTwister(Box<MersenneTwister>),
+    ChaCha(Box<StdRng>),
+}
+
+impl Generator {
+    pub fn from_seed(seed: i32, kind: RngKind) -> Self {
+        match kind {
+            RngKind::MersenneTwister => {
+                Generator::MersenneTwister(Box::new(MersenneTwister::from_seed(seed as u32)))
+            }
+            RngKind::ChaCha => Generator::ChaCha(Box::new(StdRng::seed_from_u64(seed as u64))),
+        }
+    }
+
+    pub fn kind(&self) -> RngKind {
+        match self {
+            Generator::MersenneTwister(_) => RngKind::MersenneTwister,
+            Generator::ChaCha(_) => RngKind::ChaCha,
+        }
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Generator::from_seed(rand::random(), RngKind::default())
+    }
+}
+
+impl RngCore for Generator {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Generator::MersenneTwister(rng) => rng.next_u32(),
+            Generator::ChaCha(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Generator::MersenneTwister(rng) => rng.next_u64(),
+            Generator::ChaCha(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Generator::MersenneTwister(rng) => rng.fill_bytes(dest),
+            Generator::ChaCha(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            Generator::MersenneTwister(rng) => rng.try_fill_bytes(dest),
+            Generator::ChaCha(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
+/// The random number generator of a session
+///
+/// Clones share the state of the generator, such that a seed set while
+/// evaluating one expression determines the values drawn by the next.
+///
+#[derive(Clone, Default)]
+pub struct SessionRng(Rc<RefCell<Generator>>);
+
+impl std::fmt::Debug for SessionRng {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SessionRng({})", self.0.borrow().kind())
+    }
+}
+
+impl PartialEq for SessionRng {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl SessionRng {
+    /// Reseed the generator, optionally switching to another kind
+    pub fn set_seed(&self, seed: i32, kind: Option<RngKind>) {
+        let kind = kind.unwrap_or_else(|| self.0.borrow().kind());
+        self.0.replace(Generator::from_seed(seed, kind));
+    }
+
+    /// Get mutable access to the generator
+    pub fn with_rng<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Generator) -> R,
+    {
+        f(&mut self.0.borrow_mut())
+    }
+
+    /// A uniformly distributed value in the open interval (0, 1)
+    pub fn unif_rand(&self) -> f64 {
+        const I232M1: f64 = 2.328306437080797e-10;
+        let x = self.with_rng(|rng| rng.next_u32()) as f64 * 2.3283064365386963e-10;
+        match x {
+            x if x <= 0.0 => 0.5 * I232M1,
+            x if 1.0 - x <= 0.0 => 1.0 - 0.5 * I232M1,
+            x => x,
+        }
+    }
+
+    /// A standard normally distributed value, by inversion
+    pub fn norm_rand(&self) -> f64 {
+        // a single uniform value is not of high enough precision
+        const BIG: f64 = 134217728.0;
+        let u = (BIG * self.unif_rand()).trunc() + self.unif_rand();
+        qnorm(u / BIG)
+    }
+
+    /// A standard exponentially distributed value
+    ///
+    /// Uses the algorithm of Ahrens & Dieter (1972), as does GNU R.
+    ///
+    pub fn exp_rand(&self) -> f64 {
+        // q[k - 1] = sum(log(2)^k / k!) for k = 1, 2, ...
+        const Q: [f64; 16] = [
+            std::f64::consts::LN_2,
+            0.9333736875190459,
+            0.9888777961838675,
+            0.998495925291496,
+            0.9998292811061389,
+            0.9999833164100727,
+            0.999998550819371,
+            0.9999998906925558,
+            0.9999999924734159,
+            0.9999999995283275,
+            0.9999999999728814,
+            0.9999999999985598,
+            0.999999999999929,
+            0.9999999999999968,
+            0.9999999999999999,
+            1.0000000000000000,
+        ];
+
+        let mut a = 0.0;
+        let mut u = self.unif_rand();
+        loop {
+            u += u;
+            if u > 1.0 {
+                break;
+            }
+            a += Q[0];
+        }
+
+        u -= 1.0;
+        if u <= Q[0] {
+            return a + u;
+        }
+
+        let mut i = 0;
+        let mut umin = self.unif_rand();
+        loop {
+            umin = umin.min(self.unif_rand());
+            i += 1;
+            if u <= Q[i] {
+                break;
+            }
+        }
+
+        a + umin * Q[0]
+    }
+
+    /// A uniformly distributed index below `n`
+    ///
+    /// Values are drawn by rejection sampling from the integers below the
+    /// next power of two, as in GNU R.
+    ///
+    pub fn unif_index(&self, n: usize) -> usize {
+        if n <= 1 {
+            return 0;
+        }
+
+        let bits = (n as f64).log2().ceil() as u32;
+        loop {
+            let mut v: u64 = 0;
+            for _ in (0..=bits).step_by(16) {
+                v = 65536 * v + (self.unif_rand() * 65536.0).floor() as u64;
+            }
+
+            let v = (v & ((1 << bits) - 1)) as usize;
+            if v < n {
+                return v;
+            }
+        }
+    }
+}
+
+/// The quantile function of the standard normal distribution
+///
+/// Uses algorithm AS 241 of Wichura (1988), accurate to about 1 part in
+/// 10^16.
+///
+pub fn qnorm(p: f64) -> f64 {
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    } else if p == 1.0 {
+        return f64::INFINITY;
+    }
+
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        let r = 0.180625 - q * q;
+        return q
+            * (((((((r * 2509.0809287301227 + 33430.57558358813) * r + 67265.7709270087) * r
+                + 45921.95393154987)
+                * r
+                + 13731.69376550946)
+                * r
+                + 1971.5909503065513)
+                * r
+                + 133.14166789178438)
+                * r
+                + 3.3871328727963665)
+            / (((((((r * 5226.495278852546 + 28729.085735721943) * r + 39307.89580009271) * r
+                + 21213.794301586597)
+                * r
+                + 5394.196021424751)
+                * r
+                + 687.1870074920579)
+                * r
+                + 42.31333070160091)
+                * r
+                + 1.0);
+    }
+
+    let r = (-p.min(1.0 - p).ln()).sqrt();
+    let val = if r <= 5.0 {
+        let r = r - 1.6;
+        (((((((r * 7.745450142783414e-4 + 0.022723844989269184) * r + 0.2417807251774506) * r
+            + 1.2704582524523684)
+            * r
+            + 3.6478483247632045)
+            * r
+            + 5.769497221460691)
+            * r
+            + 4.630337846156546)
+            * r
+            + 1.4234371107496835)
+            / (((((((r * 1.0507500716444169e-9 + 5.475938084995345e-4) * r
+                + 0.015198666563616457)
+                * r
+                + 0.14810397642748008)
+                * r
+                + 0.6897673349851)
+                * r
+                + 1.6763848301838038)
+                * r
+                + 2.053191626637759)
+                * r
+                + 1.0)
+    } else {
+        let r = r - 5.0;
+        (((((((r * 2.0103343992922881e-7 + 2.7115555687434876e-5) * r + 0.0012426609473880784)
+            * r
+            + 0.026532189526576124)
+            * r
+            + 0.29656057182850487)
+            * r
+            + 1.7848265399172913)
+            * r
+            + 5.463784911164114)
+            * r
+            + 6.657904643501103)
+            / (((((((r * 2.0442631033899397e-15 + 1.421511758316446e-7) * r
+                + 1.8463183175100548e-5)
+                * r
+                + 7.868691311456133e-4)
+                * r
+                + 0.014875361290850615)
+                * r
+                + 0.1369298809227358)
+                * r
+                + 0.599832206555888)
+                * r
+                + 1.0)
+    };
+
+    if q < 0.0 {
+        -val
+    } else {
+        val
+    }
+}
+
+/// Draw `n` random values, recycling distribution parameters over draws
+///
+/// Draws for which `f` produces `None`, due to invalid parameters, are
+/// `NA`. Also returns whether any such values were produced.
+///
+pub fn draw<F>(n: usize, params: &[Vec<f64>], mut f: F) -> (bool, Vec<Double>)
+where
+    F: FnMut(&[f64]) -> Option<f64>,
+{
+    let mut any_na = false;
+    if params.iter().any(|p| p.is_empty()) {
+        return (n > 0, vec![OptionNA::NA; n]);
+    }
+
+    let values = (0..n)
+        .map(|i| {
+            let args: Vec<f64> = params.iter().map(|p| p[i % p.len()]).collect();
+            match f(&args) {
+                Some(x) => OptionNA::Some(x),
+                None => {
+                    any_na = true;
+                    OptionNA::NA
+                }
+            }
+        })
+        .collect();
+
+    (any_na, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mersenne_twister_matches_r() {
+        let rng = SessionRng::default();
+        rng.set_seed(1, Some(RngKind::MersenneTwister));
+        let x: Vec<f64> = (0..3).map(|_| rng.unif_rand()).collect();
+        let expect = [0.2655087, 0.3721239, 0.5728534];
+        for (x, e) in x.iter().zip(expect) {
+            assert!((x - e).abs() < 1e-7, "{x} != {e}");
+        }
+    }
+
+    #[test]
+    fn normal_quantiles() {
+        assert_eq!(qnorm(0.5), 0.0);
+        assert!((qnorm(0.975) - 1.959963984540054).abs() < 1e-12);
+        assert!((qnorm(0.01) + 2.3263478740408408).abs() < 1e-12);
+        assert!((qnorm(1e-10) + 6.361340902404056).abs() < 1e-12);
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        for kind in [RngKind::MersenneTwister, RngKind::ChaCha] {
+            let rng = SessionRng::default();
+            rng.set_seed(42, Some(kind));
+            let x: Vec<f64> = (0..5).map(|_| rng.unif_rand()).collect();
+            rng.set_seed(42, None);
+            let y: Vec<f64> = (0..5).map(|_| rng.unif_rand()).collect();
+            assert_eq!(x, y);
+        }
+    }
+}
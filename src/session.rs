@@ -2,6 +2,7 @@ use std::rc::Rc;
 
 use crate::cli::{Cli, Experiment};
 use crate::parser::Localization;
+use crate::rng::SessionRng;
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Session {
@@ -10,6 +11,7 @@ pub struct Session {
     pub experiments: Vec<Experiment>,
     pub history: Option<String>,
     pub output: SessionOutput,
+    pub rng: SessionRng,
 }
 
 pub enum SessionOutput {
@@ -108,6 +110,7 @@ impl From<Cli> for Session {
             experiments: value.experiments,
             history: None,
             output: SessionOutput::default(),
+            rng: SessionRng::default(),
         }
     }
 }
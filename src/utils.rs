@@ -10,6 +10,29 @@ impl<T, U> SameType<T> for U {
     }
 }
 
+/// Escape a string for display as a quoted literal
+///
+/// Backslashes, double quotes and control characters are written as the
+/// escape sequences which produce them when parsed.
+///
+pub fn escape(x: &str) -> String {
+    let mut result = String::with_capacity(x.len());
+    for c in x.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' => result.push_str("\\b"),
+            '\u{c}' => result.push_str("\\f"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
 #[macro_export]
 macro_rules! r {
     // evaluate a single token directly